@@ -0,0 +1,134 @@
+// answer of a puzzle as the solvers return it, instead of a string that
+// can't be told apart from an error message; numbers are compared by
+// their value, so an answer equals the expected answer of the answers file
+// or the cache no matter if it was parsed as a signed or unsigned number
+
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt;
+
+use crate::error::SolveError;
+
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),  // only for answers that can be negative (i.e. day 9)
+    Text(String),
+    Unsolved,     // the solver of the puzzle isn't written yet
+}
+
+impl Answer {
+    // read an answer from its text (i.e. an expected or a cached answer);
+    // anything that isn't an integer is a text answer
+    pub fn parse(text: &str) -> Answer {
+        if let Ok(num) = text.parse::<u64>() {
+            return Answer::Unsigned(num);
+        }
+
+        if let Ok(num) = text.parse::<i64>() {
+            return Answer::Signed(num);
+        }
+
+        return Answer::Text(text.to_string());
+    }
+
+    // whether the answer is the expected one, given as text
+    pub fn matches(&self, expected: &str) -> bool {
+        return self.is_solved() && *self == Answer::parse(expected.trim());
+    }
+
+    pub fn is_solved(&self) -> bool {
+        return *self != Answer::Unsolved;
+    }
+
+    // value of a numeric answer (all u64 and i64 values fit into i128)
+    fn as_number(&self) -> Option<i128> {
+        return match self {
+            Answer::Unsigned(num) => Some(*num as i128),
+            Answer::Signed(num) => Some(*num as i128),
+            _ => None,
+        };
+    }
+}
+
+// answers of both puzzles of a day, each one or why it failed
+pub type BothAnswers = [Result<Answer, SolveError>; 2];
+
+// solve both puzzles of a day from its input parsed once; lap is called
+// after the parse (which happened already) and after puzzle 1, so that the
+// caller can time the parse and each puzzle (puzzle 2 is solved even if
+// puzzle 1 failed, since it might not need what made puzzle 1 fail)
+pub fn both<T: ?Sized>(parsed: &T, lap: &mut dyn FnMut(),
+                       solve1: fn(&T) -> Result<Answer, SolveError>,
+                       solve2: fn(&T) -> Result<Answer, SolveError>) ->
+    Result<BothAnswers, SolveError> {
+    lap();
+    let answer1: Result<Answer, SolveError> = solve1(parsed);
+    lap();
+
+    return Ok([answer1, solve2(parsed)]);
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        if let (Some(num), Some(other_num)) =
+            (self.as_number(), other.as_number()) {
+            return num == other_num;
+        }
+
+        return match (self, other) {
+            (Answer::Text(text), Answer::Text(other_text)) =>
+                text == other_text,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            _ => false,
+        };
+    }
+}
+
+// compare with an expected answer (i.e. `answer == "6440"`)
+impl PartialEq<&str> for Answer {
+    fn eq(&self, expected: &&str) -> bool {
+        return self.matches(expected);
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Answer::Unsigned(num) => write!(f, "{}", num),
+            Answer::Signed(num) => write!(f, "{}", num),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        };
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(num: u32) -> Answer {
+        return Answer::Unsigned(num as u64);
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(num: u64) -> Answer {
+        return Answer::Unsigned(num);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(num: usize) -> Answer {
+        return Answer::Unsigned(num as u64);
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(num: i64) -> Answer {
+        return Answer::Signed(num);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        return Answer::Text(text);
+    }
+}
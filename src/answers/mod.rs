@@ -0,0 +1,61 @@
+// expected answers of the puzzles, read from a plain text file
+// with one "DAY PART ANSWER" entry per line ('#' starts a comment), i.e.:
+//   # day part answer
+//   1 1 142
+//   1 2 281
+
+use std::collections::HashMap;
+use std::fs;
+
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    // read the answers file; malformed lines are reported with their number
+    pub fn read(path: &str) -> Result<Answers, String> {
+        let file_content: String = fs::read_to_string(path).map_err(
+            |err| format!("Couldn't read answers file '{}': {}", path, err))?;
+
+        return Answers::parse(&file_content).map_err(
+            |err| format!("{}: {}", path, err));
+    }
+
+    pub fn parse(file_content: &str) -> Result<Answers, String> {
+        let mut answers: HashMap<(u8, u8), String> = HashMap::new();
+
+        for (i, mut line) in file_content.split("\n").enumerate() {
+            // strip comments and whitespace
+            if let Some(comment_start_idx) = line.find('#') {
+                line = &line[..comment_start_idx];
+            }
+
+            line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let components: Vec<&str> = line.split_whitespace().collect();
+            let day_and_part: Option<(u8, u8)> = match components.as_slice() {
+                [day, part, _answer] =>
+                    day.parse().ok().zip(part.parse().ok()),
+                _ => None,
+            };
+
+            match day_and_part {
+                Some(day_and_part) =>
+                    answers.insert(day_and_part, components[2].to_string()),
+                None => return Err(format!(
+                    "line {}: expected 'DAY PART ANSWER', got '{}'",
+                    i + 1, line)),
+            };
+        }
+
+        return Ok(Answers{answers: answers});
+    }
+
+    // get the expected answer of a puzzle (if it's known)
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        return self.answers.get(&(day, part)).map(|a| a.as_str());
+    }
+}
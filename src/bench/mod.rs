@@ -0,0 +1,102 @@
+// measure how long the solvers take by solving the same input repeatedly
+
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::cache;
+use crate::json::Json;
+use crate::solver;
+use crate::solver::Solution;
+use crate::solver::Solver;
+use crate::solver::Strategy;
+
+pub struct Bench {
+    pub day: u8,
+    pub puzzle: u8,
+    pub strategy: &'static str,  // name of the measured solver strategy
+    pub input_hash: u64,       // hash of the solved input
+    pub answer: Answer,
+    pub times: Vec<Duration>,  // runtime of every run
+}
+
+impl Bench {
+    pub fn min(&self) -> Duration {
+        return self.times.iter().min().copied().unwrap_or_default();
+    }
+
+    pub fn max(&self) -> Duration {
+        return self.times.iter().max().copied().unwrap_or_default();
+    }
+
+    // middle runtime of all runs (mean of the two middle ones for an even
+    // number of runs), which unlike the mean ignores single slow runs
+    pub fn median(&self) -> Duration {
+        if self.times.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let mut times: Vec<Duration> = self.times.clone();
+        times.sort();
+
+        let middle: usize = times.len() / 2;
+        if times.len().is_multiple_of(2) {
+            return (times[middle - 1] + times[middle]) / 2;
+        }
+
+        return times[middle];
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.times.is_empty() {
+            return Duration::ZERO;
+        }
+
+        return self.times.iter().sum::<Duration>() / self.times.len() as u32;
+    }
+
+    pub fn to_json(&self) -> Json {
+        let micros = |duration: Duration| Json::Float(
+            duration.as_secs_f64() * 1_000_000.0);
+
+        return Json::object(vec![
+            ("day", Json::Integer(self.day as i128)),
+            ("part", Json::Integer(self.puzzle as i128)),
+            ("strategy", Json::string(self.strategy)),
+            ("answer", Json::answer(&self.answer)),
+            ("runs", Json::Integer(self.times.len() as i128)),
+            ("min_us", micros(self.min())),
+            ("mean_us", micros(self.mean())),
+            ("median_us", micros(self.median())),
+            ("max_us", micros(self.max())),
+        ]);
+    }
+}
+
+// solve a puzzle for an input the provided number of times with a strategy
+// of the solver (the default one if None); a solver that fails is only run
+// once, since its runtime isn't meaningful
+pub fn run(day: u8, puzzle: u8, strategy: Option<&str>, input: &str,
+           runs: u64) -> Result<Bench, String> {
+    let solver: &Solver = solver::get_solver(day).ok_or(
+        "Invalid AOC day number")?;
+    let strategy: &Strategy = solver.strategy(strategy)?;
+
+    let mut bench: Bench = Bench{
+        day: day,
+        puzzle: puzzle,
+        strategy: strategy.name,
+        input_hash: cache::hash_input(input),
+        answer: Answer::Unsolved,
+        times: Vec::new(),
+    };
+
+    for _ in 0..runs {
+        let solution: Solution = solver::solve_strategy(
+            day, puzzle, Some(strategy.name), input.to_string())?;
+
+        bench.answer = solution.answer.map_err(|err| err.to_string())?;
+        bench.times.push(solution.elapsed);
+    }
+
+    return Ok(bench);
+}
@@ -0,0 +1,48 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+
+// 64 bit FNV-1a hash, which (unlike the hasher of the standard library)
+// is the same across Rust versions and platforms
+pub struct Fnv(u64);
+
+impl Fnv {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    pub fn new() -> Fnv {
+        return Fnv(Fnv::OFFSET_BASIS);
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(Fnv::PRIME);
+        }
+    }
+
+    // write a text with its length, so the boundaries of consecutive
+    // texts are part of the hash ("ab" + "c" differs from "a" + "bc")
+    pub fn write_str(&mut self, text: &str) {
+        self.write(&(text.len() as u64).to_le_bytes());
+        self.write(text.as_bytes());
+    }
+
+    pub fn finish(&self) -> u64 {
+        return self.0;
+    }
+}
+
+// write a file in one go: readers (i.e. parallel jobs) either see the old
+// or the complete new file, never a partially written one
+pub fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    let tmp_path: PathBuf =
+        path.with_extension(format!("tmp{}", process::id()));
+
+    fs::write(&tmp_path, content)?;
+    return fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    });
+}
@@ -0,0 +1,156 @@
+// answers of earlier solver runs, stored in a local directory; an answer
+// is keyed by the day, the puzzle, the strategy, the hash of the input and
+// the fingerprint of the solver's source code, so neither a changed input nor
+// a changed solver can ever be answered with a stale result
+
+mod helpers;
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::answer::Answer;
+use crate::config::Config;
+use crate::mem;
+use crate::solver;
+use crate::solver::Solution;
+use crate::solver::Solver;
+use crate::solver::Strategy;
+
+use helpers::Fnv;
+
+// code every solver depends on besides its own day module
+const SHARED_SOURCES: [&str; 5] = [
+    include_str!("../answer/mod.rs"),
+    include_str!("../error/mod.rs"),
+    include_str!("../fxhash/mod.rs"),
+    include_str!("../solver/helpers.rs"),
+    include_str!("../scan/mod.rs"),
+];
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: &str) -> Cache {
+        return Cache{dir: PathBuf::from(dir)};
+    }
+
+    // file of the cached answer of a puzzle for an input (None if there is
+    // no solver for the day or it has no such strategy)
+    fn path(&self, day: u8, puzzle: u8, strategy: Option<&str>,
+            input: &str) -> Option<PathBuf> {
+        let solver: &Solver = solver::get_solver(day)?;
+        let strategy: &Strategy = solver.strategy(strategy).ok()?;
+
+        return Some(self.dir.join(format!(
+            "day{}-part{}-{}-{:016x}-{:016x}.txt", day, puzzle, strategy.name,
+            hash_input(input), fingerprint(solver))));
+    }
+
+    // store an answer in the file of its cache entry
+    fn store(&self, path: &PathBuf, answer: &str) -> Result<(), String> {
+        fs::create_dir_all(&self.dir).map_err(|err| format!(
+            "Couldn't create cache directory '{}': {}",
+            self.dir.display(), err))?;
+
+        return helpers::write_atomically(path, answer).map_err(|err| format!(
+            "Couldn't write cache file '{}': {}", path.display(), err));
+    }
+
+    // remove all cached answers and return how many there were
+    pub fn clear(&self) -> Result<usize, String> {
+        let entries: fs::ReadDir = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(_) if !self.dir.exists() => return Ok(0),
+            Err(err) => return Err(format!(
+                "Couldn't read cache directory '{}': {}",
+                self.dir.display(), err)),
+        };
+
+        let mut removed: usize = 0;
+
+        // only remove the files of the cache, in case the directory was
+        // set to one that holds other files as well
+        for entry in entries.flatten() {
+            let name: String = entry.file_name().to_string_lossy().to_string();
+            if !name.starts_with("day") || !name.contains("-part") {
+                continue;
+            }
+
+            fs::remove_file(entry.path()).map_err(|err| format!(
+                "Couldn't remove '{}': {}", entry.path().display(), err))?;
+
+            if name.ends_with(".txt") {
+                removed += 1;
+            }
+        }
+
+        // keep the directory if anything else is left in it
+        let _ = fs::remove_dir(&self.dir);
+
+        return Ok(removed);
+    }
+}
+
+// hash of everything the answer of a solver depends on besides its input
+fn fingerprint(solver: &Solver) -> u64 {
+    let mut hash: Fnv = Fnv::new();
+
+    for source in SHARED_SOURCES.iter().chain(&solver.sources) {
+        hash.write_str(source);
+    }
+
+    // overflows are answered with an error instead of a wrapped number
+    hash.write(&[cfg!(feature = "checked") as u8]);
+
+    return hash.finish();
+}
+
+// hash of an input that stays the same across runs, so answers,
+// measurements and saved crashing inputs can be told apart by the input
+// they belong to
+pub fn hash_input(input: &str) -> u64 {
+    let mut hash: Fnv = Fnv::new();
+    hash.write_str(input);
+
+    return hash.finish();
+}
+
+// solve a puzzle like solver::solve_with_timeout(), but answer it from the
+// cache if possible (unless it is disabled or the memory of the solver is
+// measured) and cache every new answer
+pub fn solve(config: &Config, day: u8, puzzle: u8, strategy: Option<&str>,
+             input: String) -> Result<Solution, String> {
+    let entry: Option<(Cache, PathBuf)> =
+        config.cache_dir.as_ref().and_then(|dir| {
+            let cache: Cache = Cache::new(dir);
+            let path: PathBuf = cache.path(day, puzzle, strategy, &input)?;
+            return Some((cache, path));
+        });
+
+    if let Some((_, path)) = &entry {
+        let start: Instant = Instant::now();
+
+        if let (false, Ok(answer)) =
+            (mem::is_enabled(), fs::read_to_string(path)) {
+            return Ok(Solution{answer: Ok(Answer::parse(&answer)),
+                               elapsed: start.elapsed(), memory: None,
+                               cached: true});
+        }
+    }
+
+    let solution: Solution = solver::solve_with_timeout(
+        day, puzzle, strategy, input, config.timeout)?;
+
+    if let (Some((cache, path)), Ok(answer)) = (&entry, &solution.answer) {
+        // a cache that can't be written only costs time on the next run;
+        // unsolved puzzles are solved again once their solver is written
+        if answer.is_solved() {
+            let _ = cache.store(path, &answer.to_string());
+        }
+    }
+
+    return Ok(solution);
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::json::Json;
+
+// a single violation of the input format of a day
+pub struct Problem {
+    pub line: Option<usize>,  // 1-based line number (None for the input)
+    pub message: String,
+}
+
+impl Problem {
+    pub fn at(line: usize, message: &str) -> Problem {
+        return Problem{line: Some(line), message: message.to_string()};
+    }
+
+    // problem of the whole input (i.e. a missing line)
+    pub fn input(message: &str) -> Problem {
+        return Problem{line: None, message: message.to_string()};
+    }
+
+    pub fn to_json(&self) -> Json {
+        let line: Json = match self.line {
+            Some(line) => Json::Integer(line as i128),
+            None => Json::Null,
+        };
+
+        return Json::object(vec![
+            ("line", line),
+            ("message", Json::string(&self.message)),
+        ]);
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        };
+    }
+}
+
+// all lines of an input with their line number, trimmed like the solvers
+// read them; empty lines are kept, since some formats depend on them
+pub fn numbered_lines(file_content: &str) -> Vec<(usize, &str)> {
+    return file_content.split('\n').enumerate().map(
+        |(i, line)| (i + 1, line.trim())).collect();
+}
+
+// like numbered_lines(), but without the empty lines
+pub fn non_empty_lines(file_content: &str) -> Vec<(usize, &str)> {
+    return numbered_lines(file_content).into_iter().filter(
+        |(_, line)| !line.is_empty()).collect();
+}
+
+// parse the numbers of a list separated by the delimiter; with `strict`,
+// every delimiter has to be followed by a number, otherwise repeated
+// delimiters (i.e. numbers aligned with several spaces) are fine
+pub fn parse_numbers<T: FromStr>(text: &str, delimiter: char, strict: bool) ->
+    Result<Vec<T>, String> {
+    let mut nums: Vec<T> = Vec::new();
+
+    for num in text.trim().split(delimiter) {
+        if num.is_empty() && !strict {
+            continue;
+        }
+
+        nums.push(num.parse().map_err(
+            |_| format!("'{}' is not a number", num))?);
+    }
+
+    return Ok(nums);
+}
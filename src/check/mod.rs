@@ -0,0 +1,367 @@
+// grammar validation of the puzzle inputs: reports every problem of an
+// input with its line number without running any of the solvers, so a
+// wrong or broken input file is caught before it reaches a parser
+
+mod helpers;
+
+pub use helpers::Problem;
+
+use std::collections::HashMap;
+
+use crate::day1;
+use crate::day2;
+use crate::day5;
+use crate::day7;
+use crate::day7::CardRanks;
+
+use helpers::non_empty_lines;
+use helpers::numbered_lines;
+use helpers::parse_numbers;
+
+// check an input against the format of a day
+pub fn check(day: u8, file_content: &str) -> Result<Vec<Problem>, String> {
+    let problems: Vec<Problem> = match day {
+        1 => check_day1(file_content),
+        2 => check_day2(file_content),
+        3 => check_day3(file_content),
+        4 => check_day4(file_content),
+        5 => check_day5(file_content),
+        6 => check_day6(file_content),
+        7 => check_day7(file_content),
+        8 => check_day8(file_content),
+        9 => check_day9(file_content),
+        _ => return Err(format!("No input format for AOC day {}", day)),
+    };
+
+    return Ok(problems);
+}
+
+// every line needs a digit (or a digit word for puzzle 2)
+fn check_day1(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+
+    for (n, line) in non_empty_lines(file_content) {
+        let has_digit: bool = line.chars().any(|c| c.is_ascii_digit());
+        let has_word: bool =
+            day1::NUMBER_WORDS.iter().any(|word| line.contains(word));
+
+        if !has_digit && !has_word {
+            problems.push(Problem::at(n, "no digit or digit word"));
+        }
+    }
+
+    return problems;
+}
+
+// lines of "Game ID: DRAW; DRAW" with draws of "COUNT COLOR, COUNT COLOR"
+fn check_day2(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+
+    for (n, line) in non_empty_lines(file_content) {
+        let (game, draws): (&str, &str) = match line.split_once(": ") {
+            Some((game, draws)) if game.starts_with("Game ") => (game, draws),
+            _ => {
+                problems.push(Problem::at(n, "expected 'Game ID: DRAWS'"));
+                continue;
+            },
+        };
+
+        let id: &str = &game["Game ".len()..];
+        if id.parse::<u32>().is_err() {
+            problems.push(Problem::at(
+                n, &format!("'{}' is not a game ID", id)));
+        }
+
+        for draw in draws.split("; ").flat_map(|draw| draw.split(", ")) {
+            let valid: bool = match draw.split_once(' ') {
+                Some((count, color)) => count.parse::<u32>().is_ok() &&
+                                        day2::COLORS.contains(&color),
+                None => false,
+            };
+
+            if !valid {
+                problems.push(Problem::at(n, &format!(
+                    "'{}' is not a draw of 'COUNT COLOR' \
+                     (with red, green or blue)", draw)));
+            }
+        }
+    }
+
+    return problems;
+}
+
+// a rectangular grid of ASCII characters
+fn check_day3(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    let lines: Vec<(usize, &str)> = non_empty_lines(file_content);
+
+    let Some((first_n, first_line)) = lines.first().copied() else {
+        return vec![Problem::input("no rows in the schematic")];
+    };
+
+    for (n, line) in lines {
+        if !line.is_ascii() {
+            problems.push(Problem::at(n, "row has non-ASCII characters"));
+        } else if line.len() != first_line.len() {
+            problems.push(Problem::at(n, &format!(
+                "row has {} fields, but line {} has {}",
+                line.len(), first_n, first_line.len())));
+        }
+    }
+
+    return problems;
+}
+
+// lines of "Card ID: WINNING | DRAWN" with consecutive IDs starting at 1
+fn check_day4(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    let mut expected_id: u32 = 1;
+
+    for (n, line) in non_empty_lines(file_content) {
+        let (card, numbers): (&str, &str) = match line.split_once(": ") {
+            Some((card, numbers)) if card.starts_with("Card ") =>
+                (card, numbers),
+            _ => {
+                problems.push(Problem::at(
+                    n, "expected 'Card ID: WINNING | DRAWN'"));
+                continue;
+            },
+        };
+
+        match card["Card ".len()..].trim().parse::<u32>() {
+            Ok(id) if id == expected_id => expected_id += 1,
+            Ok(id) => {
+                problems.push(Problem::at(n, &format!(
+                    "card {} follows card {}, expected card {}",
+                    id, expected_id - 1, expected_id)));
+                expected_id = id + 1;
+            },
+            Err(_) => problems.push(Problem::at(n, &format!(
+                "'{}' is not a card ID", card["Card ".len()..].trim()))),
+        }
+
+        let Some((winning, drawn)) = numbers.split_once(" | ") else {
+            problems.push(Problem::at(
+                n, "expected ' | ' between winning and drawn numbers"));
+            continue;
+        };
+
+        for list in [winning, drawn] {
+            if let Err(err) = parse_numbers::<u32>(list, ' ', false) {
+                problems.push(Problem::at(n, &err));
+            }
+        }
+    }
+
+    return problems;
+}
+
+// a "seeds:" line with pairs of numbers followed by the seven maps, each
+// a "SOURCE-to-DESTINATION map:" line and lines of three numbers
+fn check_day5(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    let lines: Vec<(usize, &str)> = numbered_lines(file_content);
+
+    // lines that are part of the seeds or a map
+    let mut known_lines: Vec<bool> = vec![false; lines.len()];
+
+    match lines.iter().position(|(_, line)| line.starts_with("seeds: ")) {
+        Some(i) => {
+            known_lines[i] = true;
+            let (n, line): (usize, &str) = lines[i];
+
+            match parse_numbers::<u64>(&line["seeds: ".len()..], ' ', true) {
+                Ok(seeds) if seeds.len() % 2 == 1 =>
+                    problems.push(Problem::at(n, &format!(
+                        "odd number of seeds ({}), but puzzle 2 expects \
+                         pairs of start and length", seeds.len()))),
+                Ok(_) => {},
+                Err(err) => problems.push(Problem::at(n, &err)),
+            }
+        },
+        None => problems.push(Problem::input("missing the 'seeds:' line")),
+    }
+
+    for categories in day5::CATEGORIES.windows(2) {
+        let header: String =
+            format!("{}-to-{} map:", categories[0], categories[1]);
+
+        let Some(start) = lines.iter().position(|(_, l)| *l == header) else {
+            problems.push(Problem::input(
+                &format!("missing the '{}' line", header)));
+            continue;
+        };
+        known_lines[start] = true;
+
+        // a map ends with an empty line (or the end of the input)
+        for i in start + 1..lines.len() {
+            let (n, line): (usize, &str) = lines[i];
+            if line.is_empty() {
+                break;
+            }
+            known_lines[i] = true;
+
+            match parse_numbers::<u64>(line, ' ', true) {
+                Ok(nums) if nums.len() != 3 => problems.push(Problem::at(
+                    n, "expected a map line of 'DESTINATION SOURCE LENGTH'")),
+                Ok(_) => {},
+                Err(err) => problems.push(Problem::at(n, &err)),
+            }
+        }
+    }
+
+    for (i, (n, line)) in lines.iter().enumerate() {
+        if !known_lines[i] && !line.is_empty() {
+            problems.push(Problem::at(
+                *n, &format!("unexpected line '{}'", line)));
+        }
+    }
+
+    return problems;
+}
+
+// a "Time:" and a "Distance:" line with the same number of numbers
+fn check_day6(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    let lines: Vec<(usize, &str)> = non_empty_lines(file_content);
+
+    let mut counts: Vec<usize> = Vec::new();
+
+    for id in ["Time:", "Distance:"] {
+        let Some((n, line)) = lines.iter().find(
+            |(_, line)| line.starts_with(id)) else {
+            problems.push(Problem::input(
+                &format!("missing the '{}' line", id)));
+            continue;
+        };
+
+        match parse_numbers::<u64>(&line[id.len()..], ' ', false) {
+            Ok(nums) if nums.is_empty() =>
+                problems.push(Problem::at(*n, "expected at least one number")),
+            Ok(nums) => counts.push(nums.len()),
+            Err(err) => problems.push(Problem::at(*n, &err)),
+        }
+    }
+
+    if counts.len() == 2 && counts[0] != counts[1] {
+        problems.push(Problem::input(&format!(
+            "{} times, but {} distances", counts[0], counts[1])));
+    }
+
+    for (n, line) in lines {
+        if !line.starts_with("Time:") && !line.starts_with("Distance:") {
+            problems.push(Problem::at(
+                n, &format!("unexpected line '{}'", line)));
+        }
+    }
+
+    return problems;
+}
+
+// lines of "HAND BID" with hands of five valid cards
+fn check_day7(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    let card_ranks: CardRanks = day7::get_card_ranks_puzzle1();
+
+    for (n, line) in non_empty_lines(file_content) {
+        let Some((hand, bid)) = line.split_once(' ') else {
+            problems.push(Problem::at(n, "expected 'HAND BID'"));
+            continue;
+        };
+
+        let invalid_cards: String = hand.chars().filter(
+            |card| !card_ranks.contains(*card)).collect();
+
+        if !invalid_cards.is_empty() {
+            problems.push(Problem::at(n, &format!(
+                "hand '{}' has invalid cards '{}'", hand, invalid_cards)));
+        } else if hand.len() != 5 {
+            problems.push(Problem::at(n, &format!(
+                "hand '{}' has {} cards, expected 5", hand, hand.len())));
+        }
+
+        if bid.parse::<u32>().is_err() {
+            problems.push(Problem::at(
+                n, &format!("bid '{}' is not a number", bid)));
+        }
+    }
+
+    return problems;
+}
+
+// a line of L/R directions followed by lines of "NODE = (LEFT, RIGHT)",
+// where every referenced node has to be defined
+fn check_day8(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+    let lines: Vec<(usize, &str)> = non_empty_lines(file_content);
+
+    let Some(((n, directions), node_lines)) = lines.split_first() else {
+        return vec![Problem::input("missing the line of directions")];
+    };
+
+    let invalid_directions: String =
+        directions.chars().filter(|c| *c != 'L' && *c != 'R').collect();
+    if !invalid_directions.is_empty() {
+        problems.push(Problem::at(*n, &format!(
+            "directions have characters other than 'L' and 'R': '{}'",
+            invalid_directions)));
+    }
+
+    // line every node is defined in and the nodes it jumps to
+    let mut nodes: HashMap<&str, usize> = HashMap::new();
+    let mut jumps: Vec<(usize, &str, [&str; 2])> = Vec::new();
+
+    for (n, line) in node_lines {
+        let parsed: Option<(&str, &str, &str)> =
+            line.split_once(" = (").and_then(|(node, jump_nodes)| {
+                let (left, right) = jump_nodes.strip_suffix(')')?
+                                              .split_once(", ")?;
+                return Some((node, left, right));
+            });
+
+        let Some((node, left, right)) = parsed else {
+            problems.push(Problem::at(
+                *n, "expected a node line of 'NODE = (LEFT, RIGHT)'"));
+            continue;
+        };
+
+        if let Some(defined_in) = nodes.insert(node, *n) {
+            problems.push(Problem::at(*n, &format!(
+                "node '{}' is already defined in line {}", node, defined_in)));
+        }
+
+        jumps.push((*n, node, [left, right]));
+    }
+
+    for (n, node, jump_nodes) in jumps {
+        for jump_node in jump_nodes {
+            if !nodes.contains_key(jump_node) {
+                problems.push(Problem::at(n, &format!(
+                    "node '{}' jumps to undefined node '{}'",
+                    node, jump_node)));
+            }
+        }
+    }
+
+    for node in ["AAA", "ZZZ"] {
+        if !nodes.contains_key(node) {
+            problems.push(Problem::input(&format!(
+                "no node '{}' (puzzle 1 walks from AAA to ZZZ)", node)));
+        }
+    }
+
+    return problems;
+}
+
+// lines of numbers separated by single spaces
+fn check_day9(file_content: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = Vec::new();
+
+    for (n, line) in non_empty_lines(file_content) {
+        if let Err(err) = parse_numbers::<i64>(line, ' ', true) {
+            problems.push(Problem::at(n, &err));
+        }
+    }
+
+    return problems;
+}
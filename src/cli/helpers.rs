@@ -0,0 +1,543 @@
+use std::str::FromStr;
+
+use crate::solver;
+
+use super::Arg;
+use super::BIN;
+use super::COMMANDS;
+use super::Command;
+use super::GLOBAL_OPTIONS;
+use super::Kind;
+use super::Opt;
+
+// validated arguments and options of a command
+pub struct Matches {
+    values: Vec<(&'static str, String)>,  // argument or option and its value
+}
+
+impl Matches {
+    // value of a positional argument (i.e. "DAY") or option (i.e. "--seed")
+    pub fn value(&self, name: &str) -> Option<&str> {
+        return self.values.iter().rev().find(|(n, _)| *n == name).map(
+            |(_, value)| value.as_str());
+    }
+
+    // parsed value of an argument or option; the value was already
+    // validated against its kind, so this only fails for unset values
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        return self.value(name).and_then(|value| value.parse().ok());
+    }
+
+    pub fn flag(&self, long: &str) -> bool {
+        return self.value(long).is_some();
+    }
+}
+
+// error message for a value that doesn't fit an argument or option
+pub fn invalid_value(name: &str, value: &str, expected: &str) -> String {
+    return format!("Invalid value '{}' for {} (expected {})",
+                   value, name, expected);
+}
+
+// check the arguments of a command (without the command name itself)
+pub fn parse(command: &Command, args: &[String]) -> Result<Matches, String> {
+    let mut matches: Matches = Matches{values: Vec::new()};
+    let mut positional: usize = 0;
+    let mut args_iter = args.iter();
+
+    while let Some(arg) = args_iter.next() {
+        // short options (i.e. "-o") are looked up by their long form
+        let short: Option<&Opt> = match arg.strip_prefix('-') {
+            Some(name) if name.len() == 1 => command.options.iter().find(
+                |opt| opt.short.is_some_and(|c| name.starts_with(c))),
+            _ => None,
+        };
+        let is_short: bool = arg.len() == 2 && arg.starts_with('-') &&
+                             arg[1..].chars().all(|c| c.is_alphabetic());
+        if is_short && short.is_none() {
+            return Err(format!("Unknown option '{}' for '{}'",
+                               arg, command.name));
+        }
+
+        if !arg.starts_with("--") && short.is_none() {
+            let spec: &Arg = command.args.get(positional).ok_or_else(
+                || format!("Unexpected argument '{}' for '{}'",
+                           arg, command.name))?;
+
+            check_value(spec.name, spec.kind, arg)?;
+            matches.values.push((spec.name, arg.clone()));
+            positional += 1;
+            continue;
+        }
+
+        // both "--seed 3" and "--seed=3" are accepted
+        let (long, inline_value): (&str, Option<&str>) =
+            match (short, arg.split_once('=')) {
+                (Some(opt), _) => (opt.long, None),
+                (None, Some((long, value))) => (long, Some(value)),
+                (None, None) => (arg.as_str(), None),
+            };
+
+        let spec: &Opt = command.options.iter().find(|o| o.long == long)
+            .ok_or_else(|| format!("Unknown option '{}' for '{}'",
+                                   long, command.name))?;
+
+        let value: String = match (spec.value, inline_value) {
+            (None, None) => String::new(),
+            (None, Some(_)) =>
+                return Err(format!("Option '{}' takes no value", spec.long)),
+            (Some((_, kind)), value) => {
+                let value: &str = match value {
+                    Some(value) => value,
+                    None => args_iter.next().ok_or_else(
+                        || format!("Missing value for option '{}'",
+                                   spec.long))?,
+                };
+
+                check_value(spec.long, kind, value)?;
+                value.to_string()
+            },
+        };
+
+        matches.values.push((spec.long, value));
+    }
+
+    if let Some(missing) = command.args[positional..].iter().find(
+        |arg| arg.required) {
+        return Err(format!("Missing argument {} for '{}'",
+                           missing.name, command.name));
+    }
+
+    return Ok(matches);
+}
+
+// check that a value fits the kind of its argument or option
+fn check_value(name: &str, kind: Kind, value: &str) -> Result<(), String> {
+    let valid: bool = match kind {
+        Kind::Day => {
+            let days: Vec<String> = solver::SOLVERS.iter().map(
+                |solver| solver.day.to_string()).collect();
+
+            if !days.iter().any(|day| day == value) {
+                return Err(invalid_value(
+                    name, value,
+                    &format!("a solved day: {}", days.join(", "))));
+            }
+            true
+        },
+        Kind::Puzzle => value == "1" || value == "2",
+        Kind::Number => value.parse::<u64>().is_ok(),
+        Kind::Port => value.parse::<u16>().is_ok(),
+        Kind::Path | Kind::Text => !value.is_empty(),
+        Kind::Command => super::get_command(value).is_some(),
+        // whether the day has the strategy is up to the command
+        Kind::Strategy => strategy_names().iter().any(|name| name == value),
+        Kind::Choice(choices) => choices.contains(&value),
+    };
+
+    if !valid {
+        return Err(invalid_value(name, value, &describe_kind(kind)));
+    }
+
+    return Ok(());
+}
+
+fn describe_kind(kind: Kind) -> String {
+    return match kind {
+        Kind::Day => String::from("a solved day"),
+        Kind::Puzzle => String::from("1 or 2"),
+        Kind::Number => String::from("a non-negative integer"),
+        Kind::Port => String::from("a port between 0 and 65535"),
+        Kind::Path => String::from("a path"),
+        Kind::Text => String::from("a non-empty value"),
+        Kind::Command => String::from("a command, see 'help'"),
+        Kind::Strategy =>
+            format!("a strategy: {}", strategy_names().join(", ")),
+        Kind::Choice(choices) => format!("one of {}", choices.join(", ")),
+    };
+}
+
+// titles of the puzzles of all days that can be solved
+fn available_days() -> Vec<String> {
+    let mut lines: Vec<String> = vec![String::from("Available days:")];
+
+    for solver in &solver::SOLVERS {
+        lines.push(format!("  {:>2}  {}", solver.day, solver.title));
+    }
+
+    return lines;
+}
+
+// one line summary of the arguments of a command
+pub fn usage(command: &Command) -> String {
+    let mut usage: String = format!("Usage: {} {}", BIN, command.name);
+
+    for arg in command.args {
+        if arg.required {
+            usage += &format!(" {}", arg.name);
+        } else {
+            usage += &format!(" [{}]", arg.name);
+        }
+    }
+
+    if !command.options.is_empty() {
+        usage += " [OPTIONS]";
+    }
+
+    return usage;
+}
+
+// help of a single command or, without a command, of the whole runner
+pub fn help(command: Option<&Command>) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    match command {
+        Some(command) => {
+            lines.push(usage(command));
+            lines.push(String::new());
+            lines.push(capitalize(command.about));
+
+            if !command.args.is_empty() {
+                lines.push(String::new());
+                lines.push(String::from("Arguments:"));
+                for arg in command.args {
+                    lines.push(format_entry(arg.name, arg.help));
+                }
+            }
+
+            if command.args.iter().any(|arg| matches!(arg.kind, Kind::Day)) {
+                lines.push(String::new());
+                lines.extend(available_days());
+            }
+
+            lines.push(String::new());
+            lines.push(String::from("Options:"));
+            for opt in command.options {
+                lines.push(format_entry(&option_name(opt), opt.help));
+            }
+            lines.push(format_entry("-h, --help", "show this help"));
+        },
+        None => {
+            lines.push(format!("Usage: {} COMMAND [ARGS] [OPTIONS]", BIN));
+            lines.push(format!("       {} DAY PUZZLE [INPUT]  (same as run)",
+                               BIN));
+            lines.push(String::new());
+            lines.push(String::from("Commands:"));
+            for command in &COMMANDS {
+                lines.push(format_entry(command.name, command.about));
+            }
+
+            lines.push(String::new());
+            lines.extend(available_days());
+        },
+    }
+
+    lines.push(String::new());
+    lines.push(String::from(
+        "Global options (override the settings of the config files):"));
+    let global_options: Vec<&Opt> = match command {
+        Some(command) => global_options(command),
+        None => GLOBAL_OPTIONS.iter().collect(),
+    };
+    for opt in global_options {
+        lines.push(format_entry(&option_name(opt), opt.help));
+    }
+
+    if command.is_none() {
+        lines.push(String::new());
+        lines.push(format!("Run '{} help COMMAND' for the help of a command.",
+                           BIN));
+    }
+
+    return lines.join("\n");
+}
+
+fn format_entry(name: &str, help: &str) -> String {
+    return format!("  {:<18} {}", name, help);
+}
+
+// name of an option with the name of its value (i.e. "--seed S")
+fn option_name(opt: &Opt) -> String {
+    let mut name: String = match opt.short {
+        Some(short) => format!("-{}, {}", short, opt.long),
+        None => opt.long.to_string(),
+    };
+
+    if let Some((value, _)) = opt.value {
+        name += &format!(" {}", value);
+    }
+
+    return name;
+}
+
+// all names of an option (i.e. "-o" and "--output")
+fn option_names(opt: &Opt) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    if let Some(short) = opt.short {
+        names.push(format!("-{}", short));
+    }
+    names.push(opt.long.to_string());
+
+    return names;
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+
+    return match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    };
+}
+
+// completion script for a shell
+pub fn completions(shell: &str) -> Result<String, String> {
+    return match shell {
+        "bash" => Ok(bash_completions()),
+        "zsh" => Ok(zsh_completions()),
+        "fish" => Ok(fish_completions()),
+        _ => Err(invalid_value("SHELL", shell, "one of bash, zsh, fish")),
+    };
+}
+
+// all options a command accepts, including the global ones
+fn all_options(command: &Command) -> Vec<&Opt> {
+    return command.options.iter().chain(global_options(command)).collect();
+}
+
+// global options of a command without the ones it defines itself
+fn global_options(command: &Command) -> Vec<&'static Opt> {
+    return GLOBAL_OPTIONS.iter().filter(
+        |global| !command.options.iter().any(|opt| opt.long == global.long))
+        .collect();
+}
+
+// fixed values an argument or option can take (if any)
+fn kind_values(kind: Kind) -> Vec<String> {
+    return match kind {
+        Kind::Day => solver::SOLVERS.iter().map(
+            |solver| solver.day.to_string()).collect(),
+        Kind::Puzzle => vec![String::from("1"), String::from("2")],
+        Kind::Command => COMMANDS.iter().map(
+            |command| command.name.to_string()).collect(),
+        Kind::Strategy => strategy_names(),
+        Kind::Choice(choices) => choices.iter().map(
+            |choice| choice.to_string()).collect(),
+        _ => Vec::new(),
+    };
+}
+
+// names of the strategies of all solvers, each one only once
+fn strategy_names() -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for solver in &solver::SOLVERS {
+        for name in solver.strategy_names() {
+            if !names.iter().any(|known| known == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    return names;
+}
+
+fn bash_completions() -> String {
+    let mut script: String = String::new();
+    let command_names: Vec<&str> = COMMANDS.iter().map(|c| c.name).collect();
+
+    script += &format!("_{}() {{\n", BIN);
+    script += "    local cur prev\n";
+    script += "    cur=\"${COMP_WORDS[COMP_CWORD]}\"\n";
+    script += "    prev=\"${COMP_WORDS[COMP_CWORD-1]}\"\n";
+    script += "\n";
+    script += "    if [ \"$COMP_CWORD\" -eq 1 ]; then\n";
+    script += &format!(
+        "        COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))\n",
+        command_names.join(" "));
+    script += "        return\n";
+    script += "    fi\n";
+    script += "\n";
+    script += "    local opts=\"\" words=\"\"\n";
+    script += "    case \"${COMP_WORDS[1]}\" in\n";
+
+    for command in &COMMANDS {
+        let options: Vec<String> =
+            all_options(command).into_iter().flat_map(option_names).collect();
+        let mut values: Vec<String> = Vec::new();
+        for value in command.args.iter().flat_map(|arg| kind_values(arg.kind)) {
+            if !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        script += &format!("        {})\n", command.name);
+
+        // complete the values of the options
+        for opt in all_options(command) {
+            let Some((_, kind)) = opt.value else {
+                continue;
+            };
+
+            let values: Vec<String> = kind_values(kind);
+            let completion: String = match kind {
+                Kind::Path => String::from("-f"),
+                _ if !values.is_empty() =>
+                    format!("-W \"{}\"", values.join(" ")),
+                _ => continue,
+            };
+
+            for name in option_names(opt) {
+                script += &format!(
+                    "            [ \"$prev\" = \"{}\" ] && \
+                     COMPREPLY=($(compgen {} -- \"$cur\")) && return\n",
+                    name, completion);
+            }
+        }
+
+        script += &format!("            opts=\"{} --help\"\n",
+                           options.join(" "));
+        script += &format!("            words=\"{}\"\n", values.join(" "));
+        script += "            ;;\n";
+    }
+
+    script += "    esac\n";
+    script += "\n";
+    script += "    if [[ \"$cur\" == -* ]]; then\n";
+    script += "        COMPREPLY=($(compgen -W \"$opts\" -- \"$cur\"))\n";
+    script += "    else\n";
+    script += "        COMPREPLY=($(compgen -W \"$words\" -- \"$cur\") \
+               $(compgen -f -- \"$cur\"))\n";
+    script += "    fi\n";
+    script += "}\n";
+    script += &format!("complete -F _{} {}\n", BIN, BIN);
+
+    return script;
+}
+
+// quote a text for zsh and fish, which both don't expand single quotes
+fn quote(text: &str) -> String {
+    return format!("'{}'", text.replace('\'', "'\\''"));
+}
+
+// completion action of zsh for a kind of value
+fn zsh_action(kind: Kind) -> String {
+    let values: Vec<String> = kind_values(kind);
+
+    return match kind {
+        Kind::Path => String::from("_files"),
+        _ if !values.is_empty() => format!("({})", values.join(" ")),
+        _ => String::from(" "),
+    };
+}
+
+fn zsh_completions() -> String {
+    let mut script: String = format!("#compdef {}\n\n", BIN);
+
+    script += &format!("_{}() {{\n", BIN);
+    script += "    local -a commands\n";
+    script += "    commands=(\n";
+    for command in &COMMANDS {
+        script += &format!("        {}\n", quote(
+            &format!("{}:{}", command.name, command.about)));
+    }
+    script += "    )\n";
+    script += "\n";
+    script += "    if (( CURRENT == 2 )); then\n";
+    script += "        _describe 'command' commands\n";
+    script += "        return\n";
+    script += "    fi\n";
+    script += "\n";
+    script += "    # complete the arguments as if the command was the binary\n";
+    script += "    shift words\n";
+    script += "    (( CURRENT-- ))\n";
+    script += "\n";
+    script += "    case $words[1] in\n";
+
+    for command in &COMMANDS {
+        script += &format!("        {})\n", command.name);
+        script += "            _arguments";
+
+        for opt in all_options(command) {
+            let help: String = opt.help.replace('[', "\\[").replace(']', "\\]");
+            for name in option_names(opt) {
+                let spec: String = match opt.value {
+                    Some((value, kind)) => format!("{}[{}]:{}:{}",
+                                                   name, help, value,
+                                                   zsh_action(kind)),
+                    None => format!("{}[{}]", name, help),
+                };
+                script += &format!(" \\\n                {}", quote(&spec));
+            }
+        }
+
+        for (i, arg) in command.args.iter().enumerate() {
+            let optional: &str = if arg.required { "" } else { ":" };
+            let spec: String = format!("{}:{}{}:{}", i + 1, optional,
+                                       arg.name, zsh_action(arg.kind));
+            script += &format!(" \\\n                {}", quote(&spec));
+        }
+
+        script += "\n";
+        script += "            ;;\n";
+    }
+
+    script += "    esac\n";
+    script += "}\n";
+    script += "\n";
+    script += &format!("_{} \"$@\"\n", BIN);
+
+    return script;
+}
+
+fn fish_completions() -> String {
+    let mut script: String = String::new();
+
+    // only complete files where a path is expected
+    script += &format!("complete -c {} -f\n", BIN);
+
+    for command in &COMMANDS {
+        script += &format!(
+            "complete -c {} -n '__fish_use_subcommand' -a {} -d {}\n",
+            BIN, command.name, quote(command.about));
+    }
+
+    for command in &COMMANDS {
+        let condition: String =
+            format!("'__fish_seen_subcommand_from {}'", command.name);
+
+        for opt in all_options(command) {
+            let value: String = match opt.value {
+                Some((_, Kind::Path)) => String::from(" -r -F"),
+                Some((_, kind)) if !kind_values(kind).is_empty() =>
+                    format!(" -x -a {}", quote(&kind_values(kind).join(" "))),
+                Some(_) => String::from(" -x"),
+                None => String::new(),
+            };
+
+            let short: String = match opt.short {
+                Some(short) => format!(" -s {}", short),
+                None => String::new(),
+            };
+
+            script += &format!("complete -c {} -n {}{} -l {}{} -d {}\n",
+                               BIN, condition, short, &opt.long[2..], value,
+                               quote(opt.help));
+        }
+
+        for arg in command.args {
+            let arguments: String = match arg.kind {
+                Kind::Path => String::from(" -F"),
+                kind if !kind_values(kind).is_empty() =>
+                    format!(" -a {}", quote(&kind_values(kind).join(" "))),
+                _ => continue,
+            };
+
+            script += &format!("complete -c {} -n {}{} -d {}\n",
+                               BIN, condition, arguments, quote(arg.name));
+        }
+    }
+
+    return script;
+}
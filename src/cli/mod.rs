@@ -0,0 +1,381 @@
+// description of all commands of the runner with their arguments and
+// options; the parser, the help texts and the shell completion scripts
+// are all generated from these tables
+
+mod helpers;
+
+pub use helpers::Matches;
+pub use helpers::completions;
+pub use helpers::help;
+pub use helpers::invalid_value;
+pub use helpers::parse;
+pub use helpers::usage;
+
+use crate::config;
+use crate::graph;
+
+pub const BIN: &str = "adventofcode23";
+
+// kind of value of an argument or option (used for validation
+// and to decide what the shell should complete)
+#[derive(Debug, Clone, Copy)]
+pub enum Kind {
+    Day,                                // day that has a solver
+    Puzzle,                             // puzzle number (1 or 2)
+    Number,                             // non-negative integer
+    Port,                               // TCP port number
+    Path,                               // path to a file or directory
+    Text,                               // any other value
+    Command,                            // name of a command of the runner
+    Strategy,                           // name of a strategy of a solver
+    Choice(&'static [&'static str]),    // one of a fixed set of values
+}
+
+// positional argument of a command
+pub struct Arg {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub required: bool,
+    pub help: &'static str,
+}
+
+// --long option of a command, some with a -s short form;
+// options without a value are flags
+pub struct Opt {
+    pub short: Option<char>,
+    pub long: &'static str,
+    pub value: Option<(&'static str, Kind)>,  // name and kind of the value
+    pub help: &'static str,
+}
+
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub args: &'static [Arg],
+    pub options: &'static [Opt],
+}
+
+pub const SHELLS: [&str; 3] = ["bash", "zsh", "fish"];
+const COLOR_CHOICES: [&str; 3] = ["auto", "always", "never"];
+
+// options that apply to every command (see config::SETTINGS)
+pub const GLOBAL_OPTIONS: [Opt; 8] = [
+    Opt{short: None, long: config::SETTINGS[0].1,
+        value: Some(("DIR", Kind::Path)),
+        help: config::SETTINGS[0].2},
+    Opt{short: None, long: config::SETTINGS[1].1,
+        value: Some(("PATH", Kind::Path)),
+        help: config::SETTINGS[1].2},
+    Opt{short: None, long: config::SETTINGS[2].1,
+        value: Some(("FORMAT", Kind::Choice(&["text", "json"]))),
+        help: config::SETTINGS[2].2},
+    Opt{short: None, long: config::SETTINGS[3].1,
+        value: Some(("SECS", Kind::Text)),
+        help: config::SETTINGS[3].2},
+    Opt{short: None, long: config::SETTINGS[4].1,
+        value: Some(("N", Kind::Number)),
+        help: config::SETTINGS[4].2},
+    Opt{short: None, long: config::SETTINGS[5].1,
+        value: Some(("DIR", Kind::Path)),
+        help: config::SETTINGS[5].2},
+    Opt{short: None, long: config::SETTINGS[6].1,
+        value: Some(("TOKEN", Kind::Text)),
+        help: config::SETTINGS[6].2},
+    Opt{short: None, long: config::SETTINGS[7].1,
+        value: Some(("URL", Kind::Text)),
+        help: config::SETTINGS[7].2},
+];
+
+const DAY: Arg = Arg{
+    name: "DAY", kind: Kind::Day, required: true, help: "Advent of Code day"};
+const OPTIONAL_DAY: Arg = Arg{
+    name: "DAY", kind: Kind::Day, required: false,
+    help: "Advent of Code day (all days if omitted)"};
+const PUZZLE: Arg = Arg{
+    name: "PUZZLE", kind: Kind::Puzzle, required: true,
+    help: "puzzle of the day (1 or 2)"};
+const RUN_PUZZLE: Arg = Arg{
+    name: "PUZZLE", kind: Kind::Choice(&["1", "2", "both"]), required: true,
+    help: "puzzle of the day (1, 2 or both from a single parse)"};
+const OPTIONAL_PUZZLE: Arg = Arg{
+    name: "PUZZLE", kind: Kind::Puzzle, required: false,
+    help: "puzzle of the day (both puzzles if omitted)"};
+const INPUT: Arg = Arg{
+    name: "INPUT", kind: Kind::Path, required: false,
+    help: "path to the input file (inputs/dayN.txt by default)"};
+
+const MEM: Opt = Opt{
+    short: None, long: "--mem", value: None,
+    help: "report the allocations and peak memory of the solvers"};
+const STRATEGY: Opt = Opt{
+    short: None, long: "--strategy", value: Some(("NAME", Kind::Strategy)),
+    help: "solve with another strategy of the day, see 'list'"};
+const ALL_STRATEGIES: Opt = Opt{
+    short: None, long: "--all-strategies", value: None,
+    help: "run every strategy of a day and check that they agree"};
+const NO_CACHE: Opt = Opt{
+    short: None, long: "--no-cache", value: None,
+    help: "solve all puzzles instead of using cached answers"};
+const JSON: Opt = Opt{
+    short: None, long: "--json", value: None,
+    help: "print the result as JSON"};
+
+pub const COMMANDS: [Command; 24] = [
+    Command{
+        name: "run",
+        about: "solve a puzzle of a day, or both from a single parse",
+        args: &[DAY, RUN_PUZZLE, INPUT],
+        options: &[JSON, MEM, NO_CACHE, STRATEGY],
+    },
+    Command{
+        name: "all",
+        about: "solve all puzzles of all days for which an input exists",
+        args: &[],
+        options: &[MEM, NO_CACHE],
+    },
+    Command{
+        name: "bench",
+        about: "measure the runtime of the solvers",
+        args: &[OPTIONAL_DAY, OPTIONAL_PUZZLE],
+        options: &[
+            Opt{short: None, long: "--runs",
+                value: Some(("N", Kind::Number)),
+                help: "number of runs per puzzle (default: 10)"},
+            Opt{short: None, long: "--input",
+                value: Some(("PATH", Kind::Path)),
+                help: "path to the input file (needs a DAY)"},
+            Opt{short: None, long: "--strategy",
+                value: Some(("NAME", Kind::Strategy)),
+                help: "strategy of the day to measure (needs a DAY)"},
+            ALL_STRATEGIES,
+            Opt{short: None, long: "--save", value: None,
+                help: "append the results to the bench history"},
+            Opt{short: None, long: "--compare", value: None,
+                help: "fail if a median regressed since the last saved \
+                       results of the same solver and input"},
+            Opt{short: None, long: "--history",
+                value: Some(("PATH", Kind::Path)),
+                help: "path to the bench history \
+                       (default: bench-history.txt)"},
+            Opt{short: None, long: "--threshold",
+                value: Some(("PCT", Kind::Number)),
+                help: "slowdown of the median in percent that counts as a \
+                       regression (default: 10)"},
+        ],
+    },
+    Command{
+        name: "verify",
+        about: "check the answers of the solvers against the answers file",
+        args: &[OPTIONAL_DAY],
+        options: &[NO_CACHE, ALL_STRATEGIES],
+    },
+    Command{
+        name: "fetch",
+        about: "download the input of a day with the session",
+        args: &[DAY],
+        options: &[
+            Opt{short: None, long: "--force", value: None,
+                help: "download the input even if it exists already"},
+        ],
+    },
+    Command{
+        name: "submit",
+        about: "submit the answer of a puzzle and record the verdict",
+        args: &[DAY, PUZZLE],
+        options: &[
+            Opt{short: None, long: "--answer",
+                value: Some(("VALUE", Kind::Text)),
+                help: "answer to submit instead of solving the puzzle"},
+            NO_CACHE,
+        ],
+    },
+    Command{
+        name: "leaderboard",
+        about: "show the JSON export of a private leaderboard",
+        args: &[
+            Arg{name: "PATH", kind: Kind::Path, required: true,
+                help: "path to the JSON export of the leaderboard"},
+        ],
+        options: &[],
+    },
+    Command{
+        name: "scaffold",
+        about: "generate the files of a new day and register it",
+        args: &[
+            Arg{name: "N", kind: Kind::Number, required: true,
+                help: "Advent of Code day without a solver yet (1 to 25)"},
+        ],
+        options: &[
+            Opt{short: None, long: "--title",
+                value: Some(("TITLE", Kind::Text)),
+                help: "title of the puzzle (default: Day N)"},
+            Opt{short: None, long: "--root",
+                value: Some(("DIR", Kind::Path)),
+                help: "root directory of the crate (default: .)"},
+        ],
+    },
+    Command{
+        name: "gen",
+        about: "print a random but valid input of a day",
+        args: &[DAY],
+        options: &[
+            Opt{short: None, long: "--seed",
+                value: Some(("S", Kind::Number)),
+                help: "seed of the generated input (default: 0)"},
+        ],
+    },
+    Command{
+        name: "list",
+        about: "list all solvers with their input format and complexity",
+        args: &[],
+        options: &[JSON],
+    },
+    Command{
+        name: "check",
+        about: "check that an input file has the format of a day",
+        args: &[DAY, INPUT],
+        options: &[],
+    },
+    Command{
+        name: "serve",
+        about: "serve all solvers as a JSON API over HTTP",
+        args: &[],
+        options: &[
+            Opt{short: None, long: "--port",
+                value: Some(("PORT", Kind::Port)),
+                help: "port to listen on (default: 8023, 0 for any)"},
+            Opt{short: None, long: "--host",
+                value: Some(("ADDR", Kind::Text)),
+                help: "address to listen on (default: 127.0.0.1)"},
+            MEM,
+        ],
+    },
+    Command{
+        name: "repl",
+        about: "explore the parsed input of a day interactively",
+        args: &[DAY, INPUT],
+        options: &[],
+    },
+    Command{
+        name: "watch",
+        about: "re-run the solvers of a day whenever its input changes",
+        args: &[DAY, OPTIONAL_PUZZLE],
+        options: &[
+            Opt{short: None, long: "--input",
+                value: Some(("PATH", Kind::Path)),
+                help: "path to the input file to watch"},
+            Opt{short: None, long: "--interval",
+                value: Some(("MS", Kind::Number)),
+                help: "poll interval in milliseconds (default: 500)"},
+        ],
+    },
+    Command{
+        name: "snapshot",
+        about: "print the intermediate structures of a day",
+        args: &[DAY, INPUT],
+        options: &[],
+    },
+    Command{
+        name: "robust",
+        about: "check that the solvers never panic or hang on broken inputs",
+        args: &[
+            DAY,
+            Arg{name: "N", kind: Kind::Number, required: true,
+                help: "number of mutated inputs"},
+        ],
+        options: &[
+            Opt{short: None, long: "--seed",
+                value: Some(("S", Kind::Number)),
+                help: "seed of the first generated input (default: 0)"},
+            Opt{short: None, long: "--crash-dir",
+                value: Some(("DIR", Kind::Path)),
+                help: "directory to save crashing inputs to \
+                       (default: crashes)"},
+        ],
+    },
+    Command{
+        name: "viz",
+        about: "draw the parsed input of a day as an SVG picture",
+        args: &[DAY, INPUT],
+        options: &[
+            Opt{short: Some('o'), long: "--output",
+                value: Some(("PATH", Kind::Path)),
+                help: "file to write the SVG to (default: stdout)"},
+        ],
+    },
+    Command{
+        name: "graph",
+        about: "export the graph of a day's input as Graphviz DOT or JSON",
+        args: &[DAY, INPUT],
+        options: &[
+            Opt{short: None, long: "--format",
+                value: Some(("FORMAT", Kind::Choice(&graph::FORMATS))),
+                help: "dot or node-link json (default: dot)"},
+        ],
+    },
+    Command{
+        name: "show",
+        about: "print the parsed input of a day with colors in the terminal",
+        args: &[DAY, INPUT],
+        options: &[
+            Opt{short: None, long: "--color",
+                value: Some(("WHEN", Kind::Choice(&COLOR_CHOICES))),
+                help: "color the output: auto (only when printing to a \
+                       terminal), always or never (default: auto)"},
+        ],
+    },
+    Command{
+        name: "config",
+        about: "show the effective settings and where they come from",
+        args: &[
+            Arg{name: "ACTION", kind: Kind::Choice(&["show"]), required: true,
+                help: "what to do with the config (show)"},
+        ],
+        options: &[],
+    },
+    Command{
+        name: "cache",
+        about: "manage the cached answers of the solvers",
+        args: &[
+            Arg{name: "ACTION", kind: Kind::Choice(&["clear"]), required: true,
+                help: "what to do with the cache (clear)"},
+        ],
+        options: &[],
+    },
+    Command{
+        name: "completions",
+        about: "print a shell completion script",
+        args: &[
+            Arg{name: "SHELL", kind: Kind::Choice(&SHELLS), required: true,
+                help: "shell to complete for (bash, zsh or fish)"},
+        ],
+        options: &[],
+    },
+    Command{
+        name: "help",
+        about: "show the help of all or a single command",
+        args: &[
+            Arg{name: "COMMAND", kind: Kind::Command, required: false,
+                help: "command to show the help of"},
+        ],
+        options: &[],
+    },
+    Command{
+        name: "version",
+        about: "print the version of the runner",
+        args: &[],
+        options: &[],
+    },
+];
+
+// look up a command by its name
+pub fn get_command(name: &str) -> Option<&'static Command> {
+    return COMMANDS.iter().find(|command| command.name == name);
+}
+
+// whether a command defines an option itself, which then takes precedence
+// over the global option of the same name (i.e. "--format" of graph)
+pub fn has_option(command: &str, option: &str) -> bool {
+    return get_command(command).is_some_and(
+        |command| command.options.iter().any(|opt| opt.long == option));
+}
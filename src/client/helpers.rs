@@ -0,0 +1,205 @@
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::time::Duration;
+
+// the server is slow at times, but never this slow
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// upper limit for response bodies (puzzle pages are only a few KiB)
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+// server part of an "http://host[:port][/prefix]" URL
+pub struct Url {
+    pub host: String,
+    pub port: u16,
+    pub prefix: String,  // path all requests start with, without a '/' suffix
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Url {
+    pub fn parse(url: &str) -> Result<Url, String> {
+        let invalid = || format!(
+            "Invalid URL '{}' (expected 'http://host[:port][/path]')", url);
+
+        if url.starts_with("https://") {
+            return Err(format!(
+                "Can't connect to '{}': HTTPS isn't supported, use a local \
+                 TLS proxy and an http:// base_url", url));
+        }
+
+        let rest: &str = url.strip_prefix("http://").ok_or_else(invalid)?;
+        let (authority, prefix): (&str, &str) = match rest.find('/') {
+            Some(slash_idx) => (&rest[..slash_idx], &rest[slash_idx..]),
+            None => (rest, ""),
+        };
+
+        let (host, port): (&str, u16) = match authority.rsplit_once(':') {
+            Some((host, port)) =>
+                (host, port.parse().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        return Ok(Url{
+            host: host.to_string(),
+            port: port,
+            prefix: prefix.trim_end_matches('/').to_string(),
+        });
+    }
+}
+
+// send a single HTTP/1.1 request and read the whole response; the
+// connection is closed afterwards
+pub fn request(url: &Url, method: &str, path: &str,
+               headers: &[(&str, &str)], body: &str) -> io::Result<Response> {
+    let mut stream: TcpStream = TcpStream::connect((url.host.as_str(),
+                                                    url.port))?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request: String = format!(
+        "{} {}{} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\
+         Content-Length: {}\r\n",
+        method, url.prefix, path, url.host, body.len());
+    for (name, value) in headers {
+        request += &format!("{}: {}\r\n", name, value);
+    }
+    request += "\r\n";
+    request += body;
+
+    stream.write_all(request.as_bytes())?;
+    stream.flush()?;
+
+    return read_response(&stream);
+}
+
+fn read_response(stream: &TcpStream) -> io::Result<Response> {
+    let mut reader: BufReader<&TcpStream> = BufReader::new(stream);
+
+    // status line looks like: "HTTP/1.1 200 OK"
+    let mut status_line: String = String::new();
+    reader.read_line(&mut status_line)?;
+
+    let status: u16 = match status_line.split_whitespace().collect::<Vec<_>>()
+        .as_slice() {
+        [version, status, ..] if version.starts_with("HTTP/1.") =>
+            status.parse().map_err(|_| invalid_data("Malformed status line"))?,
+        _ => return Err(invalid_data("Malformed status line")),
+    };
+
+    // the body is delimited by its length, sent in chunks or ends with the
+    // connection
+    let mut content_length: Option<usize> = None;
+    let mut chunked: bool = false;
+
+    loop {
+        let mut header: String = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Err(invalid_data("Connection closed inside of headers"));
+        }
+
+        let header: &str = header.trim_end();
+        if header.is_empty() {  // empty line marks the end of the headers
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            let (name, value): (&str, &str) = (name.trim(), value.trim());
+
+            if name.eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(value.parse().map_err(
+                    |_| invalid_data("Invalid Content-Length header"))?);
+            } else if name.eq_ignore_ascii_case("Transfer-Encoding") {
+                chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+
+    let body: Vec<u8> = match (chunked, content_length) {
+        (true, _) => read_chunks(&mut reader)?,
+        (false, Some(length)) if length > MAX_BODY_SIZE =>
+            return Err(invalid_data("Response body too large")),
+        (false, Some(length)) => {
+            let mut body: Vec<u8> = vec![0; length];
+            reader.read_exact(&mut body)?;
+            body
+        },
+        (false, None) => {
+            let mut body: Vec<u8> = Vec::new();
+            reader.take(MAX_BODY_SIZE as u64).read_to_end(&mut body)?;
+            body
+        },
+    };
+
+    let body: String = String::from_utf8(body).map_err(
+        |_| invalid_data("Response body isn't valid UTF-8"))?;
+
+    return Ok(Response{status: status, body: body});
+}
+
+// body in "Transfer-Encoding: chunked", where every chunk is preceded by
+// its length in hex and a zero length chunk ends the body
+fn read_chunks(reader: &mut BufReader<&TcpStream>) -> io::Result<Vec<u8>> {
+    let mut body: Vec<u8> = Vec::new();
+
+    loop {
+        let mut size_line: String = String::new();
+        reader.read_line(&mut size_line)?;
+
+        // chunk extensions (after a ';') are ignored
+        let size: &str = size_line.split(';').next().unwrap_or("").trim();
+        let size: usize = usize::from_str_radix(size, 16).map_err(
+            |_| invalid_data("Invalid chunk size"))?;
+        if body.len() + size > MAX_BODY_SIZE {
+            return Err(invalid_data("Response body too large"));
+        }
+
+        // the last chunk is followed by (ignored) trailer headers
+        // up to an empty line
+        if size == 0 {
+            loop {
+                let mut line: String = String::new();
+                if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                    return Ok(body);
+                }
+            }
+        }
+
+        let mut chunk: Vec<u8> = vec![0; size];
+        reader.read_exact(&mut chunk)?;
+        body.extend(chunk);
+
+        // every chunk ends with a line break
+        reader.read_line(&mut String::new())?;
+    }
+}
+
+// encode a value of an application/x-www-form-urlencoded body
+pub fn form_encode(value: &str) -> String {
+    let mut encoded: String = String::new();
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' |
+            b'~' => encoded.push(byte as char),
+            b' ' => encoded.push('+'),
+            _ => encoded += &format!("%{:02X}", byte),
+        }
+    }
+
+    return encoded;
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, msg);
+}
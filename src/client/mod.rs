@@ -0,0 +1,310 @@
+// client for the Advent of Code server: download the input of a day and
+// submit answers, authenticated with the session cookie of a logged in
+// browser; only plain HTTP is spoken (over a std TcpStream), so the real
+// server needs a local TLS proxy, while the tests use a mock server
+//
+// submitted answers are recorded in a plain text file with one
+// "TIMESTAMP DAY PART ANSWER VERDICT" entry per line, i.e.:
+//   # timestamp day part answer verdict
+//   1701406800 1 1 54338 too-low
+//   1701406860 1 1 54388 correct
+// so that known wrong answers aren't submitted again
+
+mod helpers;
+
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::history;
+use crate::records;
+
+use helpers::Response;
+use helpers::Url;
+
+const YEAR: u16 = 2023;
+
+const HEADER: &str = "# timestamp day part answer verdict\n";
+const FORMAT: &str = "TIMESTAMP DAY PART ANSWER VERDICT";
+
+// the server asks automated clients to identify themselves
+const USER_AGENT: &str =
+    concat!("adventofcode23/", env!("CARGO_PKG_VERSION"), " (local runner)");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    TooSoon(Option<String>),  // time left to wait, i.e. "41s"
+    WrongLevel,               // solved already or the puzzle is locked
+}
+
+impl Verdict {
+    // verdict of the article in the page answering a submission
+    fn parse(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            return Some(Verdict::Correct);
+        }
+        if page.contains("That's not the right answer") {
+            return Some(match page {
+                _ if page.contains("your answer is too high") =>
+                    Verdict::TooHigh,
+                _ if page.contains("your answer is too low") =>
+                    Verdict::TooLow,
+                _ => Verdict::Wrong,
+            });
+        }
+        if page.contains("You gave an answer too recently") {
+            let wait: Option<String> = page.split_once("You have ").and_then(
+                |(_, rest)| rest.split_once(" left to wait")).map(
+                |(wait, _)| wait.to_string());
+            return Some(Verdict::TooSoon(wait));
+        }
+        if page.contains("You don't seem to be solving the right level") {
+            return Some(Verdict::WrongLevel);
+        }
+
+        return None;
+    }
+
+    fn name(&self) -> &'static str {
+        return match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon(_) => "too-soon",
+            Verdict::WrongLevel => "wrong-level",
+        };
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        return match name {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            "too-soon" => Some(Verdict::TooSoon(None)),
+            "wrong-level" => Some(Verdict::WrongLevel),
+            _ => None,
+        };
+    }
+
+    // whether the answer was judged (and it makes no sense to submit it
+    // again), as opposed to a submission that was turned away
+    pub fn is_final(&self) -> bool {
+        return !matches!(self, Verdict::TooSoon(_) | Verdict::WrongLevel);
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong (too high)"),
+            Verdict::TooLow => write!(f, "wrong (too low)"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooSoon(Some(wait)) =>
+                write!(f, "answered too recently, {} left to wait", wait),
+            Verdict::TooSoon(None) => write!(f, "answered too recently"),
+            Verdict::WrongLevel =>
+                write!(f, "not submitted, the puzzle is solved or locked"),
+        };
+    }
+}
+
+pub struct Client {
+    url: Url,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Result<Client, String> {
+        return Ok(Client{
+            url: Url::parse(base_url)?,
+            session: session.to_string(),
+        });
+    }
+
+    // personal input of a day
+    pub fn fetch_input(&self, day: u8) -> Result<String, String> {
+        let path: String = format!("/{}/day/{}/input", YEAR, day);
+        let response: Response = self.request("GET", &path, "")?;
+
+        return match response.status {
+            200 => Ok(response.body),
+            _ => Err(format!("Couldn't fetch the input of day {}: {}",
+                             day, describe_error(&response))),
+        };
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &Answer) ->
+        Result<Verdict, String> {
+        let path: String = format!("/{}/day/{}/answer", YEAR, day);
+        let body: String = format!(
+            "level={}&answer={}", part, helpers::form_encode(
+                &answer.to_string()));
+        let response: Response = self.request("POST", &path, &body)?;
+
+        if response.status != 200 {
+            return Err(format!("Couldn't submit the answer of day {}, \
+                                puzzle {}: {}",
+                               day, part, describe_error(&response)));
+        }
+
+        return Verdict::parse(&response.body).ok_or_else(|| format!(
+            "Couldn't find the verdict in the response to the answer of \
+             day {}, puzzle {}", day, part));
+    }
+
+    fn request(&self, method: &str, path: &str, body: &str) ->
+        Result<Response, String> {
+        let cookie: String = format!("session={}", self.session);
+        let mut headers: Vec<(&str, &str)> =
+            vec![("Cookie", &cookie), ("User-Agent", USER_AGENT)];
+        if method == "POST" {
+            headers.push(
+                ("Content-Type", "application/x-www-form-urlencoded"));
+        }
+
+        return helpers::request(&self.url, method, path, &headers, body)
+            .map_err(|err| format!("Request to {}:{} failed: {}",
+                                   self.url.host, self.url.port, err));
+    }
+}
+
+// status and first line of the body of a failed request (the server
+// answers "Puzzle inputs differ by user. Please log in..." with 400 for
+// a wrong session and 404 for puzzles that aren't unlocked yet)
+fn describe_error(response: &Response) -> String {
+    let message: &str = response.body.lines().next().unwrap_or("").trim();
+
+    return match (response.status, message.is_empty()) {
+        (_, true) => format!("status {}", response.status),
+        (400, false) => format!("status 400 ({} Is the session expired?)",
+                                message),
+        (_, false) => format!("status {} ({})", response.status, message),
+    };
+}
+
+pub struct Submission {
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+impl Submission {
+    pub fn new(day: u8, part: u8, answer: &Answer, verdict: &Verdict) ->
+        Submission {
+        return Submission{
+            timestamp: history::timestamp(),
+            day: day,
+            part: part,
+            answer: answer.clone(),
+            verdict: verdict.clone(),
+        };
+    }
+
+    fn parse(line: &str) -> Option<Submission> {
+        let components: Vec<&str> = line.split_whitespace().collect();
+        let [timestamp, day, part, answer, verdict] =
+            components.as_slice() else {
+            return None;
+        };
+
+        return Some(Submission{
+            timestamp: timestamp.parse().ok()?,
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            answer: Answer::parse(answer),
+            verdict: Verdict::from_name(verdict)?,
+        });
+    }
+
+    fn to_line(&self) -> String {
+        return format!("{} {} {} {} {}\n", self.timestamp, self.day,
+                       self.part, self.answer, self.verdict.name());
+    }
+}
+
+pub struct Submissions {
+    submissions: Vec<Submission>,  // in the order they were submitted
+}
+
+impl Submissions {
+    // read the record of submissions; a missing file is an empty record,
+    // malformed lines are reported with their number
+    pub fn read(path: &str) -> Result<Submissions, String> {
+        let submissions: Vec<Submission> =
+            records::read(path, "submissions", FORMAT, Submission::parse)?;
+
+        return Ok(Submissions{submissions: submissions});
+    }
+
+    // why an answer can't be right judging from earlier verdicts of the
+    // same puzzle (None if it's worth submitting)
+    pub fn reject(&self, day: u8, part: u8, answer: &Answer) ->
+        Option<String> {
+        let verdicts = self.submissions.iter().filter(
+            |s| s.day == day && s.part == part && s.verdict.is_final());
+
+        for submission in verdicts {
+            let known: &Answer = &submission.answer;
+
+            let reason: String = match &submission.verdict {
+                Verdict::Correct if known == answer => format!(
+                    "{} was already accepted as correct", known),
+                Verdict::Correct => format!(
+                    "the puzzle was already solved with {}", known),
+                _ if known == answer => format!(
+                    "{} was already submitted: {}", known,
+                    submission.verdict),
+                Verdict::TooHigh if is_at_least(answer, known) => format!(
+                    "{} was already too high", known),
+                Verdict::TooLow if is_at_least(known, answer) => format!(
+                    "{} was already too low", known),
+                _ => continue,
+            };
+
+            return Some(format!("Not submitting {} for day {}, puzzle {}: \
+                                 {}", answer, day, part, reason));
+        }
+
+        return None;
+    }
+}
+
+// whether both answers are numbers and the first one isn't smaller
+fn is_at_least(a: &Answer, b: &Answer) -> bool {
+    let number = |answer: &Answer| answer.to_string().parse::<i128>().ok();
+
+    return match (number(a), number(b)) {
+        (Some(a), Some(b)) => a >= b,
+        _ => false,
+    };
+}
+
+// answers are a single field of a line of the record, so they can't
+// contain whitespace or '#' (which starts a comment); the answers of the
+// puzzles never do
+pub fn check_answer(answer: &Answer) -> Result<(), String> {
+    let text: String = answer.to_string();
+
+    if text.is_empty() || text.contains(|c: char| c.is_whitespace() ||
+                                        c == '#') {
+        return Err(format!("Invalid answer '{}': answers can't be empty or \
+                            contain whitespace or '#'", text));
+    }
+
+    return Ok(());
+}
+
+// append a submission to the record (creating it with a header
+// if it doesn't exist yet)
+pub fn record(path: &str, submission: &Submission) -> Result<(), String> {
+    return records::append(path, "submissions", HEADER,
+                           &[submission.to_line()]);
+}
@@ -0,0 +1,33 @@
+// parse a simple INI file of "key = value" lines, where lines starting
+// with '#' or ';' are comments; all settings are top-level, so "[section]"
+// headers are errors
+pub fn parse_ini(file_content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+
+    for (i, mut line) in file_content.split("\n").enumerate() {
+        line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            return Err(format!("line {}: sections aren't supported, got '{}'",
+                               i + 1, line));
+        }
+
+        let (key, value): (&str, &str) = match line.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() =>
+                (key.trim(), value.trim()),
+            _ => return Err(format!(
+                "line {}: expected 'key = value', got '{}'", i + 1, line)),
+        };
+
+        // values may optionally be quoted
+        let value: &str = value.strip_prefix('"').and_then(
+            |v| v.strip_suffix('"')).unwrap_or(value);
+
+        entries.push((key.to_string(), value.to_string()));
+    }
+
+    return Ok(entries);
+}
@@ -0,0 +1,283 @@
+// settings of the runner; read from an optional config file in the working
+// directory ("aoc.ini", i.e. in the repo root, which relative paths like
+// the input directory are resolved against as well) and in
+// "$XDG_CONFIG_HOME/adventofcode23/config.ini", where the settings of the
+// repo override the user wide ones and command line options override both
+// of them
+
+mod helpers;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+// relative to the working directory
+pub const REPO_CONFIG_FILE: &str = "aoc.ini";
+
+// all settings with their command line option and description
+pub const SETTINGS: [(&str, &str, &str); 8] = [
+    ("input_dir", "--input-dir", "directory of the dayN.txt puzzle inputs"),
+    ("answers", "--answers", "file of the expected answers"),
+    ("format", "--format", "output format (text or json)"),
+    ("timeout", "--timeout", "max. seconds per solver (0 for no limit)"),
+    ("jobs", "--jobs", "number of solvers to run in parallel"),
+    ("cache_dir", "--cache-dir", "directory of cached answers (empty: off)"),
+    ("session", "--session", "session cookie of adventofcode.com"),
+    ("base_url", "--base-url", "http:// URL of a TLS proxy to the server"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+pub struct Config {
+    pub input_dir: String,
+    pub answers_file: Option<String>,
+    pub format: OutputFormat,
+    pub timeout: Option<Duration>,
+    pub jobs: usize,
+    pub cache_dir: Option<String>,         // None if caching is disabled
+    pub session: Option<String>,           // for fetch and submit
+    pub base_url: Option<String>,          // http:// proxy to the server
+                                           // (no default, the client
+                                           // doesn't speak HTTPS)
+    sources: Vec<(&'static str, String)>,  // where each setting was set
+}
+
+impl Config {
+    pub fn default() -> Config {
+        return Config{
+            input_dir: String::from("inputs"),
+            answers_file: None,
+            format: OutputFormat::Text,
+            timeout: None,
+            jobs: 1,
+            cache_dir: Some(String::from(".cache")),
+            session: None,
+            base_url: None,
+            sources: Vec::new(),
+        };
+    }
+
+    // read the default settings and apply all existing config files
+    pub fn load() -> Result<Config, String> {
+        let mut config: Config = Config::default();
+
+        let mut config_files: Vec<PathBuf> = Vec::new();
+        if let Some(user_config_file) = get_user_config_file() {
+            config_files.push(user_config_file);
+        }
+        config_files.push(PathBuf::from(REPO_CONFIG_FILE));
+
+        for config_file in config_files {
+            if config_file.is_file() {
+                config.apply_file(&config_file)?;
+            }
+        }
+
+        return Ok(config);
+    }
+
+    fn apply_file(&mut self, path: &PathBuf) -> Result<(), String> {
+        let source: String = path.display().to_string();
+
+        let file_content: String = fs::read_to_string(path).map_err(
+            |err| format!("Couldn't read config file '{}': {}", source, err))?;
+
+        let entries: Vec<(String, String)> = helpers::parse_ini(&file_content)
+            .map_err(|err| format!("{}: {}", source, err))?;
+
+        for (key, value) in entries {
+            self.set(&key, &value, &source).map_err(
+                |err| format!("{}: {}", source, err))?;
+        }
+
+        return Ok(());
+    }
+
+    // change a setting and remember where the new value came from
+    pub fn set(&mut self, key: &str, value: &str, source: &str) ->
+        Result<(), String> {
+        let invalid_value =
+            || format!("invalid value '{}' for '{}'", value, key);
+
+        let key: &'static str = match key {
+            "input_dir" => {
+                self.input_dir = value.to_string();
+                "input_dir"
+            },
+            "answers" => {
+                self.answers_file = Some(value.to_string());
+                "answers"
+            },
+            "format" => {
+                self.format = match value {
+                    "text" => OutputFormat::Text,
+                    "json" => OutputFormat::Json,
+                    _ => return Err(invalid_value()),
+                };
+                "format"
+            },
+            "timeout" => {
+                let secs: f64 = value.parse().map_err(|_| invalid_value())?;
+                if !secs.is_finite() || secs < 0.0 {
+                    return Err(invalid_value());
+                }
+
+                self.timeout = if secs == 0.0 {
+                    None
+                } else {
+                    Some(Duration::from_secs_f64(secs))
+                };
+                "timeout"
+            },
+            "jobs" => {
+                self.jobs = match value.parse() {
+                    Ok(jobs) if jobs > 0 => jobs,
+                    _ => return Err(invalid_value()),
+                };
+                "jobs"
+            },
+            "cache_dir" => {
+                self.cache_dir = match value {
+                    "" => None,
+                    _ => Some(value.to_string()),
+                };
+                "cache_dir"
+            },
+            "session" => {
+                self.session = match value {
+                    "" => None,
+                    _ => Some(value.to_string()),
+                };
+                "session"
+            },
+            "base_url" => {
+                if value.starts_with("https://") {
+                    return Err(format!(
+                        "invalid value '{}' for 'base_url': HTTPS isn't \
+                         supported, use the http:// URL of a local TLS \
+                         proxy to https://adventofcode.com", value));
+                }
+                if !value.is_empty() && !value.starts_with("http://") {
+                    return Err(invalid_value());
+                }
+
+                self.base_url = match value.trim_end_matches('/') {
+                    "" => None,
+                    url => Some(url.to_string()),
+                };
+                "base_url"
+            },
+            _ => return Err(format!("unknown setting '{}'", key)),
+        };
+
+        self.sources.retain(|(k, _)| *k != key);
+        self.sources.push((key, source.to_string()));
+
+        return Ok(());
+    }
+
+    // path to the puzzle input of a day
+    pub fn input_path(&self, day: u8) -> String {
+        let path: PathBuf =
+            PathBuf::from(&self.input_dir).join(format!("day{}.txt", day));
+        return path.display().to_string();
+    }
+
+    // record of the answers submitted with the session (next to the
+    // inputs, as both belong to the account)
+    pub fn submissions_path(&self) -> String {
+        let path: PathBuf =
+            PathBuf::from(&self.input_dir).join("submissions.txt");
+        return path.display().to_string();
+    }
+
+    // all settings with their effective value and where they were set
+    pub fn show(&self) -> Vec<(&'static str, String, String)> {
+        let mut settings: Vec<(&'static str, String, String)> = Vec::new();
+
+        let format: &str = match self.format {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+        };
+
+        let timeout: String = match self.timeout {
+            Some(timeout) => timeout.as_secs_f64().to_string(),
+            None => String::from("0"),
+        };
+
+        // the session is as good as a password, so it isn't shown
+        let session: &str = match self.session {
+            Some(_) => "(set)",
+            None => "",
+        };
+
+        let values: [String; 8] = [
+            self.input_dir.clone(),
+            self.answers_file.clone().unwrap_or_default(),
+            format.to_string(),
+            timeout,
+            self.jobs.to_string(),
+            self.cache_dir.clone().unwrap_or_default(),
+            session.to_string(),
+            self.base_url.clone().unwrap_or_default(),
+        ];
+
+        for ((key, _, _), value) in SETTINGS.iter().zip(values) {
+            let source: String =
+                match self.sources.iter().find(|(k, _)| k == key) {
+                    Some((_, source)) => source.clone(),
+                    None => String::from("default"),
+                };
+
+            settings.push((key, value, source));
+        }
+
+        return settings;
+    }
+}
+
+// apply all settings passed as command line options (i.e. "--jobs 4")
+// and return the remaining arguments; options the command (the first
+// remaining argument) defines itself are left to the command
+pub fn apply_cli_options(config: &mut Config, args: &[String],
+                         has_option: fn(&str, &str) -> bool) ->
+    Result<Vec<String>, String> {
+    let mut remaining_args: Vec<String> = Vec::new();
+    let mut args_iter = args.iter();
+
+    while let Some(arg) = args_iter.next() {
+        let setting = SETTINGS.iter().find(|(_, option, _)| option == arg);
+        let is_command_option: bool = remaining_args.first().is_some_and(
+            |command| has_option(command, arg));
+
+        match setting {
+            Some(_) if is_command_option => remaining_args.push(arg.clone()),
+            Some((key, option, _)) => {
+                let value: &String = args_iter.next().ok_or(
+                    format!("Missing value for option '{}'", option))?;
+
+                config.set(key, value, "command line").map_err(
+                    |err| format!("Option '{}': {}", option, err))?;
+            },
+            None => remaining_args.push(arg.clone()),
+        }
+    }
+
+    return Ok(remaining_args);
+}
+
+// user wide config file; falls back to ~/.config if XDG_CONFIG_HOME
+// isn't set (as defined by the XDG Base Directory Specification)
+fn get_user_config_file() -> Option<PathBuf> {
+    let config_home: PathBuf = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    return Some(config_home.join("adventofcode23").join("config.ini"));
+}
@@ -1,7 +1,7 @@
 const FILE_RADIX: u32 = 10; // digits in file are decimal numbers (Base10)
 
 // all possible number words
-const NUMBER_WORDS: [&str; 9] =
+pub const NUMBER_WORDS: [&str; 9] =
     ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
 
 // corresponding number characters to all possible number words
@@ -18,13 +18,13 @@ pub fn get_first_number_char(line: impl Iterator<Item = char>) ->
             }
         }
 
-        return Err("Couldn't find any number character in the provided line!");
+        return Err("a digit in the line");
 }
 
 // iterate over every character in line and return either the
 // first number word or number character, depending on which comes first
 pub fn get_first_number_word(line: &str) -> Result<u32, &'static str> {
-    for (i, chr) in line.chars().enumerate() {
+    for (i, chr) in line.char_indices() {
         if chr.is_alphabetic() {
             // if character is alphabetic, iterate over every number word
             // and check if the current character marks the start of the
@@ -34,7 +34,7 @@ pub fn get_first_number_word(line: &str) -> Result<u32, &'static str> {
                 let word_end_idx: usize = i + number.len();
                 let word_inbounds: bool = word_end_idx < line.len();
 
-                if word_inbounds && *number == &line[i..word_end_idx] {
+                if word_inbounds && line[i..].starts_with(number) {
                     let num_char_of_word: char = NUMBER_CHAR_OF_WORD[j];
                     return Ok(num_char_of_word.to_digit(FILE_RADIX).unwrap());
                 }
@@ -46,24 +46,25 @@ pub fn get_first_number_word(line: &str) -> Result<u32, &'static str> {
         }
     }
 
-    return Err("Couldn't find any number word in the provided line!");
+    return Err("a digit or digit word in the line");
 }
 
 // iterate over every character in line in reverse order and return either the
 // first number word or number character, depending on which comes first
 pub fn get_last_number_word(line: &str) -> Result<u32, &'static str> {
-    for (mut i, chr) in line.chars().rev().enumerate() {
-        i = line.len() - 1 - i;
+    for (i, chr) in line.char_indices().rev() {
+        // end index of the current character (which might span more
+        // than one byte, so the line can't be sliced at i+1)
+        let chr_end_idx: usize = i + chr.len_utf8();
 
         if chr.is_alphabetic() {
             for (j, number) in NUMBER_WORDS.iter().enumerate() {
                 let word_start_idx: isize =
-                    i as isize - number.len() as isize + 1;
+                    chr_end_idx as isize - number.len() as isize;
 
                 let word_inbounds: bool = word_start_idx > 0;
 
-                if word_inbounds && *number ==
-                   &line[word_start_idx as usize..i+1] {
+                if word_inbounds && line[..chr_end_idx].ends_with(number) {
                     let num_char_of_word: char = NUMBER_CHAR_OF_WORD[j];
                     return Ok(num_char_of_word.to_digit(FILE_RADIX).unwrap());
                 }
@@ -73,5 +74,5 @@ pub fn get_last_number_word(line: &str) -> Result<u32, &'static str> {
         }
     }
 
-    return Err("Couldn't find any number word in the provided line!");
+    return Err("a digit or digit word in the line");
 }
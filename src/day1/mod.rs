@@ -2,44 +2,80 @@
 
 mod helpers;
 
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+pub use helpers::NUMBER_WORDS;
+
 use helpers::get_first_number_char;
 use helpers::get_first_number_word;
 use helpers::get_last_number_word;
 
-pub fn puzzle1(file_content: String) -> String {
+const DAY: u8 = 1;
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content));
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content));
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)[..], lap, solve1, solve2);
+}
+
+// the non-empty lines of the input, which both puzzles search for digits
+pub fn parse(file_content: &str) -> Vec<Scanner<'_>> {
+    return Scanner::new(file_content).lines().into_iter().filter(
+        |line| !line.is_empty()).collect();
+}
+
+pub fn solve1(lines: &[Scanner]) -> Result<Answer, SolveError> {
     let mut total_sum: u32 = 0;
 
-    for mut line in file_content.split("\n") {
-        line = line.trim();   // strip line of whitespace
-        if line.is_empty() {  // skip empty lines
-            continue;
-        }
+    for start in lines {
+        // the digits are searched in the whole line, so a copy of it is
+        // consumed at once; errors point at the start of the line
+        let mut scanner: Scanner = *start;
+        let line: &str = scanner.rest();
 
-        let first_num: u32 = get_first_number_char(line.chars()).unwrap();
-        let last_num:  u32 = get_first_number_char(line.chars().rev()).unwrap();
+        let first_num: u32 = get_first_number_char(line.chars()).map_err(
+            |msg| start.error(msg).invalid_input(DAY))?;
+        let last_num:  u32 = get_first_number_char(line.chars().rev()).map_err(
+            |msg| start.error(msg).invalid_input(DAY))?;
 
         // combine first and last number and add them to total sum
         total_sum += first_num * 10 + last_num;
     }
 
-    return total_sum.to_string();
+    return Ok(Answer::from(total_sum));
 }
 
-pub fn puzzle2(file_content: String) -> String {
+pub fn solve2(lines: &[Scanner]) -> Result<Answer, SolveError> {
     let mut total_sum: u32 = 0;
 
-    for mut line in file_content.split("\n") {
-        line = line.trim();   // strip line of whitespace
-        if line.is_empty() {  // skip empty lines
-            continue;
-        }
+    for start in lines {
+        // the digits are searched in the whole line, so a copy of it is
+        // consumed at once; errors point at the start of the line
+        let mut scanner: Scanner = *start;
+        let line: &str = scanner.rest();
 
-        let first_num: u32 = get_first_number_word(line).unwrap();
-        let last_num:  u32 = get_last_number_word(line).unwrap();
+        let first_num: u32 = get_first_number_word(line).map_err(
+            |msg| start.error(msg).invalid_input(DAY))?;
+        let last_num:  u32 = get_last_number_word(line).map_err(
+            |msg| start.error(msg).invalid_input(DAY))?;
 
         // combine first and last number and add them to total sum
         total_sum += first_num * 10 + last_num;
     }
 
-    return total_sum.to_string();
+    return Ok(Answer::from(total_sum));
 }
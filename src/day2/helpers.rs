@@ -1,73 +1,74 @@
-use std::collections::HashMap;
+use alloc::vec::Vec;
 
-const GAME_ID_STR:       &str = "Game ";
-const END_OF_GAMEID_STR: &str = ": ";
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::scan::ScanError;
+use crate::scan::Scanner;
 
-// get GameID number from line
-pub fn get_gameid(game: &str) -> u32 {
-    // find index of Game ID number x (ID look like: "Game x:")
-    assert!(game.starts_with("Game "));
-    assert!(game.find(END_OF_GAMEID_STR).is_some());
+use super::DAY;
 
-    // start index of x
-    let gameid_start_idx: usize = GAME_ID_STR.len();
-
-    // end idx of x
-    let gameid_end_idx:   usize = game.find(END_OF_GAMEID_STR).unwrap();
+const GAME_ID_STR:       &str = "Game ";
+const END_OF_GAMEID_STR: &str = ": ";
 
-    // extract GameID from string and convert it to an actual number
-    let gameid: u32 =
-        (&game[gameid_start_idx..gameid_end_idx]).parse().unwrap();
+// colors of all cubes in the bag
+pub const COLORS: [&str; 3] = ["red", "green", "blue"];
 
-    return gameid;
+pub struct Game {
+    pub id: u32,                      // GameID number
+    pub draws: Vec<Vec<(u32, usize)>>,  // number and color (index into
+                                      // COLORS) of all cubes of every draw
 }
 
-pub fn strip_gameid(game: &str) -> &str {
-    assert!(game.find(END_OF_GAMEID_STR).is_some());
-
-    let end_idx_of_gameid: usize =
-        game.find(END_OF_GAMEID_STR).unwrap() + END_OF_GAMEID_STR.len();
-    let game_without_id: &str = &game[end_idx_of_gameid..];
-
-    return game_without_id;
+// parse a game ("Game ID: DRAW; DRAW") with its draws of color draws
+// ("COUNT COLOR, COUNT COLOR")
+pub fn parse_game(line: &mut Scanner) -> Result<Game, SolveError> {
+    line.expect_literal(GAME_ID_STR).map_err(|err| err.invalid_input(DAY))?;
+    let gameid: u32 = line.take_uint().map_err(|err| err.invalid_input(DAY))?;
+    line.expect_literal(END_OF_GAMEID_STR)
+        .map_err(|err| err.invalid_input(DAY))?;
+
+    // draws are separated by "; ", the color draws of a draw by ", "
+    let draws: Vec<Vec<(u32, usize)>> = line.sep_by(
+        "; ", |draw| draw.sep_by(", ", get_draw_components))
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.expect_end().map_err(|err| err.invalid_input(DAY))?;
+
+    return Ok(Game{id: gameid, draws: draws});
 }
 
 // get number of cubes and color of cubes for current color draw
-pub fn get_draw_components(draw: &str) -> (u32, &str) {
-    let draw_components: Vec<&str> = draw.split(" ").collect();
-
-    let draw_num:    u32 = draw_components[0].parse().unwrap();
-    let draw_color: &str = draw_components[1];
-
-    return (draw_num, draw_color);
+fn get_draw_components(draw: &mut Scanner) ->
+    Result<(u32, usize), ScanError> {
+    let draw_num: u32 = draw.take_uint()?;
+    draw.expect_literal(" ")?;
+
+    let color_start: Scanner = *draw;
+    let draw_color: &str = draw.take_while(|c| c.is_ascii_lowercase());
+    let color_idx: usize = match COLORS.iter().position(|c| *c == draw_color) {
+        Some(color_idx) => color_idx,
+        None => return Err(color_start.error("a color of red, green or blue")),
+    };
+
+    return Ok((draw_num, color_idx));
 }
 
 // returns whether or not the game is legal/possible
 // (i.e. if no more cubes than the max. allowed number of cubes
 //  for each color were drawn in any of the draws)
-pub fn is_game_possible(game: &str) -> bool {
+pub fn is_game_possible(game: &Game) -> bool {
     const MAX_RED_CUBES:   u32 = 12;
     const MAX_GREEN_CUBES: u32 = 13;
     const MAX_BLUE_CUBES:  u32 = 14;
 
-    let max_color_draws: HashMap<&str, u32> = HashMap::from([
-        ("red", MAX_RED_CUBES),
-        ("green", MAX_GREEN_CUBES),
-        ("blue", MAX_BLUE_CUBES),
-    ]);
-
-    let game_without_id: &str = strip_gameid(game);
-
-    // iterate over draws of current game (separated by "; ")
-    for draw in game_without_id.split("; ") {
-        // iterate over color draws of current draw (separated by ", ")
-        for color_draw in draw.split(", ") {
-            // get number of cubes and color of cubes for current color draw
-            let (draw_num, draw_color): (u32, &str) =
-                get_draw_components(color_draw);
+    // max. number of cubes of every color (in the order of COLORS)
+    let max_color_draws: [u32; COLORS.len()] =
+        [MAX_RED_CUBES, MAX_GREEN_CUBES, MAX_BLUE_CUBES];
 
+    // iterate over all color draws of all draws of the game
+    for draw in &game.draws {
+        for (draw_num, draw_color) in draw {
             // check if number of drawn cubes is legal
-            if draw_num > max_color_draws[draw_color] {
+            if *draw_num > max_color_draws[*draw_color] {
                 return false;
             }
         }
@@ -76,41 +77,31 @@ pub fn is_game_possible(game: &str) -> bool {
     return true;
 }
 
-pub fn calc_power_of_minimum_cubeset(game: &str) -> u32 {
-    let mut power_of_minimum_cubeset: u32 = 1;
-
-    let mut minimum_cubeset: HashMap<&str, u32> = HashMap::from([
-        ("red", 0),
-        ("green", 0),
-        ("blue", 0),
-    ]);
-
-    // remove the GameID from the game string
-    let game_without_id: &str = strip_gameid(game);
-
-    // iterate over draws of current game (separated by "; ")
-    for draw in game_without_id.split("; ") {
-        // iterate over color draws of current draw (separated by ", ")
-        for color_draw in draw.split(", ") {
-            // get number of cubes and color of cubes for current color draw
-            let (draw_num, draw_color): (u32, &str) =
-                get_draw_components(color_draw);
+pub fn calc_power_of_minimum_cubeset(game: &Game) -> Result<u64, SolveError> {
+    let mut power_of_minimum_cubeset: u64 = 1;
 
-            assert!(minimum_cubeset.contains_key(draw_color));
+    // min. number of cubes of every color (in the order of COLORS)
+    let mut minimum_cubeset: [u32; COLORS.len()] = [0; COLORS.len()];
 
+    // iterate over all color draws of all draws of the game
+    for draw in &game.draws {
+        for (draw_num, draw_color) in draw {
             // if the number of drawn cubes for the current color is bigger
             // than the previously biggest draw number, update it
-            if draw_num > minimum_cubeset[draw_color] {
-                minimum_cubeset.insert(draw_color, draw_num);
+            if *draw_num > minimum_cubeset[*draw_color] {
+                minimum_cubeset[*draw_color] = *draw_num;
             }
         }
     }
 
     // iterate over all color keys and multiply them all,
     // which equals the "power" of the cube set
-    for (_color, minimum_num) in minimum_cubeset {
-        power_of_minimum_cubeset *= minimum_num;
+    for minimum_num in minimum_cubeset {
+        power_of_minimum_cubeset = checked!(
+            DAY, "calc_power_of_minimum_cubeset",
+            power_of_minimum_cubeset.checked_mul(minimum_num as u64),
+            power_of_minimum_cubeset * minimum_num as u64);
     }
 
-    return power_of_minimum_cubeset;
+    return Ok(power_of_minimum_cubeset);
 }
@@ -2,43 +2,77 @@
 
 mod helpers;
 
-use helpers::get_gameid;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+pub use helpers::COLORS;
+pub use helpers::Game;
+
+use helpers::parse_game;
 use helpers::is_game_possible;
 use helpers::calc_power_of_minimum_cubeset;
 
-pub fn puzzle1(file_content: String) -> String {
-    let mut gameid_sum: u32 = 0;
+const DAY: u8 = 2;
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?[..], lap, solve1, solve2);
+}
 
-    for mut game in file_content.split("\n") {
-        game = game.trim();   // strip line of whitespace
-        if game.is_empty() {  // skip empty lines
+// the games of all non-empty lines
+pub fn parse(file_content: &str) -> Result<Vec<Game>, SolveError> {
+    let mut games: Vec<Game> = Vec::new();
+
+    for mut line in Scanner::new(file_content).lines() {
+        if line.is_empty() {  // skip empty lines
             continue;
         }
 
-        let gameid: u32 = get_gameid(game);
+        games.push(parse_game(&mut line)?);
+    }
 
+    return Ok(games);
+}
+
+pub fn solve1(games: &[Game]) -> Result<Answer, SolveError> {
+    let mut gameid_sum: u32 = 0;
+
+    for game in games {
         if is_game_possible(game) {
-            gameid_sum += gameid;
+            gameid_sum += game.id;
         }
     }
 
-    return gameid_sum.to_string();
+    return Ok(Answer::from(gameid_sum));
 }
 
-pub fn puzzle2(file_content: String) -> String {
-    let mut power_of_sets_sum: u32 = 0;
-
-    for mut game in file_content.split("\n") {
-        game = game.trim();   // strip line of whitespace
-        if game.is_empty() {  // skip empty lines
-            continue;
-        }
+pub fn solve2(games: &[Game]) -> Result<Answer, SolveError> {
+    let mut power_of_sets_sum: u64 = 0;
 
+    for game in games {
         // calculate the power of the minimum set of cubes of the current game
-        let power_of_minimum_cubeset: u32 = calc_power_of_minimum_cubeset(game);
+        let power_of_minimum_cubeset: u64 =
+            calc_power_of_minimum_cubeset(game)?;
 
-        power_of_sets_sum += power_of_minimum_cubeset;
+        power_of_sets_sum = checked!(DAY, "puzzle2",
+            power_of_sets_sum.checked_add(power_of_minimum_cubeset),
+            power_of_sets_sum + power_of_minimum_cubeset);
     }
 
-    return power_of_sets_sum.to_string();
+    return Ok(Answer::from(power_of_sets_sum));
 }
@@ -1,11 +1,29 @@
-use std::collections::HashMap;
+use alloc::format;
+use alloc::vec::Vec;
 
-#[derive(Eq, Hash, PartialEq)]
+use crate::error::SolveError;
+use crate::fxhash::FxHashMap;
+use crate::scan::Scanner;
+
+use super::DAY;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Field {
     line : usize,   // line/row of the field
     column: usize,  // column of the field
 }
 
+impl Field {
+    pub fn line(&self) -> usize {
+        return self.line;
+    }
+
+    pub fn column(&self) -> usize {
+        return self.column;
+    }
+}
+
+#[derive(Debug)]
 pub struct PartNumber {
     pub num: u32,                     // part number
     pub adjacent_fields: Vec<Field>,  // all adjacent fields of the part number
@@ -29,9 +47,36 @@ impl PartNumber {
     }
 }
 
+// collect all lines of file into vector, trimming off whitespace
+// and skipping empty lines; all lines need to be of the same length
+pub fn get_schematic_lines(file_content: &str) ->
+    Result<Vec<&str>, SolveError> {
+    let mut file_lines: Vec<&str> = Vec::new();
+
+    for mut line in Scanner::new(file_content).lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let line_start: Scanner = line;
+        let row: &str = line.rest();
+
+        if let Some(first) = file_lines.first().filter(
+            |first| first.len() != row.len()) {
+            return Err(line_start.error(&format!(
+                "a line of {} fields like the first line of the schematic",
+                first.len())).invalid_input(DAY));
+        }
+
+        file_lines.push(row);
+    }
+
+    return Ok(file_lines);
+}
 
 // get all part numbers of the current line
-pub fn get_part_numbers(lines: &Vec<&str>, line_num: usize) -> Vec<PartNumber> {
+pub fn get_part_numbers(lines: &Vec<&str>, line_num: usize) ->
+    Result<Vec<PartNumber>, SolveError> {
     // maximum line column (assumes that all lines of file are of same length)
     let max_right_idx: usize = lines[0].len() - 1;
 
@@ -40,46 +85,37 @@ pub fn get_part_numbers(lines: &Vec<&str>, line_num: usize) -> Vec<PartNumber> {
 
     let mut part_numbers: Vec<PartNumber> = Vec::new();
 
-    let mut i_part_num: usize;
-    let mut j_part_num: usize;
+    // offsets of the scanner are the columns of the line
+    let mut scanner: Scanner = Scanner::new(lines[line_num]);
 
-    // treat line string as a byte slice/array, which is safe here
-    // since input file only contains ASCII characters
-    let line_array: &[u8] = lines[line_num].as_bytes();
-    let mut line_idx: usize = 0;
-
-    while line_idx <= max_right_idx {
-        if line_array[line_idx].is_ascii_digit() {
-            // mark start index of the found part number
-            i_part_num = line_idx;
-
-            // keep going until the end of the current part number is reached
-            while line_idx <= max_right_idx &&
-                  line_array[line_idx].is_ascii_digit() {
-                line_idx += 1;
-            }
+    loop {
+        // skip everything up to the next part number
+        scanner.take_while(|c| !c.is_ascii_digit());
+        if scanner.is_empty() {
+            break;
+        }
 
-            // mark end index of the found part number
-            j_part_num = line_idx - 1;
+        // mark start index of the found part number
+        let i_part_num: usize = scanner.offset();
 
-            // convert part number string slice to actual number
-            let part_number: u32 =
-                lines[line_num][i_part_num..=j_part_num].parse().unwrap();
+        // convert part number to actual number
+        let part_number: u32 = scanner.take_uint().map_err(
+            |err| err.invalid_input(DAY))?;
 
-            // calculate all adjacent fields of the current part number
-            let adjacent_fields: Vec<Field> =
-                get_adjacent_fields(
-                    line_num, i_part_num, j_part_num, max_right_idx, max_line);
+        // mark end index of the found part number
+        let j_part_num: usize = scanner.offset() - 1;
 
-            // add part number to vector
-            part_numbers.push(
-                PartNumber{num: part_number, adjacent_fields: adjacent_fields});
-        }
+        // calculate all adjacent fields of the current part number
+        let adjacent_fields: Vec<Field> =
+            get_adjacent_fields(
+                line_num, i_part_num, j_part_num, max_right_idx, max_line);
 
-        line_idx += 1;
+        // add part number to vector
+        part_numbers.push(
+            PartNumber{num: part_number, adjacent_fields: adjacent_fields});
     }
 
-    return part_numbers;
+    return Ok(part_numbers);
 }
 
 // calculate all adjacent fields of a part number based
@@ -116,16 +152,17 @@ pub fn get_symbol_from_line(field: &Field, lines: &Vec<&str>) -> u8 {
 }
 
 // calculate the gear ration sum
-pub fn calc_gear_ratio_sum(potential_gears: HashMap<Field, Vec<u32>>) -> u32 {
+pub fn calc_gear_ratio_sum(potential_gears: FxHashMap<Field, Vec<u32>>) -> u64 {
     const GEAR_PARTS: usize = 2;
 
-    let mut gear_ration_sum: u32 = 0;
+    // products of two u32 part numbers always fit into an u64
+    let mut gear_ration_sum: u64 = 0;
 
     for (_gear, part_numbers) in potential_gears {
         let is_gear: bool = part_numbers.len() == GEAR_PARTS;
 
         if is_gear {
-            gear_ration_sum += part_numbers[0] * part_numbers[1];
+            gear_ration_sum += part_numbers[0] as u64 * part_numbers[1] as u64;
         }
     }
 
@@ -1,23 +1,51 @@
 // Solutions to https://adventofcode.com/2023/day/3
 
-use std::collections::HashMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+use crate::fxhash::FxHashMap;
 
 mod helpers;
 
-use helpers::PartNumber;
-use helpers::Field;
-use helpers::get_part_numbers;
+pub use helpers::Field;
+pub use helpers::PartNumber;
+pub use helpers::get_part_numbers;
+pub use helpers::get_schematic_lines;
+
 use helpers::get_symbol_from_line;
 use helpers::calc_gear_ratio_sum;
 
-pub fn puzzle1(file_content: String) -> String {
-    let mut engine_part_sum: u32 = 0;
+const DAY: u8 = 3;
+
+// lines of the engine schematic with the part numbers of all lines
+pub struct Schematic<'a> {
+    pub lines: Vec<&'a str>,
+    pub part_numbers: Vec<PartNumber>,
+}
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?, lap, solve1, solve2);
+}
 
+pub fn parse(file_content: &str) -> Result<Schematic<'_>, SolveError> {
     // collect all lines of file into vector, trimming off whitespace
     // and skipping empty lines
-    let file_lines: Vec<&str> =
-        file_content.split("\n").map(|l| l.trim()).
-            filter(|l| !l.is_empty()).collect();
+    let file_lines: Vec<&str> = get_schematic_lines(file_content)?;
+    let mut part_numbers: Vec<PartNumber> = Vec::new();
 
     for (i, line) in file_lines.iter().enumerate() {
         if line.is_empty() {  // skip empty lines
@@ -25,67 +53,54 @@ pub fn puzzle1(file_content: String) -> String {
         }
 
         // get all part numbers of the current line
-        let part_numbers: Vec<PartNumber> = get_part_numbers(&file_lines, i);
-
-        // iterate over all part numbers of current line and check if it
-        // has an adjacent symbol; if so, add part number to total sum
-        for part_number in part_numbers {
-            if part_number.has_adjacent_symbol(&file_lines) {
-                engine_part_sum += part_number.num;
-            }
-        }        
+        part_numbers.extend(get_part_numbers(&file_lines, i)?);
     }
 
-    return engine_part_sum.to_string();
+    return Ok(Schematic{lines: file_lines, part_numbers: part_numbers});
 }
 
-pub fn puzzle2(file_content: String) -> String {
-    const GEAR_SYMBOL: u8 = b'*';
-    let mut potential_gears: HashMap<Field, Vec<u32>> = HashMap::new();
-
-    // collect all lines of file into vector, trimming off whitespace
-    // and skipping empty lines
-    let file_lines: Vec<&str> =
-        file_content.split("\n").map(|l| l.trim()).
-            filter(|l| !l.is_empty()).collect();
+pub fn solve1(schematic: &Schematic) -> Result<Answer, SolveError> {
+    let mut engine_part_sum: u64 = 0;
 
-    for (i, line) in file_lines.iter().enumerate() {
-        if line.is_empty() {  // skip empty lines
-            continue;
+    // iterate over all part numbers and check if it has an adjacent
+    // symbol; if so, add part number to total sum
+    for part_number in &schematic.part_numbers {
+        if part_number.has_adjacent_symbol(&schematic.lines) {
+            engine_part_sum += part_number.num as u64;
         }
+    }
 
-        // get all part numbers of the current line
-        let part_numbers: Vec<PartNumber> = get_part_numbers(&file_lines, i);
-
-        // iterate over all part numbers of current line and check if it
-        // has an adjacent symbol; if so, add part number to total sum
-        for part_number in part_numbers {
-            for field in part_number.adjacent_fields {
-                // get symbol of current field
-                let curr_symbol: u8 = get_symbol_from_line(&field, &file_lines);
-
-                // if current symbol is a gear ('*'), add it to the gear map
-                // and add the current part number to its adjacent number list
-                if curr_symbol == GEAR_SYMBOL {
-                    match potential_gears.get_mut(&field) {
-                        Some(num_map) => num_map.push(part_number.num),
-
-                        // if the gear isn't part of the map yet,
-                        // add it to the map and add the current
-                        // part number to its adjacent number list
-                        None =>
-                            match potential_gears.insert(
-                                field, vec![part_number.num]) {
-                                    Some(_) => (),
-                                    None => (),
-                            }
+    return Ok(Answer::from(engine_part_sum));
+}
+
+pub fn solve2(schematic: &Schematic) -> Result<Answer, SolveError> {
+    const GEAR_SYMBOL: u8 = b'*';
+    let mut potential_gears: FxHashMap<Field, Vec<u32>> = FxHashMap::default();
+
+    for part_number in &schematic.part_numbers {
+        for field in &part_number.adjacent_fields {
+            // get symbol of current field
+            let curr_symbol: u8 = get_symbol_from_line(field, &schematic.lines);
+
+            // if current symbol is a gear ('*'), add it to the gear map
+            // and add the current part number to its adjacent number list
+            if curr_symbol == GEAR_SYMBOL {
+                match potential_gears.get_mut(field) {
+                    Some(num_map) => num_map.push(part_number.num),
+
+                    // if the gear isn't part of the map yet, add it to the
+                    // map and add the current part number to its adjacent
+                    // number list
+                    None => {
+                        potential_gears.insert(
+                            *field, vec![part_number.num]);
                     }
                 }
             }
         }
     }
 
-    let gear_ration_sum: u32 = calc_gear_ratio_sum(potential_gears);
+    let gear_ration_sum: u64 = calc_gear_ratio_sum(potential_gears);
 
-    return gear_ration_sum.to_string();
+    return Ok(Answer::from(gear_ration_sum));
 }
@@ -1,105 +1,91 @@
-use std::collections::HashSet;
+use alloc::vec;
+use alloc::vec::Vec;
 
-pub const NUMBER_DELIMITER: &str = " ";
-pub const LINE_DELIMITER: &str = " | ";
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::fxhash::FxHashSet;
+use crate::scan::Scanner;
 
-const CARDID_START: usize = 1;
-
-// strip CardID ("Card x: ") from line and return the CardID number "x"
-pub fn strip_cardid(line: &str) -> (u32, &str) {
-    const CARDID_END_STR: &str = ": ";
-    const CARDID_STR: &str = "Card ";
-
-    assert!(line.find(CARDID_STR).is_some());
-    assert!(line.find(CARDID_END_STR).is_some());
-
-    let cardid_start_idx: usize =
-        line.find(CARDID_STR).unwrap() + CARDID_STR.len();
-
-    let cardid_end_idx: usize =
-        line.find(CARDID_END_STR).unwrap();
-
-    let line_without_cardid: &str =
-        &line[cardid_end_idx + CARDID_END_STR.len()..];
-    
-    let cardid_num: u32 =
-        ((&line[cardid_start_idx..cardid_end_idx]).trim()).parse().unwrap();
+use super::DAY;
 
-    return (cardid_num, line_without_cardid);
-}
-
-// parse the winning numbers from the current line and
-// add them to a set for fast lookups later
-pub fn get_winning_numbers(line: &str) -> HashSet<u32> {
-    assert!(line.find(LINE_DELIMITER).is_some());
-
-    let mut winning_numbers: HashSet<u32> = HashSet::new();
-
-    let line_delim_idx: usize = line.find(LINE_DELIMITER).unwrap();
-
-    let winning_number_str: &str = &line[0..line_delim_idx];
-
-    for mut num_str in winning_number_str.split(NUMBER_DELIMITER) {
-        num_str = num_str.trim();
-        if num_str.is_empty() {
-            continue;
-        }
-
-        assert!(num_str.parse::<u32>().is_ok());
-
-        let num: u32 = num_str.parse().unwrap();
-        winning_numbers.insert(num);
-    }
+const CARDID_START: usize = 1;
 
-    return winning_numbers;
+pub struct Card {
+    pub id: u32,                        // CardID number
+    pub winning_numbers: FxHashSet<u32>,  // set for fast lookups
+    pub drawn_numbers: Vec<u32>,
 }
 
-// isolate the list of drawn numbers from the line
-pub fn get_drawn_numbers_str(line: &str) -> &str {
-    assert!(line.find(LINE_DELIMITER).is_some());
-
-    let line_delim_end_idx: usize =
-        line.find(LINE_DELIMITER).unwrap() + LINE_DELIMITER.len();
-
-    return &line[line_delim_end_idx..];
+// parse a card ("Card ID: WINNING | DRAWN"), whose numbers may be aligned
+// with additional spaces
+pub fn parse_card(line: &mut Scanner) -> Result<Card, SolveError> {
+    const CARDID_STR: &str = "Card ";
+    const CARDID_END_STR: &str = ": ";
+    const LINE_DELIMITER: &str = "|";
+
+    line.expect_literal(CARDID_STR).map_err(|err| err.invalid_input(DAY))?;
+    line.take_while(|c| c == ' ');
+    let cardid_num: u32 = line.take_uint()
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.expect_literal(CARDID_END_STR)
+        .map_err(|err| err.invalid_input(DAY))?;
+
+    let winning_numbers: Vec<u32> = line.take_uints()
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.take_while(|c| c == ' ');
+    line.expect_literal(LINE_DELIMITER)
+        .map_err(|err| err.invalid_input(DAY))?;
+
+    let drawn_numbers: Vec<u32> = line.take_uints()
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.expect_end().map_err(|err| err.invalid_input(DAY))?;
+
+    return Ok(Card{
+        id: cardid_num,
+        winning_numbers: winning_numbers.into_iter().collect(),
+        drawn_numbers: drawn_numbers,
+    });
 }
 
 pub fn collect_won_scratchcards(
-    drawn_numbers: &str, card: u32, winning_numbers: &HashSet<u32>,
-    card_copies: &mut Vec<HashSet<u32>>) {
+    card: &Card, card_copies: &mut Vec<FxHashSet<u32>>) ->
+    Result<(), SolveError> {
+    // cards are looked up by their CardID, so they need to be in order
+    if card.id as usize != card_copies.len() {
+        return Err(SolveError::invalid_input(
+            DAY, "Expected the CardIDs to count up from 1"));
+    }
+
     // counter for all won scratchcards of current card
-    let mut won_scratchcard: u32 = card;
+    let mut won_scratchcard: u32 = card.id;
 
     // add the current card to the hashmap with an initial count of 1
         // since we've just found the first (original) copy of the card
-    card_copies.push(HashSet::new());
+    card_copies.push(FxHashSet::default());
 
     // iterate over all drawn numbers
-    for mut num_str in drawn_numbers.split(NUMBER_DELIMITER) {
-        num_str = num_str.trim();
-        if num_str.is_empty() {
-            continue;
-        }
-
-        // parse the current drawn number
-        let num: u32 = num_str.parse().unwrap();
-        if winning_numbers.contains(&num) {
+    for num in &card.drawn_numbers {
+        if card.winning_numbers.contains(num) {
             won_scratchcard += 1;
 
             // get mutable reference to the set of the current scratchcard
             // that stores all additional won copies of other scratchcards
-            let won_cards: &mut HashSet<u32> = &mut card_copies[card as usize];
+            let won_cards: &mut FxHashSet<u32> =
+                &mut card_copies[card.id as usize];
 
             // add the won additional scratchcard to the set
             won_cards.insert(won_scratchcard);
         }
     }
+
+    return Ok(());
 }
 
 // count the total scratchcards, including all the won scratchcards
-pub fn count_total_scratchcards(card_copies: &Vec<HashSet<u32>>) -> u32 {
+pub fn count_total_scratchcards(card_copies: &Vec<FxHashSet<u32>>) ->
+    Result<u64, SolveError> {
     // stores counts for all scratchcards based in their IDs (starting from 1)
-    let mut card_counts = vec![1; card_copies.len()];
+    let mut card_counts: Vec<u64> = vec![1; card_copies.len()];
 
      // first value in vector is just there to make indexing directly
      // with CardIDs possible (CardIDs start from 1), so this value
@@ -115,22 +101,29 @@ pub fn count_total_scratchcards(card_copies: &Vec<HashSet<u32>>) -> u32 {
                 continue;
             }
 
-            let won_cards: &HashSet<u32> = &card_copies[cmp_card];
-            let cmp_card_count: u32 = card_counts[cmp_card];
+            let won_cards: &FxHashSet<u32> = &card_copies[cmp_card];
+            let cmp_card_count: u64 = card_counts[cmp_card];
 
             // if the current card appears in the won cards for the current
             // comparison card, increment its counter by the counter of
             // the comparison card
             // (assumes that processing of file is top to bottom)
             if won_cards.contains(&(card as u32)) {
-                let card_count: &mut u32 = &mut card_counts[card];
-                *card_count += cmp_card_count;
+                let card_count: &mut u64 = &mut card_counts[card];
+                *card_count = checked!(DAY, "count_total_scratchcards",
+                    card_count.checked_add(cmp_card_count),
+                    *card_count + cmp_card_count);
             }
         }
     }
 
     // sum up all the final card counts for every card
-    let total_scratchcards: u32 = card_counts.iter().sum();
+    let mut total_scratchcards: u64 = 0;
+    for card_count in card_counts {
+        total_scratchcards = checked!(DAY, "count_total_scratchcards",
+            total_scratchcards.checked_add(card_count),
+            total_scratchcards + card_count);
+    }
 
-    return total_scratchcards;
+    return Ok(total_scratchcards);
 }
@@ -1,97 +1,114 @@
 // Solutions to https://adventofcode.com/2023/day/4
 
-use std::collections::HashSet;
-
 mod helpers;
 
-use helpers::NUMBER_DELIMITER;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::fxhash::FxHashSet;
+use crate::scan::Scanner;
 
-use helpers::strip_cardid;
-use helpers::get_winning_numbers;
-use helpers::get_drawn_numbers_str;
+pub use helpers::Card;
+
+use helpers::parse_card;
 use helpers::collect_won_scratchcards;
 use helpers::count_total_scratchcards;
 
-pub fn puzzle1(file_content: String) -> String {
-    let mut total_points: u32 = 0;
+const DAY: u8 = 4;
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?[..], lap, solve1, solve2);
+}
+
+// parse the CardID with the winning and drawn numbers of every card
+pub fn parse(file_content: &str) -> Result<Vec<Card>, SolveError> {
+    let mut cards: Vec<Card> = Vec::new();
 
-    for mut line in file_content.split("\n") {
-        line = line.trim();   // strip whitespace
+    for mut line in Scanner::new(file_content).lines() {
         if line.is_empty() {  // skip empty lines
             continue;
         }
 
-        let mut winning_number_count: u32 = 0;
+        cards.push(parse_card(&mut line)?);
+    }
 
-        // get the line content without the CardID part
-        let (_cardid_num, line_without_id): (u32, &str) = strip_cardid(line);
+    return Ok(cards);
+}
 
-        // collect all winning numbers in a set
-        let winning_numbers: HashSet<u32> =
-            get_winning_numbers(line_without_id);
+pub fn solve1(cards: &[Card]) -> Result<Answer, SolveError> {
+    let mut total_points: u64 = 0;
 
-        // isolate just the drawn numbers from the line
-        let drawn_numbers_str: &str = get_drawn_numbers_str(line_without_id);
+    for card in cards {
+        let mut winning_number_count: u32 = 0;
 
         // iterate over all drawn numbers and do a simple lookup if
         // the current drawn number is part of the winning numbers;
         // if so, update the count
-        for mut num_str in drawn_numbers_str.split(NUMBER_DELIMITER) {
-            num_str = num_str.trim();
-            if num_str.is_empty() {
-                continue;
-            }
-
-            let num: u32 = num_str.parse().unwrap();
-            if winning_numbers.contains(&num) {
+        for num in &card.drawn_numbers {
+            if card.winning_numbers.contains(num) {
                 winning_number_count += 1;
-
             }
         }
 
         // calculate the points for the current card
-        let points: u32 = if winning_number_count == 0 {
+        let points: u64 = if winning_number_count == 0 {
             0
         } else {
-            u32::pow(2, winning_number_count - 1)
+            checked!(DAY, "puzzle1",
+                u64::checked_pow(2, winning_number_count - 1),
+                u64::pow(2, winning_number_count - 1))
         };
 
-        total_points += points; 
+        total_points = checked!(DAY, "puzzle1",
+            total_points.checked_add(points), total_points + points);
     }
 
-    return total_points.to_string();
+    return Ok(Answer::from(total_points));
 }
 
-pub fn puzzle2(file_content: String) -> String {
-    // stores all won copies for every card;
-    // each vector index corresponds to a CardID;
-    // since the input file provides incremental CardIDs starting from 1,
-    // we can use the vector indices to look up a CardID
-    let mut card_copies: Vec<HashSet<u32>> = Vec::new();
+pub fn solve2(cards: &[Card]) -> Result<Answer, SolveError> {
+    let card_copies: Vec<FxHashSet<u32>> = won_card_copies(cards)?;
 
-    // push empty value into vector since CardIDs start from 1 and not from 0
-    card_copies.push(HashSet::new());
+    // count the total scratchcards, including all the won scratchcards
+    let total_scratchcards: u64 = count_total_scratchcards(&card_copies)?;
 
-    for mut line in file_content.split("\n") {
-        line = line.trim();   // strip whitespace
-        if line.is_empty() {  // skip empty lines
-            continue;
-        }
+    return Ok(Answer::from(total_scratchcards));
+}
 
-        let (cardid_num, line_without_id): (u32, &str) = strip_cardid(line);
+// collect all won copies for every card of the input
+pub fn collect_card_copies(file_content: &str) ->
+    Result<Vec<FxHashSet<u32>>, SolveError> {
+    return won_card_copies(&parse(file_content)?);
+}
 
-        let winning_numbers: HashSet<u32> =
-            get_winning_numbers(line_without_id);
+// collect all won copies for every card;
+// each vector index corresponds to a CardID;
+// since the input file provides incremental CardIDs starting from 1,
+// we can use the vector indices to look up a CardID
+fn won_card_copies(cards: &[Card]) -> Result<Vec<FxHashSet<u32>>, SolveError> {
+    let mut card_copies: Vec<FxHashSet<u32>> = Vec::new();
 
-        let drawn_numbers_str: &str = get_drawn_numbers_str(line_without_id);
+    // push empty value into vector since CardIDs start from 1 and not from 0
+    card_copies.push(FxHashSet::default());
 
+    for card in cards {
         // collect all won scratchcards for the current card
-        collect_won_scratchcards(
-            drawn_numbers_str, cardid_num, &winning_numbers, &mut card_copies);
+        collect_won_scratchcards(card, &mut card_copies)?;
     }
 
-    // count the total scratchcards, including all the won scratchcards
-    let total_scratchcards: u32 = count_total_scratchcards(&card_copies);
-
-    return total_scratchcards.to_string();
+    return Ok(card_copies);
 }
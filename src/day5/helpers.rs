@@ -1,22 +1,38 @@
-use std::ops::Range;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+use super::DAY;
+
+// destination offsets and source ranges of a map
+pub type Map = (Vec<i128>, Vec<Range<u64>>);
 
 const NUMBER_DELIMITER: &str = " ";
 const SEED_ID: &str = "seeds: ";
 
-// get all the seed ranges from the seed input line
-pub fn get_seed_ranges(lines: &Vec<&str>) -> Vec<Range<u64>> {
-    assert!(find_line_idx(SEED_ID, &lines).is_some());
-
-    let seed_line_idx: usize = find_line_idx(SEED_ID, &lines).unwrap();
-    let seed_line: &str = lines[seed_line_idx];
+// names of all categories a seed number gets mapped to (in order)
+pub const CATEGORIES: [&str; 8] = [
+    "seed", "soil", "fertilizer", "water",
+    "light", "temperature", "humidity", "location",
+];
 
+// get all the seed ranges from the seed input line
+pub fn get_seed_ranges(lines: &Vec<Scanner>) ->
+    Result<Vec<Range<u64>>, SolveError> {
     let mut seed_ranges: Vec<Range<u64>> = Vec::new();
 
-    let seed_line_without_id: &str = seed_line.strip_prefix(SEED_ID).unwrap();
-
-    let seed_vals: Vec<u64> = seed_line_without_id.split(
-        NUMBER_DELIMITER).map(|s| s.trim().parse().unwrap()).collect();
-    assert!(seed_vals.len() % 2 == 0);
+    let mut seed_line: Scanner = get_seed_line(lines)?;
+    let seed_vals: Vec<u64> = parse_numbers(&mut seed_line)?;
+    if !seed_vals.len().is_multiple_of(2) {
+        return Err(seed_line.error("a range length for every seed number")
+            .invalid_input(DAY));
+    }
 
     let mut seed_val_idx: usize = 0;
 
@@ -24,113 +40,198 @@ pub fn get_seed_ranges(lines: &Vec<&str>) -> Vec<Range<u64>> {
         let seed_val: u64 = seed_vals[seed_val_idx];
         let seed_range: u64 = seed_vals[seed_val_idx+1];
 
-        seed_ranges.push(seed_val..seed_val+seed_range);
+        let seed_range_end: u64 = checked!(DAY, "get_seed_ranges",
+            seed_val.checked_add(seed_range), seed_val + seed_range);
+
+        seed_ranges.push(seed_val..seed_range_end);
 
         seed_val_idx += 2;
     }
 
-    return seed_ranges;
+    return Ok(seed_ranges);
 }
 
 // get all seeds for which we need to find the lowest location number
-pub fn get_seeds(lines: &Vec<&str>) -> Vec<u64> {
-    assert!(find_line_idx(SEED_ID, &lines).is_some());
+pub fn get_seeds(lines: &Vec<Scanner>) -> Result<Vec<u64>, SolveError> {
+    let mut seeds: Vec<u64> = Vec::new();
 
-    let seed_line_idx: usize = find_line_idx(SEED_ID, &lines).unwrap();
-    let seed_line: &str = lines[seed_line_idx];
+    for seed_num in parse_numbers(&mut get_seed_line(lines)?)? {
+        seeds.push(seed_num);
+    }
 
-    let mut seeds: Vec<u64> = Vec::new();
+    return Ok(seeds);
+}
 
-    let seed_line_without_id: &str = seed_line.strip_prefix(SEED_ID).unwrap();
+// get the seed input line without its ID
+fn get_seed_line<'a>(lines: &Vec<Scanner<'a>>) ->
+    Result<Scanner<'a>, SolveError> {
+    let mut seed_line: Scanner = match find_line_idx(SEED_ID, lines) {
+        Some(idx) => lines[idx],
+        None => return Err(SolveError::invalid_input(
+            DAY, "Expected a line starting with 'seeds: '")),
+    };
 
-    for mut seed in seed_line_without_id.split(NUMBER_DELIMITER) {
-        seed = seed.trim();
-        let seed_num: u64 = seed.parse().unwrap();
+    seed_line.expect_literal(SEED_ID).unwrap();
 
-        seeds.push(seed_num);
-    }
+    return Ok(seed_line);
+}
+
+// parse all space separated numbers of a line
+fn parse_numbers(line: &mut Scanner) -> Result<Vec<u64>, SolveError> {
+    let nums: Vec<u64> = line.sep_by(NUMBER_DELIMITER, Scanner::take_uint)
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.expect_end().map_err(|err| err.invalid_input(DAY))?;
 
-    return seeds;
+    return Ok(nums);
 }
 
 // read the seed-to-soil map
-pub fn get_seed_to_soil_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_seed_to_soil_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const SEED_TO_SOIL_ID: &str = "seed-to-soil map:";
     return parse_map(SEED_TO_SOIL_ID, lines);
 }
 
 // read the soil-to-fertilizer map
-pub fn get_soil_to_fertilizer_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_soil_to_fertilizer_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const SOIL_TO_FERTILIZER_ID: &str = "soil-to-fertilizer map:";
     return parse_map(SOIL_TO_FERTILIZER_ID, lines);
 }
 
 // read the fertilizer-to-water map
-pub fn get_fertilizer_to_water_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_fertilizer_to_water_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const FERTILIZER_TO_WATER_ID: &str = "fertilizer-to-water map:";
     return parse_map(FERTILIZER_TO_WATER_ID, lines);
 }
 
 // read the water-to-light map
-pub fn get_water_to_light_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_water_to_light_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const WATER_TO_LIGHT_ID: &str = "water-to-light map:";
     return parse_map(WATER_TO_LIGHT_ID, lines);
 }
 
 // read the light-to-temperature map
-pub fn get_light_to_temperature_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_light_to_temperature_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const LIGHT_TO_TEMPERATURE_ID: &str = "light-to-temperature map:";
     return parse_map(LIGHT_TO_TEMPERATURE_ID, lines);
 }
 
 // read the temperature-to-humidity map
-pub fn get_temperature_to_humidity_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_temperature_to_humidity_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const TEMPERATURE_TO_HUMIDITY_ID: &str = "temperature-to-humidity map:";
     return parse_map(TEMPERATURE_TO_HUMIDITY_ID, lines);
 }
 
 // read the humidity-to-location map
-pub fn get_humidity_to_location_map(lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
+pub fn get_humidity_to_location_map(lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError> {
     const HUMIDITY_TO_LOCATION_ID: &str = "humidity-to-location map:";
     return parse_map(HUMIDITY_TO_LOCATION_ID, lines);
 }
 
+// read all maps in the order a seed number passes through them
+pub fn get_all_maps(lines: &Vec<Scanner>) -> Result<Vec<Map>, SolveError> {
+    return Ok(vec![
+        get_seed_to_soil_map(lines)?,
+        get_soil_to_fertilizer_map(lines)?,
+        get_fertilizer_to_water_map(lines)?,
+        get_water_to_light_map(lines)?,
+        get_light_to_temperature_map(lines)?,
+        get_temperature_to_humidity_map(lines)?,
+        get_humidity_to_location_map(lines)?,
+    ]);
+}
+
 // pass a number through one of the previously read maps
-pub fn map_number(num: u64, maps: &(Vec<i64>, Vec<Range<u64>>)) -> u64 {
-    match __map_number(num, maps) {
-        Some(mapped_num) => mapped_num,
-        None => num,
+pub fn map_number(num: u64, maps: &(Vec<i128>, Vec<Range<u64>>)) ->
+    Result<u64, SolveError> {
+    match __map_number(num, maps)? {
+        Some(mapped_num) => Ok(mapped_num),
+        None => Ok(num),
+    }
+}
+
+// pass ranges of numbers through one of the previously read maps: the
+// parts of a range that overlap a source range get shifted by its offset,
+// all other parts stay as they are
+pub fn map_ranges(ranges: &[Range<u64>], maps: &Map) ->
+    Result<Vec<Range<u64>>, SolveError> {
+    let mut unmapped: Vec<Range<u64>> = ranges.iter()
+        .filter(|range| !range.is_empty()).cloned().collect();
+    let mut mapped: Vec<Range<u64>> = Vec::new();
+
+    for (offset, source) in maps.0.iter().zip(&maps.1) {
+        let mut remaining: Vec<Range<u64>> = Vec::new();
+
+        for range in unmapped {
+            let start: u64 = range.start.max(source.start);
+            let end: u64 = range.end.min(source.end);
+
+            if start >= end {
+                remaining.push(range);
+                continue;
+            }
+
+            // the first and the last number of the overlap are shifted, as
+            // the end of the range may lie just outside of the u64 numbers
+            let first: i128 = start as i128 + offset;
+            let first: u64 = checked!(DAY, "map_ranges",
+                u64::try_from(first).ok(), first as u64);
+            let last: i128 = (end - 1) as i128 + offset;
+            let last: u64 = checked!(DAY, "map_ranges",
+                u64::try_from(last).ok(), last as u64);
+
+            mapped.push(first..last.saturating_add(1));
+
+            if range.start < start {
+                remaining.push(range.start..start);
+            }
+            if end < range.end {
+                remaining.push(end..range.end);
+            }
+        }
+
+        unmapped = remaining;
     }
+
+    mapped.extend(unmapped);
+    mapped.sort_by_key(|range| range.start);
+
+    return Ok(mapped);
 }
 
 // internal generic function to pass a number through one of the maps;
 // function returns Option for case when provided number isn't part
 // of any of the ranges of the provided map
-fn __map_number(num: u64, maps: &(Vec<i64>, Vec<Range<u64>>)) -> Option<u64> {
+fn __map_number(num: u64, maps: &(Vec<i128>, Vec<Range<u64>>)) ->
+    Result<Option<u64>, SolveError> {
     for (i, map) in maps.1.iter().enumerate() {
         if map.contains(&num) {
-            let offset: i64 = maps.0[i];
-            let mapped_number: u64 = (num as i64 + offset) as u64;
+            let offset: i128 = maps.0[i];
+
+            // offset is calculated on the widened type, so only the
+            // conversion back to an u64 can fail here
+            let mapped_number: i128 = num as i128 + offset;
+            let mapped_number: u64 = checked!(DAY, "__map_number",
+                u64::try_from(mapped_number).ok(), mapped_number as u64);
 
-            return Some(mapped_number);
+            return Ok(Some(mapped_number));
         } 
     }
 
-    return None;
+    return Ok(None);
 }
 
 // try to find a line with the provided line_id/prefixc in a
 // vector of lines and return its index if it could be found
-fn find_line_idx(line_id: &str, lines: &Vec<&str>) -> Option<usize> {
+fn find_line_idx(line_id: &str, lines: &Vec<Scanner>) -> Option<usize> {
     for (i, line) in lines.iter().enumerate() {
-        if line.starts_with(&line_id) {
+        if line.starts_with(line_id) {
             return Some(i);
         }
     }
@@ -141,41 +242,42 @@ fn find_line_idx(line_id: &str, lines: &Vec<&str>) -> Option<usize> {
 // parse a map from the input file and collect all source number ranges as
 // Range structs in a vector; the destination maps are merely stores as
 // an offset from the source maps
-fn parse_map(line_id: &str, lines: &Vec<&str>) ->
-    (Vec<i64>, Vec<Range<u64>>)  {
-    assert!(find_line_idx(line_id, &lines).is_some());
-
-    let map_start_idx: usize =
-        find_line_idx(line_id, &lines).unwrap() + 1;
-
-    let mut i: usize = map_start_idx;
-    let mut line: &str = lines[i];
+fn parse_map(line_id: &str, lines: &Vec<Scanner>) ->
+    Result<(Vec<i128>, Vec<Range<u64>>), SolveError>  {
+    let map_start_idx: usize = match find_line_idx(line_id, lines) {
+        Some(idx) => idx + 1,
+        None => return Err(SolveError::invalid_input(
+            DAY, &format!("Expected a line '{}'", line_id))),
+    };
 
     let mut source_ranges: Vec<Range<u64>> = Vec::new();
-    let mut destination_offsets: Vec<i64> = Vec::new();
+    let mut destination_offsets: Vec<i128> = Vec::new();
 
-    while !line.is_empty() {
-        let nums: Vec<i64> =
-            line.trim().split(NUMBER_DELIMITER).map(
-                |l| l.trim().parse().unwrap()).collect();
+    // a map ends with an empty line (or the end of the file)
+    for line in lines[map_start_idx..].iter().take_while(|l| !l.is_empty()) {
+        let nums: Vec<u64> = parse_numbers(&mut line.to_owned())?;
 
-        assert!(nums.len() == 3);
+        if nums.len() != 3 {
+            return Err(line.error("a map line of 'DESTINATION SOURCE LENGTH'")
+                .invalid_input(DAY));
+        }
 
-        let destination_range_start: i64 = nums[0];
-        let source_range_start: i64 = nums[1];
-        let range_len: i64 = nums[2];
+        let destination_range_start: u64 = nums[0];
+        let source_range_start: u64 = nums[1];
+        let range_len: u64 = nums[2];
 
-        let destination_offset: i64 =
-            destination_range_start - source_range_start;
+        // the offset of two u64 values always fits into an i128
+        let destination_offset: i128 =
+            destination_range_start as i128 - source_range_start as i128;
 
         destination_offsets.push(destination_offset);
 
-        source_ranges.push(
-            source_range_start as u64..(source_range_start + range_len) as u64);
+        let source_range_end: u64 = checked!(DAY, "parse_map",
+            source_range_start.checked_add(range_len),
+            source_range_start + range_len);
 
-        i += 1;
-        line = lines[i];
+        source_ranges.push(source_range_start..source_range_end);
     }
 
-    return (destination_offsets, source_ranges);
+    return Ok((destination_offsets, source_ranges));
 }
@@ -1,115 +1,189 @@
-use std::ops::Range;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
 
 mod helpers;
 
-pub fn puzzle1(file_content: String) -> String {
-    let lines: Vec<&str> = file_content.split("\n").collect();
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+pub use helpers::Map;
+pub use helpers::CATEGORIES;
+pub use helpers::get_all_maps;
+pub use helpers::get_seed_ranges;
+pub use helpers::map_number;
+pub use helpers::map_ranges;
+
+const DAY: u8 = 5;
+
+// seeds and maps of the almanac, with its lines for the seed ranges
+pub struct Almanac<'a> {
+    pub lines: Vec<Scanner<'a>>,
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Map>,
+}
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?, lap, solve1, solve2);
+}
+
+pub fn parse(file_content: &str) -> Result<Almanac<'_>, SolveError> {
+    let lines: Vec<Scanner> = Scanner::new(file_content).lines();
+    let seeds: Vec<u64> = helpers::get_seeds(&lines)?;
+    let maps: Vec<Map> = helpers::get_all_maps(&lines)?;
+
+    return Ok(Almanac{lines: lines, seeds: seeds, maps: maps});
+}
+
+pub fn solve1(almanac: &Almanac) -> Result<Answer, SolveError> {
+    // every seed is a range of its own
+    let seed_ranges: Vec<Range<u64>> = almanac.seeds.iter()
+        .map(|seed| *seed..seed.saturating_add(1)).collect();
+
+    return Ok(Answer::from(lowest_location(seed_ranges, &almanac.maps)?));
+}
+
+pub fn solve2(almanac: &Almanac) -> Result<Answer, SolveError> {
+    let seed_ranges: Vec<Range<u64>> =
+        helpers::get_seed_ranges(&almanac.lines)?;
+
+    return Ok(Answer::from(lowest_location(seed_ranges, &almanac.maps)?));
+}
+
+// pass whole seed ranges through all maps instead of every single seed;
+// the lowest location is the start of the lowest location range
+fn lowest_location(seed_ranges: Vec<Range<u64>>, maps: &[Map]) ->
+    Result<u64, SolveError> {
+    let mut ranges: Vec<Range<u64>> = seed_ranges;
+
+    for map in maps {
+        ranges = helpers::map_ranges(&ranges, map)?;
+    }
+
+    return Ok(ranges.iter().map(|range| range.start).min()
+        .unwrap_or(u64::MAX));
+}
+
+pub fn puzzle1_brute_force(file_content: String) -> Result<Answer, SolveError> {
+    let lines: Vec<Scanner> = Scanner::new(&file_content).lines();
 
-    let seeds: Vec<u64> = helpers::get_seeds(&lines);
+    let seeds: Vec<u64> = helpers::get_seeds(&lines)?;
 
-    let seed_to_soil_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_seed_to_soil_map(&lines);
+    let seed_to_soil_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_seed_to_soil_map(&lines)?;
 
-    let soil_to_fertilizer_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_soil_to_fertilizer_map(&lines);
+    let soil_to_fertilizer_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_soil_to_fertilizer_map(&lines)?;
 
-    let fertilizer_to_water_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_fertilizer_to_water_map(&lines);
+    let fertilizer_to_water_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_fertilizer_to_water_map(&lines)?;
 
-    let water_to_light_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_water_to_light_map(&lines);
+    let water_to_light_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_water_to_light_map(&lines)?;
 
-    let light_to_temperature_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_light_to_temperature_map(&lines);
+    let light_to_temperature_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_light_to_temperature_map(&lines)?;
 
-    let temperature_to_humidity_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_temperature_to_humidity_map(&lines);
+    let temperature_to_humidity_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_temperature_to_humidity_map(&lines)?;
 
-    let humidity_to_location_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_humidity_to_location_map(&lines);
+    let humidity_to_location_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_humidity_to_location_map(&lines)?;
 
     let mut lowest_location: u64 = u64::MAX;
 
     for seed in seeds {
         let soil_number: u64 = helpers::map_number(
-            seed, &seed_to_soil_map);
+            seed, &seed_to_soil_map)?;
 
         let fertilizer_number: u64 = helpers::map_number(
-            soil_number, &soil_to_fertilizer_map);
+            soil_number, &soil_to_fertilizer_map)?;
 
         let water_number: u64 = helpers::map_number(
-            fertilizer_number, &fertilizer_to_water_map);
+            fertilizer_number, &fertilizer_to_water_map)?;
 
         let light_number: u64 = helpers::map_number(
-            water_number, &water_to_light_map);
+            water_number, &water_to_light_map)?;
 
         let temperature_number: u64 = helpers::map_number(
-            light_number, &light_to_temperature_map);
+            light_number, &light_to_temperature_map)?;
 
         let humidity_number: u64 = helpers::map_number(
-            temperature_number, &temperature_to_humidity_map);
+            temperature_number, &temperature_to_humidity_map)?;
 
         let location_number: u64 = helpers::map_number(
-            humidity_number, &humidity_to_location_map);
+            humidity_number, &humidity_to_location_map)?;
 
         if location_number < lowest_location {
             lowest_location = location_number;
         }
     }
 
-    return lowest_location.to_string();
+    return Ok(Answer::from(lowest_location));
 }
 
-pub fn puzzle2(file_content: String) -> String {
-    let lines: Vec<&str> = file_content.split("\n").collect();
+pub fn puzzle2_brute_force(file_content: String) -> Result<Answer, SolveError> {
+    let lines: Vec<Scanner> = Scanner::new(&file_content).lines();
 
-    let seed_ranges: Vec<Range<u64>> = helpers::get_seed_ranges(&lines);
+    let seed_ranges: Vec<Range<u64>> = helpers::get_seed_ranges(&lines)?;
 
-    let seed_to_soil_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_seed_to_soil_map(&lines);
+    let seed_to_soil_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_seed_to_soil_map(&lines)?;
 
-    let soil_to_fertilizer_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_soil_to_fertilizer_map(&lines);
+    let soil_to_fertilizer_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_soil_to_fertilizer_map(&lines)?;
 
-    let fertilizer_to_water_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_fertilizer_to_water_map(&lines);
+    let fertilizer_to_water_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_fertilizer_to_water_map(&lines)?;
 
-    let water_to_light_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_water_to_light_map(&lines);
+    let water_to_light_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_water_to_light_map(&lines)?;
 
-    let light_to_temperature_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_light_to_temperature_map(&lines);
+    let light_to_temperature_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_light_to_temperature_map(&lines)?;
 
-    let temperature_to_humidity_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_temperature_to_humidity_map(&lines);
+    let temperature_to_humidity_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_temperature_to_humidity_map(&lines)?;
 
-    let humidity_to_location_map: (Vec<i64>, Vec<Range<u64>>) =
-        helpers::get_humidity_to_location_map(&lines);
+    let humidity_to_location_map: (Vec<i128>, Vec<Range<u64>>) =
+        helpers::get_humidity_to_location_map(&lines)?;
 
     let mut lowest_location: u64 = u64::MAX;
 
     for seed_range in seed_ranges {
         for seed in seed_range {
             let soil_number: u64 = helpers::map_number(
-                seed, &seed_to_soil_map);
+                seed, &seed_to_soil_map)?;
 
             let fertilizer_number: u64 = helpers::map_number(
-                soil_number, &soil_to_fertilizer_map);
+                soil_number, &soil_to_fertilizer_map)?;
 
             let water_number: u64 = helpers::map_number(
-                fertilizer_number, &fertilizer_to_water_map);
+                fertilizer_number, &fertilizer_to_water_map)?;
 
             let light_number: u64 = helpers::map_number(
-                water_number, &water_to_light_map);
+                water_number, &water_to_light_map)?;
 
             let temperature_number: u64 = helpers::map_number(
-                light_number, &light_to_temperature_map);
+                light_number, &light_to_temperature_map)?;
 
             let humidity_number: u64 = helpers::map_number(
-                temperature_number, &temperature_to_humidity_map);
+                temperature_number, &temperature_to_humidity_map)?;
 
             let location_number: u64 = helpers::map_number(
-                humidity_number, &humidity_to_location_map);
+                humidity_number, &humidity_to_location_map)?;
 
             if location_number < lowest_location {
                 lowest_location = location_number;
@@ -117,5 +191,5 @@ pub fn puzzle2(file_content: String) -> String {
         }
     }
 
-    return lowest_location.to_string();
+    return Ok(Answer::from(lowest_location));
 }
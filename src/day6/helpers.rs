@@ -1,51 +1,24 @@
-const TIME_LINE_ID: &str = "Time:";
-const DISTANCE_LINE_ID: &str = "Distance:";
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-pub fn get_times(file_content: &str) -> Vec<u64> {
-    let first_line_end_idx: usize = file_content.find("\n").unwrap();
+use crate::error::SolveError;
+use crate::scan::Scanner;
 
-    let times_str: &str = &file_content[TIME_LINE_ID.len()..first_line_end_idx];
+use super::DAY;
 
-    let times: Vec<u64> = get_numbers_from_line(times_str);
-
-    return times;
-}
-
-pub fn get_time(file_content: &str) -> u64 {
-    let line_start_idx: usize = file_content.find(TIME_LINE_ID).unwrap();
-    let line_end_idx: usize = file_content.find("\n").unwrap();
-
-    let distance_line: &str =
-        &file_content[line_start_idx+TIME_LINE_ID.len()..line_end_idx];
-
-    return get_number_from_line(distance_line);
-}
-
-pub fn get_distances(file_content: &str) -> Vec<u64> {
-    let first_line_end_idx: usize = file_content.find("\n").unwrap();
-    let second_line_start_idx: usize =
-        first_line_end_idx + 1 + DISTANCE_LINE_ID.len();
-
-    let distances_str: &str = &file_content[second_line_start_idx..];
-
-    let distances: Vec<u64> = get_numbers_from_line(distances_str);
-
-    return distances;
-}
-
-pub fn get_distance(file_content: &str) -> u64 {
-    let line_start_idx: usize = file_content.find(DISTANCE_LINE_ID).unwrap();
-    let line_end_idx: usize = file_content.len();
-
-    let distance_line: &str =
-        &file_content[line_start_idx+DISTANCE_LINE_ID.len()..line_end_idx];
-
-    return get_number_from_line(distance_line);
-}
+pub const TIME_LINE_ID: &str = "Time:";
+pub const DISTANCE_LINE_ID: &str = "Distance:";
 
 pub fn calc_num_of_record_possibilities(
     total_time: u64, record_distance: u64) -> u64 {
-
+    // charging for half of the total time goes the farthest;
+    // if even that doesn't beat the record, nothing does
+    let half_time: u64 = total_time / 2;
+    if calc_traveled_distance(total_time - half_time, half_time) <=
+       record_distance as u128 {
+        return 0;
+    }
 
     let longest_possible_charge_time: u64 =
         calc_longest_possible_charge_time(total_time, record_distance);
@@ -59,108 +32,143 @@ pub fn calc_num_of_record_possibilities(
     return longest_possible_charge_time - shortest_possible_charge_time + 1;
 }
 
-fn get_numbers_from_line(line: &str) -> Vec<u64> {
-    let nums: Vec<u64> = line.split(" ").map(
-        |n| n.trim()).filter(
-                |n| !n.is_empty()).map(
-                    |n| n.parse().unwrap()).collect();
+// count the charge times that beat the record by solving the quadratic
+// equation charge * (total - charge) = record instead of searching for
+// its solutions; the integer square root is only an estimate of the
+// shortest charge time, which is corrected by single steps
+pub fn calc_num_of_record_possibilities_closed_form(
+    total_time: u64, record_distance: u64) -> u64 {
+    let half_time: u64 = total_time / 2;
+    let beats_record = |charge_time: u64| calc_traveled_distance(
+        total_time - charge_time, charge_time) > record_distance as u128;
+
+    if !beats_record(half_time) {
+        return 0;
+    }
+
+    // the discriminant is positive, since half of the total time beats
+    // the record (the square of an u64 value always fits into an u128)
+    let discriminant: u128 = (total_time as u128).pow(2) -
+        4 * record_distance as u128;
+    let root: u64 = discriminant.isqrt() as u64;
+
+    let mut shortest_charge_time: u64 = (total_time - root) / 2;
+    while shortest_charge_time > 0 && beats_record(shortest_charge_time - 1) {
+        shortest_charge_time -= 1;
+    }
+    while !beats_record(shortest_charge_time) {
+        shortest_charge_time += 1;
+    }
+
+    // the distances are symmetric around half of the total time, so the
+    // longest charge time is as far from the total time as the shortest
+    // one is from zero
+    return total_time - 2 * shortest_charge_time + 1;
+}
+
+// find the line that starts with the line ID and consume the ID
+pub fn get_line<'a>(file_content: &'a str, line_id: &str) ->
+    Result<Scanner<'a>, SolveError> {
+    for mut line in Scanner::new(file_content).lines() {
+        if line.expect_literal(line_id).is_ok() {
+            return Ok(line);
+        }
+    }
 
-    return nums;
+    return Err(SolveError::invalid_input(
+        DAY, &format!("Expected a line starting with '{}'", line_id)));
 }
 
-fn get_number_from_line(line: &str) -> u64 {
-    let total_time: u64 = line.replace(" ", "").parse().unwrap();
+pub fn get_numbers_from_line(line: &mut Scanner) ->
+    Result<Vec<u64>, SolveError> {
+    let nums: Vec<u64> = line.take_uints()
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.expect_end().map_err(|err| err.invalid_input(DAY))?;
 
-    return total_time;
+    return Ok(nums);
 }
 
+// read the numbers of the line as a single number, ignoring the spaces
+// between them (i.e. "7  15   30" is 71530)
+pub fn get_number_from_line(line: &mut Scanner) -> Result<u64, SolveError> {
+    let start: Scanner = *line;
+    let mut digits: String = String::new();
+
+    loop {
+        line.take_while(|c| c == ' ');
+        let part: &str = line.take_while(|c| c.is_ascii_digit());
+        if part.is_empty() {
+            break;
+        }
+        digits += part;
+    }
+    line.expect_end().map_err(|err| err.invalid_input(DAY))?;
+
+    let num: u64 = Scanner::new(&digits).take_uint().map_err(
+        |err| start.error(&err.expected).invalid_input(DAY))?;
+
+    return Ok(num);
+}
+
+// (the product of two u64 values always fits into an u128)
 #[inline(always)]
-fn calc_traveled_distance(time_remaining: u64, speed: u64) -> u64 {
-    return speed * time_remaining;
+fn calc_traveled_distance(time_remaining: u64, speed: u64) -> u128 {
+    return speed as u128 * time_remaining as u128;
 }
 
 // find longest/maximum possible charge time using binary search
+// (assumes that charging for half of the total time beats the record)
 fn calc_longest_possible_charge_time(
     total_time: u64, record_distance: u64) -> u64 {
     
-    // look in range from middle to max time and try to find the charge time
-    // that produces a speed that is just too small to beat the record time
+    // look in range from middle to max time; the low time always beats
+    // the record distance, while the high time never does
+    // (charging for the whole time doesn't move the boat at all)
     let mut low_time: u64 = total_time / 2;
-    let mut high_time: u64 = total_time - 1;
+    let mut high_time: u64 = total_time;
 
-    loop {
-        let middle: u64 = (high_time + low_time) / 2;
+    while high_time - low_time > 1 {
+        let middle: u64 = low_time + (high_time - low_time) / 2;
 
-        let lower_distance: u64 =
+        let distance: u128 =
             calc_traveled_distance(total_time - middle, middle);
-        let upper_distance: u64 =
-            calc_traveled_distance(total_time - (middle + 1), middle + 1);
 
-        // check if we found the longest time the boat
-        // can charge to still beat the record distance
-        let found_longest_time: bool =
-          lower_distance > record_distance && upper_distance <= record_distance;
-
-        if found_longest_time {
-            return middle;
-        }
-
-        // if true, we're still in haven't reached the maximum possible
-        // charging time and need to keep increasing it
-        if lower_distance > record_distance &&
-           upper_distance > record_distance {
+        // if true, we haven't reached the maximum possible charging
+        // time yet and need to keep increasing it; otherwise we've jumped
+        // too far and need to decrease the time window again
+        if distance > record_distance as u128 {
             low_time = middle;
-        // if true, we've jumped too far and skipped the maximum possible
-        // charge time, so we need to decrease the time window again
-        } else if lower_distance <= record_distance && 
-                  upper_distance <= record_distance {
-            high_time = middle;
-        // if true, we've also jumped too far (see condition above)
-        } else if lower_distance <= record_distance &&
-                  upper_distance > record_distance {
+        } else {
             high_time = middle;
         }
     }
+
+    return low_time;
 }
 
 // find shortest/minimum possible charge time using binary search
+// (assumes that charging for half of the total time beats the record)
 fn calc_shortest_possible_charge_time(
     total_time: u64, record_distance: u64) -> u64 {
-    let mut low_time: u64 = 1;
+    // look in range from no charging at all (which never beats the record)
+    // to the middle, which always beats it
+    let mut low_time: u64 = 0;
     let mut high_time: u64 = total_time / 2;
 
-    loop {
-        let middle: u64 = (high_time + low_time) / 2;
+    while high_time - low_time > 1 {
+        let middle: u64 = low_time + (high_time - low_time) / 2;
 
-        let lower_distance: u64 =
-            calc_traveled_distance(total_time - (middle - 1), middle - 1);
-        let upper_distance: u64 =
+        let distance: u128 =
             calc_traveled_distance(total_time - middle, middle);
 
-            // check if we found the shortest time the boat
-            // can charge to still beat the record distance
-            let found_shortest_time: bool =
-                lower_distance <= record_distance &&
-                upper_distance > record_distance;
-    
-            if found_shortest_time {
-                return middle;
-            }
-    
-            // if true, we've jumped too far and skipped the minimum possible
-            // charge time, so we need to increase the time window again
-            if lower_distance <= record_distance &&
-               upper_distance <= record_distance {
-                low_time = middle;
-            // if true, we've skipped the minimum possible charge time
-            // and need to decrease the time window again
-            } else if lower_distance > record_distance && 
-                      upper_distance <= record_distance {
-                high_time = middle;
-            // same case as condition above; just jumped even further here
-            } else if lower_distance > record_distance &&
-                      upper_distance > record_distance {
-                high_time = middle;
-            }
+        // if true, we've skipped the minimum possible charge time and need
+        // to decrease the time window again; otherwise we need to increase it
+        if distance > record_distance as u128 {
+            high_time = middle;
+        } else {
+            low_time = middle;
+        }
     }
+
+    return high_time;
 }
@@ -1,32 +1,118 @@
 mod helpers;
 
-pub fn puzzle1(file_content: String) -> String {
-    let mut ans: u64 = 1;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-    let file_content: &str = file_content.trim();
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+use crate::scan::Scanner;
 
-    let times: Vec<u64> = helpers::get_times(file_content);
-    
-    let distances: Vec<u64> = helpers::get_distances(file_content);
+const DAY: u8 = 6;
 
-    for (i, time) in times.iter().enumerate() {
-        let num_of_record_possibilites: u64 =
-            helpers::calc_num_of_record_possibilities(*time, distances[i]);
-        ans *= num_of_record_possibilites;
-    }
+// the race times and record distances of puzzle 1, and the time and record
+// distance of the single race of puzzle 2 (the same digits read without the
+// spaces between them); the digits of all races together may not fit into
+// an u64, which only fails puzzle 2
+pub struct Races {
+    pub times: Vec<u64>,
+    pub distances: Vec<u64>,
+    pub single_race: Result<(u64, u64), SolveError>,
+}
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
 
-    return ans.to_string();
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
 }
 
-pub fn puzzle2(file_content: String) -> String {
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?, lap, solve1, solve2);
+}
+
+pub fn puzzle1_closed_form(file_content: String) ->
+    Result<Answer, SolveError> {
+    return solve_puzzle1(
+        &parse(&file_content)?,
+        helpers::calc_num_of_record_possibilities_closed_form);
+}
+
+pub fn puzzle2_closed_form(file_content: String) ->
+    Result<Answer, SolveError> {
+    return solve_puzzle2(
+        &parse(&file_content)?,
+        helpers::calc_num_of_record_possibilities_closed_form);
+}
+
+pub fn parse(file_content: &str) -> Result<Races, SolveError> {
     let file_content: &str = file_content.trim();
 
-    let total_time: u64 = helpers::get_time(file_content);
+    let time_line: Scanner =
+        helpers::get_line(file_content, helpers::TIME_LINE_ID)?;
+    let distance_line: Scanner =
+        helpers::get_line(file_content, helpers::DISTANCE_LINE_ID)?;
+
+    // puzzle 1 reads the numbers of the lines one by one, puzzle 2 reads
+    // each line as a single number
+    let mut line: Scanner = time_line;
+    let times: Vec<u64> = helpers::get_numbers_from_line(&mut line)?;
+    let mut line: Scanner = distance_line;
+    let distances: Vec<u64> = helpers::get_numbers_from_line(&mut line)?;
+
+    return Ok(Races{
+        times: times,
+        distances: distances,
+        single_race: parse_single_race(time_line, distance_line),
+    });
+}
 
-    let record_distance: u64 = helpers::get_distance(file_content);
+fn parse_single_race(mut time_line: Scanner, mut distance_line: Scanner) ->
+    Result<(u64, u64), SolveError> {
+    let total_time: u64 = helpers::get_number_from_line(&mut time_line)?;
+    let record_distance: u64 =
+        helpers::get_number_from_line(&mut distance_line)?;
+
+    return Ok((total_time, record_distance));
+}
+
+pub fn solve1(races: &Races) -> Result<Answer, SolveError> {
+    return solve_puzzle1(races, helpers::calc_num_of_record_possibilities);
+}
+
+pub fn solve2(races: &Races) -> Result<Answer, SolveError> {
+    return solve_puzzle2(races, helpers::calc_num_of_record_possibilities);
+}
+
+// count the possibilities of every race with the provided function
+// (binary search or closed form)
+fn solve_puzzle1(races: &Races, count_possibilities: fn(u64, u64) -> u64) ->
+    Result<Answer, SolveError> {
+    let mut ans: u64 = 1;
+
+    if races.times.len() != races.distances.len() {
+        return Err(SolveError::invalid_input(
+            DAY, "Expected a distance for every race time"));
+    }
+
+    for (i, time) in races.times.iter().enumerate() {
+        let num_of_record_possibilites: u64 =
+            count_possibilities(*time, races.distances[i]);
+        ans *= num_of_record_possibilites;
+    }
+
+    return Ok(Answer::from(ans));
+}
 
+fn solve_puzzle2(races: &Races, count_possibilities: fn(u64, u64) -> u64) ->
+    Result<Answer, SolveError> {
+    let (total_time, record_distance): (u64, u64) =
+        races.single_race.clone()?;
     let total_record_beating_races: u64 =
-        helpers::calc_num_of_record_possibilities(total_time, record_distance);
+        count_possibilities(total_time, record_distance);
 
-    return total_record_beating_races.to_string();
+    return Ok(Answer::from(total_record_beating_races));
 }
@@ -1,11 +1,22 @@
-use std::collections::HashMap;
-use std::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::fxhash::FxHashMap;
+use crate::scan::ScanError;
+use crate::scan::Scanner;
+
+use super::DAY;
 
 const VALID_CARDS: [char; 13] =
     ['J', '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'Q', 'K', 'A'];
 
+// number of every card in a hand
+type CardCounts = FxHashMap<char, u8>;
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Clone, Copy)]
-enum Rank {
+pub enum Rank {
     FiveOfKind  = 7,
     FourOfKind  = 6,
     FullHouse   = 5,
@@ -18,7 +29,7 @@ enum Rank {
 impl Rank {
     // check if all cards are equal (since a consists of exaclty 5 cards)
     fn is_five_of_kind(hand: &str) -> bool {
-        let (_counts, high_count): (HashMap<char, u8>, u8) = count_cards(hand);
+        let (_counts, high_count): (CardCounts, u8) = count_cards(hand);
 
         let is_five_of_kind: bool = high_count == 5;
 
@@ -26,7 +37,7 @@ impl Rank {
     }
 
     fn is_four_of_kind(hand: &str) -> bool {
-        let (_counts, high_count): (HashMap<char, u8>, u8) = count_cards(hand);
+        let (_counts, high_count): (CardCounts, u8) = count_cards(hand);
 
         let is_four_of_kind: bool = high_count == 4;
         
@@ -34,7 +45,7 @@ impl Rank {
     }
 
     fn is_three_of_kind(hand: &str) -> bool {
-        let (_counts, high_count): (HashMap<char, u8>, u8) = count_cards(hand);
+        let (_counts, high_count): (CardCounts, u8) = count_cards(hand);
 
         let is_three_of_kind: bool = high_count == 3;
 
@@ -42,7 +53,7 @@ impl Rank {
     }
 
     fn is_full_house(hand: &str) -> bool {
-        let (counts, _high_count): (HashMap<char, u8>, u8) = count_cards(hand);
+        let (counts, _high_count): (CardCounts, u8) = count_cards(hand);
 
         let mut pair_count: u8 = 0;
         let mut triplet_count: u8 = 0;
@@ -61,7 +72,7 @@ impl Rank {
     }
 
     fn is_two_pair(hand: &str) -> bool {
-        let (counts, _high_count): (HashMap<char, u8>, u8) = count_cards(hand);
+        let (counts, _high_count): (CardCounts, u8) = count_cards(hand);
         let mut pair_count: u8 = 0;
 
         for (_card, count) in counts {
@@ -77,7 +88,7 @@ impl Rank {
 
     // if highest card count is 2, all other cards must be different
     fn is_single_pair(hand: &str) -> bool {
-        let (counts, _high_count): (HashMap<char, u8>, u8) = count_cards(hand);
+        let (counts, _high_count): (CardCounts, u8) = count_cards(hand);
 
         let mut pair_count: u8 = 0;
         let mut triplet_count: u8 = 0;
@@ -96,16 +107,34 @@ impl Rank {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Hand<'a> {
     hand: &'a str,
     bid: u32,
     rank: Rank,
 }
 
-impl Hand<'_> {
+impl<'a> Hand<'a> {
+    // the same hand ranked with its jokers (i.e. for puzzle 2)
+    pub fn with_joker(&self) -> Hand<'a> {
+        return Hand{hand: self.hand, bid: self.bid,
+                    rank: get_rank(self.hand, true)};
+    }
+
+    pub fn cards(&self) -> &str {
+        return self.hand;
+    }
+
+    pub fn bid(&self) -> u32 {
+        return self.bid;
+    }
+
+    pub fn rank(&self) -> Rank {
+        return self.rank;
+    }
+
     pub fn compare(
-        &self, other: &Self, card_ranks: &HashMap<char, u8>) -> Ordering {
+        &self, other: &Self, card_ranks: &CardRanks) -> Ordering {
         if self.hand == other.hand {
             return Ordering::Equal;
         }
@@ -116,7 +145,7 @@ impl Hand<'_> {
             return Ordering::Less;
         }
     }
-    pub fn beats(&self, other: &Hand, card_ranks: &HashMap<char, u8>) -> bool {
+    pub fn beats(&self, other: &Hand, card_ranks: &CardRanks) -> bool {
         if self.rank == other.rank {
             return self.is_hand_stronger(other, card_ranks);
         } else {
@@ -127,12 +156,12 @@ impl Hand<'_> {
     // check if hand is stronger than the other hand if they
     // both share the same rank (i.e. both hands contain 2 pairs)
     fn is_hand_stronger(
-        &self, other: &Hand, card_ranks: &HashMap<char, u8>) -> bool {
+        &self, other: &Hand, card_ranks: &CardRanks) -> bool {
 
         for (self_card, other_card) in
             self.hand.chars().zip(other.hand.chars()) {
             if self_card != other_card {
-                return card_ranks[&self_card] > card_ranks[&other_card];
+                return card_ranks.get(self_card) > card_ranks.get(other_card);
             }
         }
 
@@ -141,8 +170,35 @@ impl Hand<'_> {
     }
 }
 
-pub fn get_card_ranks_puzzle1() -> HashMap<char, u8> {
-    let rank_map: HashMap<char, u8> = HashMap::from([
+// ranks of the cards, looked up by the ASCII code of a card instead of
+// hashing it (characters that aren't cards have rank 0)
+#[derive(Debug, Clone)]
+pub struct CardRanks {
+    ranks: [u8; 128],
+}
+
+impl CardRanks {
+    fn new(card_ranks: &[(char, u8)]) -> CardRanks {
+        let mut ranks: [u8; 128] = [0; 128];
+
+        for (card, rank) in card_ranks {
+            ranks[*card as usize] = *rank;
+        }
+
+        return CardRanks{ranks: ranks};
+    }
+
+    pub fn get(&self, card: char) -> u8 {
+        return self.ranks.get(card as usize).copied().unwrap_or(0);
+    }
+
+    pub fn contains(&self, card: char) -> bool {
+        return self.get(card) != 0;
+    }
+}
+
+pub fn get_card_ranks_puzzle1() -> CardRanks {
+    let rank_map: CardRanks = CardRanks::new(&[
         ('2', 2),
         ('3', 3),
         ('4', 4),
@@ -161,8 +217,8 @@ pub fn get_card_ranks_puzzle1() -> HashMap<char, u8> {
     return rank_map;
 }
 
-pub fn get_card_ranks_puzzle2() -> HashMap<char, u8> {
-    let rank_map: HashMap<char, u8> = HashMap::from([
+pub fn get_card_ranks_puzzle2() -> CardRanks {
+    let rank_map: CardRanks = CardRanks::new(&[
         ('J', 1),
         ('2', 2),
         ('3', 3),
@@ -182,35 +238,56 @@ pub fn get_card_ranks_puzzle2() -> HashMap<char, u8> {
 }
 
 // parse the hand of cards from the current line and determine its rank
-pub fn parse_hand<'a> (line: &'a str, with_joker: bool) -> Hand<'a> {
-    const DELIMITER: &str = " ";
+pub fn parse_hand<'a> (line: &mut Scanner<'a>, with_joker: bool) ->
+    Result<Hand<'a>, ScanError> {
+    let start: Scanner = *line;
+    let hand: &str = line.take_while(|c| c != ' ');
+    if hand.len() != 5 || !is_valid_hand(hand) {
+        return Err(start.error("a hand of 5 cards"));
+    }
 
-    assert!(line.find(DELIMITER).is_some());
-    let delimiter_idx: usize = line.find(DELIMITER).unwrap();
+    line.expect_literal(" ")?;
+    let bid: u32 = line.take_uint()?;
+    line.expect_end()?;
+    let rank: Rank = get_rank(hand, with_joker);
 
-    let hand: &str = &line[0..delimiter_idx];
-    assert!(is_valid_hand(hand));
+    return Ok(Hand{hand: hand, bid: bid, rank: rank});
+}
 
-    let bid: u32 = (&line[delimiter_idx+1..]).parse().unwrap();
-    let rank: Rank = get_rank(hand, with_joker);
+// pack the rank and the cards of a hand into a single number that sorts
+// like the hand (rank in the highest bits, then 4 bits for every card),
+// so hands are sorted without looking up the card ranks on every comparison
+pub fn calc_sort_key(hand: &Hand, card_ranks: &CardRanks) -> u32 {
+    let mut sort_key: u32 = hand.rank as u32;
 
-    return Hand{hand: hand, bid: bid, rank: rank};
+    for card in hand.hand.chars() {
+        sort_key = sort_key << 4 | card_ranks.get(card) as u32;
+    }
+
+    return sort_key;
 }
 
-pub fn calc_total_winnings(ranked_hands: &Vec<Hand>) -> u32 {
-    let mut total_winnings: u32 = 0;
+pub fn calc_total_winnings(ranked_hands: &Vec<Hand>) ->
+    Result<u64, SolveError> {
+    let mut total_winnings: u64 = 0;
 
     for (i, hand) in ranked_hands.iter().enumerate() {
         // since hands Vector is sorted, the indices of the hands (+1)
         // represent their final rank
-        let final_rank_of_hand: u32 = (i + 1) as u32;
-        total_winnings += final_rank_of_hand * hand.bid;
+        let final_rank_of_hand: u64 = (i + 1) as u64;
+
+        let winnings: u64 = checked!(DAY, "calc_total_winnings",
+            final_rank_of_hand.checked_mul(hand.bid as u64),
+            final_rank_of_hand * hand.bid as u64);
+
+        total_winnings = checked!(DAY, "calc_total_winnings",
+            total_winnings.checked_add(winnings), total_winnings + winnings);
     }
 
-    return total_winnings;
+    return Ok(total_winnings);
 }
 
-fn get_rank(hand: &str, with_joker: bool) -> Rank {
+pub fn get_rank(hand: &str, with_joker: bool) -> Rank {
     let rank: Rank = if Rank::is_five_of_kind(hand) {
         Rank::FiveOfKind
     } else if Rank::is_four_of_kind(hand) {
@@ -235,16 +312,15 @@ fn get_rank(hand: &str, with_joker: bool) -> Rank {
     return rank;
 }
 
-fn count_cards(hand: &str) -> (HashMap<char, u8>, u8) {
-    let mut card_counts: HashMap<char, u8> = HashMap::new();
+fn count_cards(hand: &str) -> (CardCounts, u8) {
+    let mut card_counts: CardCounts = FxHashMap::default();
 
     let mut highest_card_count: u8 = 0;
 
     for card in hand.chars() {
         let entry: Option<&mut u8> = card_counts.get_mut(&card);
 
-        if entry.is_some() {
-            let count: &mut u8 = entry.unwrap();
+        if let Some(count) = entry {
             *count += 1;
 
             if *count > highest_card_count {
@@ -258,19 +334,19 @@ fn count_cards(hand: &str) -> (HashMap<char, u8>, u8) {
     return (card_counts, highest_card_count);
 }
 
-fn is_valid_hand(hand: &str) -> bool {
+pub fn is_valid_hand(hand: &str) -> bool {
     return hand.chars().all(|c| VALID_CARDS.contains(&c));
 }
 
 // figure out how many free joker cards are available in the current hand
 // to make a better hand
-fn get_free_joker_count(card_counts: &HashMap<char, u8>, rank: &Rank) -> u8 {
+fn get_free_joker_count(card_counts: &CardCounts, rank: &Rank) -> u8 {
     const JOKER_CARD: &char = &'J';
     let joker_count: Option<&u8> = card_counts.get(JOKER_CARD);
 
     // count the number of jokers
-    let joker_count: u8 = if joker_count.is_some() {
-        *joker_count.unwrap()
+    let joker_count: u8 = if let Some(joker_count) = joker_count {
+        *joker_count
     } else {
         return 0;
     };
@@ -310,9 +386,9 @@ fn get_free_joker_count(card_counts: &HashMap<char, u8>, rank: &Rank) -> u8 {
 // check if the rank of the hand can be upgraded
 // with the use of available joker cards
 fn try_to_upgrade_rank(rank: &Rank, hand: &str) -> Rank {
-    let (card_counts, _): (HashMap<char, u8>, _) = count_cards(hand);
-    let mut free_joker_count: u8 = get_free_joker_count(&card_counts, &rank);
-    let mut upgraded_rank: Rank = rank.clone();
+    let (card_counts, _): (CardCounts, _) = count_cards(hand);
+    let mut free_joker_count: u8 = get_free_joker_count(&card_counts, rank);
+    let mut upgraded_rank: Rank = *rank;
 
     while free_joker_count > 0 {
         upgraded_rank = upgrade_rank(&upgraded_rank);
@@ -1,48 +1,132 @@
 mod helpers;
 
-use std::collections::HashMap;
-use helpers::Hand;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-pub fn puzzle1(file_content: String) -> String {
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+pub use helpers::CardRanks;
+pub use helpers::Hand;
+pub use helpers::Rank;
+pub use helpers::get_rank;
+pub use helpers::is_valid_hand;
+pub use helpers::get_card_ranks_puzzle1;
+pub use helpers::get_card_ranks_puzzle2;
+
+const DAY: u8 = 7;
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?[..], lap, solve1, solve2);
+}
+
+pub fn puzzle1_packed(file_content: String) -> Result<Answer, SolveError> {
     let with_joker: bool = false;
-    let card_ranks: HashMap<char, u8> = helpers::get_card_ranks_puzzle1();
-    let total_winnings: u32 =
-        solve_puzzle(file_content, with_joker, &card_ranks);
+    let card_ranks: CardRanks = helpers::get_card_ranks_puzzle1();
+    let total_winnings: u64 = solve_puzzle(
+        parse_hands(&file_content, with_joker)?, &card_ranks, true)?;
 
-    return total_winnings.to_string();
+    return Ok(Answer::from(total_winnings));
 }
 
-pub fn puzzle2(file_content: String) -> String {
+pub fn puzzle2_packed(file_content: String) -> Result<Answer, SolveError> {
     let with_joker: bool = true;
-    let card_ranks: HashMap<char, u8> = helpers::get_card_ranks_puzzle2();
-    let total_winnings: u32 =
-        solve_puzzle(file_content, with_joker, &card_ranks);
+    let card_ranks: CardRanks = helpers::get_card_ranks_puzzle2();
+    let total_winnings: u64 = solve_puzzle(
+        parse_hands(&file_content, with_joker)?, &card_ranks, true)?;
 
-    return total_winnings.to_string();
+    return Ok(Answer::from(total_winnings));
+}
+
+// all hands ranked without jokers; puzzle 2 re-ranks them with jokers
+pub fn parse(file_content: &str) -> Result<Vec<Hand<'_>>, SolveError> {
+    return parse_hands(file_content, false);
+}
+
+pub fn solve1(hands: &[Hand]) -> Result<Answer, SolveError> {
+    let card_ranks: CardRanks = helpers::get_card_ranks_puzzle1();
+    let total_winnings: u64 =
+        solve_puzzle(hands.to_vec(), &card_ranks, false)?;
+
+    return Ok(Answer::from(total_winnings));
+}
+
+pub fn solve2(hands: &[Hand]) -> Result<Answer, SolveError> {
+    let card_ranks: CardRanks = helpers::get_card_ranks_puzzle2();
+    let total_winnings: u64 = solve_puzzle(
+        hands.iter().map(Hand::with_joker).collect(), &card_ranks, false)?;
+
+    return Ok(Answer::from(total_winnings));
 }
 
 fn solve_puzzle(
-    file_content: String, with_joker: bool,
-    card_ranks: &HashMap<char, u8>) -> u32 {
+    mut hands: Vec<Hand>, card_ranks: &CardRanks, packed_keys: bool) ->
+    Result<u64, SolveError> {
+    sort_hands(&mut hands, card_ranks, packed_keys);
+
+    // calculate the total winnings by multplying
+    // the rank of each hand with its bidding value
+    let total_winnings: u64 = helpers::calc_total_winnings(&hands)?;
+
+    return Ok(total_winnings);
+}
+
+// parse all hands of the input and sort them by their strength
+pub fn rank_hands<'a>(
+    file_content: &'a str, with_joker: bool,
+    card_ranks: &CardRanks) -> Result<Vec<Hand<'a>>, SolveError> {
+    let mut hands: Vec<Hand> = parse_hands(file_content, with_joker)?;
+    sort_hands(&mut hands, card_ranks, false);
+
+    return Ok(hands);
+}
+
+// sort the hands like rank_hands(), but by their packed sort keys
+// (the sort is stable, so equal hands keep their order in both)
+pub fn rank_hands_packed<'a>(
+    file_content: &'a str, with_joker: bool,
+    card_ranks: &CardRanks) -> Result<Vec<Hand<'a>>, SolveError> {
+    let mut hands: Vec<Hand> = parse_hands(file_content, with_joker)?;
+    sort_hands(&mut hands, card_ranks, true);
+
+    return Ok(hands);
+}
+
+// sort the hands by their rank in increasing order
+// (i.e. best hand is rightmost element of vector)
+fn sort_hands(hands: &mut [Hand], card_ranks: &CardRanks, packed_keys: bool) {
+    match packed_keys {
+        true => hands.sort_by_key(
+            |hand| helpers::calc_sort_key(hand, card_ranks)),
+        false => hands.sort_by(|a, b| a.compare(b, card_ranks)),
+    }
+}
+
+fn parse_hands(file_content: &str, with_joker: bool) ->
+    Result<Vec<Hand<'_>>, SolveError> {
     let mut hands: Vec<Hand> = Vec::new();
 
     // parse all the hands and bids; determine rank of each hand
-    for mut line in file_content.split("\n") {
-        line = line.trim();
+    for mut line in Scanner::new(file_content).lines() {
         if line.is_empty() {
             continue;
         }
 
-        hands.push(helpers::parse_hand(line, with_joker));
+        hands.push(helpers::parse_hand(&mut line, with_joker)
+            .map_err(|err| err.invalid_input(DAY))?);
     }
 
-    // sort the hands by their rank in increasing order
-    // (i.e. best hand is rightmost element of vector)
-    hands.sort_by(|a, b| a.compare(b, card_ranks));
-
-    // calculate the total winnings by multplying
-    // the rank of each hand with its bidding value
-    let total_winnings: u32 = helpers::calc_total_winnings(&hands);
-
-    return total_winnings;
+    return Ok(hands);
 }
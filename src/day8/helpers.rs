@@ -1,4 +1,13 @@
-use std::collections::HashMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::fxhash::FxHashMap;
+use crate::scan::ScanError;
+use crate::scan::Scanner;
+
+use super::DAY;
 
 pub type JumpNodes = [usize; 2];
 
@@ -7,44 +16,50 @@ const END_NODE: &str = "ZZZ";
 const LOCATION_DELIMITER: &str = " = (";
 
 // parse the directions ('L's will be 0, 'R's will be 1)
-pub fn parse_directions(file: &str) -> Vec<u8> {
+pub fn parse_directions(file: &str) -> Result<Vec<u8>, SolveError> {
     let mut directions: Vec<u8> = Vec::new();
 
-    let end_of_directions_line_idx: usize = file.find("\n").unwrap();
-    let directions_line: &str = &file[..end_of_directions_line_idx];
+    let mut directions_line: Scanner = Scanner::new(file).lines()[0];
+    let start: Scanner = directions_line;
 
     // make sure that all characters of line are R (for right) or L (for left)
-    assert!(directions_line.chars().all(|c| c == 'R' || c == 'L'));
+    let directions_str: &str =
+        directions_line.take_while(|c| c == 'R' || c == 'L');
+    if directions_str.is_empty() || !directions_line.is_empty() {
+        return Err(start.error("a first line of 'L' and 'R' directions")
+            .invalid_input(DAY));
+    }
 
     // push all 'L's as 0, all 'R's as 1
-    for chr in directions_line.chars() {
+    for chr in directions_str.chars() {
         directions.push((chr == 'R') as u8);
     }
 
-    return directions;
+    return Ok(directions);
 }
 
-// read all nodes/locations from the input file
-pub fn map_node_locations(file: &str) -> HashMap<&str, usize> {
-    let mut nodes: HashMap<&str, usize> = HashMap::new();
-    let mut node_counter: usize = 0;
-
-    for mut line in file.split("\n") {
-        line = line.trim();
-        if line.is_empty() {
-            continue;
+// all lines that start with a node followed by its jump nodes, along with
+// the name of the node; the lines are scanned up to the jump nodes
+fn get_node_lines(file: &str) -> Vec<(&str, Scanner<'_>)> {
+    let mut node_lines: Vec<(&str, Scanner)> = Vec::new();
+
+    for mut line in Scanner::new(file).lines() {
+        let node: &str = line.take_while(|c| c.is_ascii_alphanumeric());
+
+        if !node.is_empty() && line.expect_literal(LOCATION_DELIMITER).is_ok() {
+            node_lines.push((node, line));
         }
+    }
 
-        let location_delimiter: Option<usize> = line.find(LOCATION_DELIMITER);
-        let has_location: bool = location_delimiter.is_some();
+    return node_lines;
+}
 
-        if has_location {
-            let location_delimiter_idx: usize = location_delimiter.unwrap();
-            let node: &str = &line[..location_delimiter_idx];
+// read all nodes/locations from the input file
+pub fn map_node_locations(file: &str) -> FxHashMap<&str, usize> {
+    let mut nodes: FxHashMap<&str, usize> = FxHashMap::default();
 
-            nodes.insert(node, node_counter);
-            node_counter += 1;
-        }
+    for (node_counter, (node, _)) in get_node_lines(file).iter().enumerate() {
+        nodes.insert(node, node_counter);
     }
 
     return nodes;
@@ -53,28 +68,12 @@ pub fn map_node_locations(file: &str) -> HashMap<&str, usize> {
 pub fn get_start_end_nodes(file: &str) -> (Vec<usize>, Vec<usize>) {
     let mut start_nodes: Vec<usize> = Vec::new();
     let mut end_nodes: Vec<usize> = Vec::new();
-    let mut node_counter: usize = 0;
-
-    for mut line in file.split("\n") {
-        line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-
-        let location_delimiter: Option<usize> = line.find(LOCATION_DELIMITER);
-        let has_location: bool = location_delimiter.is_some();
-
-        if has_location {
-            let location_delimiter_idx: usize = location_delimiter.unwrap();
-            let node: &str = &line[..location_delimiter_idx];
 
-            if node.ends_with("A") {
-                start_nodes.push(node_counter);
-            } else if node.ends_with("Z") {
-                end_nodes.push(node_counter);
-            }
-
-            node_counter += 1;
+    for (node_counter, (node, _)) in get_node_lines(file).iter().enumerate() {
+        if node.ends_with("A") {
+            start_nodes.push(node_counter);
+        } else if node.ends_with("Z") {
+            end_nodes.push(node_counter);
         }
     }
 
@@ -84,63 +83,65 @@ pub fn get_start_end_nodes(file: &str) -> (Vec<usize>, Vec<usize>) {
 // parse all nodes and record the index of every
 // left/right jump of each node in the network vector
 pub fn parse_network(
-    file: &str, node_locations: &HashMap<&str, usize>) -> Vec<JumpNodes> {
+    file: &str, node_locations: &FxHashMap<&str, usize>) ->
+    Result<Vec<JumpNodes>, SolveError> {
+    let mut network: Vec<JumpNodes> = Vec::new();
+
+    for (_, mut line) in get_node_lines(file) {
+        network.push(parse_jump_nodes(&mut line, node_locations)
+            .map_err(|err| err.invalid_input(DAY))?);
+    }
+
+    return Ok(network);
+}
+
+// parse the part between the braces of a node line
+fn parse_jump_nodes(
+    line: &mut Scanner, node_locations: &FxHashMap<&str, usize>) ->
+    Result<JumpNodes, ScanError> {
     const JUMP_NODE_DELIMITER: &str = ", ";
 
-    let mut network: Vec<JumpNodes> = Vec::new();
+    let left_node: usize = parse_location(line, node_locations)?;
+    line.expect_literal(JUMP_NODE_DELIMITER)?;
+    let right_node: usize = parse_location(line, node_locations)?;
+    line.expect_literal(")")?;
+    line.expect_end()?;
 
-    for mut line in file.split("\n") {
-        line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+    return Ok([left_node, right_node]);
+}
 
-        let location_delimiter: Option<usize> = line.find(LOCATION_DELIMITER);
-        let has_location: bool = location_delimiter.is_some();
-
-        if has_location {
-            let jump_nodes_start_idx: usize =
-                location_delimiter.unwrap() + LOCATION_DELIMITER.len();
-            let jump_nodes_end_idx: usize = line.len() - 1;
-
-            // collect part between the braces
-            let jump_nodes: &str =
-                &line[jump_nodes_start_idx..jump_nodes_end_idx];
-            
-            assert!(jump_nodes.find(JUMP_NODE_DELIMITER).is_some());
-            let jump_node_delim_idx: usize =
-                jump_nodes.find(JUMP_NODE_DELIMITER).unwrap();
-            
-            let left_node: &str = &jump_nodes[..jump_node_delim_idx];
-            let right_node: &str =
-                &jump_nodes[jump_node_delim_idx+JUMP_NODE_DELIMITER.len()..];
-            
-            assert!(node_locations.contains_key(left_node));
-            assert!(node_locations.contains_key(right_node));
-
-
-            network.push(
-                [node_locations[left_node], node_locations[right_node]]);
-        }
-    }
+fn parse_location(
+    line: &mut Scanner, node_locations: &FxHashMap<&str, usize>) ->
+    Result<usize, ScanError> {
+    let start: Scanner = *line;
+    let node: &str = line.take_while(|c| c.is_ascii_alphanumeric());
 
-    return network;
+    return node_locations.get(node).copied().ok_or_else(
+        || start.error("a known node"));
 }
 
 pub fn calc_total_steps_puzzle1(
     directions: &Vec<u8>,
-    node_locations: &HashMap<&str, usize>,
-    network: &Vec<JumpNodes>) -> u32 {
+    node_locations: &FxHashMap<&str, usize>,
+    network: &Vec<JumpNodes>) -> Result<u32, SolveError> {
     let mut total_steps: u32 = 0;
 
-    let end_node: usize = get_destination_idx(node_locations);
-
-    let mut current_node: usize = node_locations[START_NODE];
+    let (mut current_node, end_node): (usize, usize) =
+        match (node_locations.get(START_NODE), node_locations.get(END_NODE)) {
+            (Some(start_node), Some(end_node)) => (*start_node, *end_node),
+            _ => return Err(SolveError::invalid_input(
+                DAY, "Expected the network to contain 'AAA' and 'ZZZ'")),
+        };
 
     // index to track the current direction (either left or right)
     let mut direction_idx: usize = 0;
 
     while current_node != end_node {
+        if total_steps as usize >= max_steps(directions, network) {
+            return Err(SolveError::invalid_input(
+                DAY, "'ZZZ' can't be reached from 'AAA'"));
+        }
+
         // direction is either 0 ('L') or 1 ('R')
         let direction: usize = directions[direction_idx] as usize;
 
@@ -155,14 +156,14 @@ pub fn calc_total_steps_puzzle1(
         }
     }
 
-    return total_steps;
+    return Ok(total_steps);
 }
 
 pub fn calc_total_steps_puzzle2(
     directions: &Vec<u8>,
     network: &Vec<JumpNodes>,
     start_nodes: Vec<usize>,
-    end_nodes: Vec<usize>) -> u64 {
+    end_nodes: Vec<usize>) -> Result<u64, SolveError> {
     // index to track the current direction (either left or right)
     let mut direction_idx: usize = 0;
     let mut steps_per_node: Vec<u64> = Vec::new();
@@ -174,6 +175,11 @@ pub fn calc_total_steps_puzzle2(
         let found_end_node = |node: usize| end_nodes.contains(&node);
 
         while !found_end_node(current_node) {
+            if steps as usize >= max_steps(directions, network) {
+                return Err(SolveError::invalid_input(
+                    DAY, "An end node can't be reached from a start node"));
+            }
+
             // direction is either 0 ('L') or 1 ('R')
             let direction: usize = directions[direction_idx] as usize;
     
@@ -195,16 +201,61 @@ pub fn calc_total_steps_puzzle2(
     // of the needed steps of all starting nodes
     let mut total_steps: u64 = 1;
     for steps in steps_per_node {
-        total_steps = lcm(total_steps, steps);
+        total_steps = lcm(total_steps, steps)?;
+    }
+
+    return Ok(total_steps);
+}
+
+// walk all ghosts at once until they all stand on end nodes at the same
+// time, which (unlike the LCM) doesn't assume anything about the cycles
+// of the walks but takes as many steps as the answer
+pub fn calc_total_steps_puzzle2_simulation(
+    directions: &Vec<u8>,
+    network: &Vec<JumpNodes>,
+    start_nodes: Vec<usize>,
+    end_nodes: Vec<usize>) -> Result<u64, SolveError> {
+    let mut is_end_node: Vec<bool> = vec![false; network.len()];
+    for node in end_nodes {
+        is_end_node[node] = true;
+    }
+
+    // every combination of the nodes of all ghosts and the direction
+    // has been visited after this many steps
+    let max_steps: u64 = (directions.len() as u64).saturating_mul(
+        (network.len() as u64).saturating_pow(start_nodes.len() as u32));
+
+    let mut current_nodes: Vec<usize> = start_nodes;
+    let mut direction_idx: usize = 0;
+    let mut total_steps: u64 = 0;
+
+    while !current_nodes.iter().all(|node| is_end_node[*node]) {
+        if total_steps >= max_steps {
+            return Err(SolveError::invalid_input(
+                DAY, "The ghosts never stand on end nodes at the same time"));
+        }
+
+        // direction is either 0 ('L') or 1 ('R')
+        let direction: usize = directions[direction_idx] as usize;
+
+        for node in current_nodes.iter_mut() {
+            *node = network[*node][direction];
+        }
+        total_steps += 1;
+
+        direction_idx += 1;
+        if direction_idx == directions.len() {
+            direction_idx = 0;
+        }
     }
 
-    return total_steps;
+    return Ok(total_steps);
 }
 
-// returns the index of the destination/end node "ZZZ" in the network vector
-fn get_destination_idx(node_locations: &HashMap<&str, usize>) -> usize {
-    assert!(node_locations.contains_key(END_NODE));
-    return node_locations[END_NODE];
+// after this many steps, every combination of node and direction
+// has been visited, so the walk is stuck in a loop if it didn't end yet
+fn max_steps(directions: &Vec<u8>, network: &Vec<JumpNodes>) -> usize {
+    return directions.len() * network.len();
 }
 
 // calculate greatest common divisor (GCD) of two numbers
@@ -220,6 +271,7 @@ fn gcd(a: u64, b: u64) -> u64 {
 // calculate least common multiplier (LCM) of two numbers
 // (needed to find the total steps needed to go to all end nodes
 //  from all start nodes in the network)
-fn lcm(a: u64, b: u64) -> u64 {
-    return (a / gcd(a, b)) * b; 
+fn lcm(a: u64, b: u64) -> Result<u64, SolveError> {
+    return Ok(checked!(DAY, "lcm",
+        (a / gcd(a, b)).checked_mul(b), (a / gcd(a, b)) * b));
 }
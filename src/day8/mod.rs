@@ -1,50 +1,101 @@
 mod helpers;
 
-use std::collections::HashMap;
-use helpers::JumpNodes;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-pub fn puzzle1(file_content: String) -> String {
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+use crate::fxhash::FxHashMap;
+
+const DAY: u8 = 8;
+
+pub use helpers::JumpNodes;
+pub use helpers::parse_directions;
+pub use helpers::map_node_locations;
+pub use helpers::parse_network;
+
+type StepsFn = fn(&Vec<u8>, &Vec<JumpNodes>, Vec<usize>, Vec<usize>) ->
+    Result<u64, SolveError>;
+
+// directions and network of the map, with the nodes the ghosts start and
+// end their walks at
+pub struct Network<'a> {
+    pub directions: Vec<u8>,
+    pub node_locations: FxHashMap<&'a str, usize>,
+    pub network: Vec<JumpNodes>,
+    pub start_nodes: Vec<usize>,
+    pub end_nodes: Vec<usize>,
+}
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?, lap, solve1, solve2);
+}
+
+pub fn puzzle2_simulation(file_content: String) ->
+    Result<Answer, SolveError> {
+    return solve_puzzle2(&parse(&file_content)?,
+                         helpers::calc_total_steps_puzzle2_simulation);
+}
+
+pub fn parse(file_content: &str) -> Result<Network<'_>, SolveError> {
     // parse the directions
-    let directions: Vec<u8> = helpers::parse_directions(&file_content);
+    let directions: Vec<u8> = helpers::parse_directions(file_content)?;
 
     // collect all existing nodes as well as their index
-    // in the to-be-generated lookup array 
-    let node_locations: HashMap<&str, usize> =
-        helpers::map_node_locations(&file_content);
+    // in the to-be-generated lookup array
+    let node_locations: FxHashMap<&str, usize> =
+        helpers::map_node_locations(file_content);
 
     // parse the network by recording the indices of all left/right jumps
     // of every node in the network vector
     let network: Vec<JumpNodes> =
-        helpers::parse_network(&file_content, &node_locations);
+        helpers::parse_network(file_content, &node_locations)?;
 
-    // calculate the number of steps it takes to go from "AAA" to "ZZZ"
-    let total_steps: u32 = helpers::calc_total_steps_puzzle1(
-        &directions, &node_locations, &network);
+    let (start_nodes, end_nodes): (Vec<usize>, Vec<usize>) =
+        helpers::get_start_end_nodes(file_content);
 
-    return total_steps.to_string();
+    return Ok(Network{directions: directions, node_locations: node_locations,
+                      network: network, start_nodes: start_nodes,
+                      end_nodes: end_nodes});
 }
 
-pub fn puzzle2(file_content: String) -> String {
-    // parse the directions
-    let directions: Vec<u8> = helpers::parse_directions(&file_content);
+pub fn solve1(network: &Network) -> Result<Answer, SolveError> {
+    // calculate the number of steps it takes to go from "AAA" to "ZZZ"
+    let total_steps: u32 = helpers::calc_total_steps_puzzle1(
+        &network.directions, &network.node_locations, &network.network)?;
 
-    let (start_nodes, end_nodes): (Vec<usize>, Vec<usize>) =
-        helpers::get_start_end_nodes(&file_content);
+    return Ok(Answer::from(total_steps));
+}
 
-    // collect all existing nodes as well as their index
-    // in the to-be-generated lookup array 
-    let node_locations: HashMap<&str, usize> =
-        helpers::map_node_locations(&file_content);
+pub fn solve2(network: &Network) -> Result<Answer, SolveError> {
+    return solve_puzzle2(network, helpers::calc_total_steps_puzzle2);
+}
 
-    // parse the network by recording the indices of all left/right jumps
-    // of every node in the network vector
-    let network: Vec<JumpNodes> =
-        helpers::parse_network(&file_content, &node_locations);
+// count the steps of the ghosts with the provided function
+// (LCM of the single walks or simulation of all walks at once)
+fn solve_puzzle2(network: &Network, calc_total_steps: StepsFn) ->
+    Result<Answer, SolveError> {
+    if network.start_nodes.is_empty() {
+        return Err(SolveError::invalid_input(
+            DAY, "Expected at least one node ending with 'A'"));
+    }
 
     // calculate the number of steps it takes to go from all nodes
     // that end with 'A' to all nodes that end with 'Z'
-    let total_steps: u64 = helpers::calc_total_steps_puzzle2(
-        &directions, &network, start_nodes, end_nodes);
+    let total_steps: u64 = calc_total_steps(
+        &network.directions, &network.network, network.start_nodes.clone(),
+        network.end_nodes.clone())?;
 
-    return total_steps.to_string();
+    return Ok(Answer::from(total_steps));
 }
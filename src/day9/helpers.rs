@@ -1,28 +1,35 @@
+use alloc::vec::Vec;
+
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+use super::DAY;
+
 // parse all numbers from a line and store them in a vector
-pub fn parse_nums_from_line(line: &str) -> Vec<i32> {
+pub fn parse_nums_from_line(line: &mut Scanner) ->
+    Result<Vec<i64>, SolveError> {
     const NUM_DELIMITER: &str = " ";
 
-    let mut nums: Vec<i32> = Vec::new();
+    let nums: Vec<i64> = line.sep_by(NUM_DELIMITER, Scanner::take_int)
+        .map_err(|err| err.invalid_input(DAY))?;
+    line.expect_end().map_err(|err| err.invalid_input(DAY))?;
 
-    for num in line.split(NUM_DELIMITER) {
-        assert!(num.parse::<i32>().is_ok());
-        nums.push(num.parse().unwrap());
-    }
-
-    return nums;
+    return Ok(nums);
 }
 
 // find the next value for a history of numbers
 // (for space efficiency, nums vector is modified in place
 //  until the differences between adjacent numbers is 0)
-pub fn find_next_val_of_history(mut nums: Vec<i32>, at_beginning: bool) -> i32 {
+pub fn find_next_val_of_history(mut nums: Vec<i64>, at_beginning: bool) ->
+    Result<i64, SolveError> {
     // check if all numbers in the provided vector (range) are 0
     let all_nums_zero =
-        |vec: &Vec<i32>, n: usize| (&vec[0..n]).iter().all(|n| *n == 0);
+        |vec: &Vec<i64>, n: usize| vec[0..n].iter().all(|n| *n == 0);
 
     let total_nums: usize = nums.len();
 
-    let mut relevant_vals: Vec<i32> = Vec::new();
+    let mut relevant_vals: Vec<i64> = Vec::new();
 
     // we don't get the last number for the original number history
     // in the loop since the loop only iteratively processes the differences
@@ -35,13 +42,13 @@ pub fn find_next_val_of_history(mut nums: Vec<i32>, at_beginning: bool) -> i32 {
 
     // loop until all adjacent number differences are 0
     while !all_nums_zero(&nums, unprocessed_nums_end_idx) {
-        let unprocessed_nums: &mut [i32] =
+        let unprocessed_nums: &mut [i64] =
             &mut nums[0..unprocessed_nums_end_idx];
 
-        let mut relevant_val: i32 =
+        let mut relevant_val: i64 =
             get_relevant_val_at_beginning(unprocessed_nums);
 
-        calc_nums_difference(unprocessed_nums);
+        calc_nums_difference(unprocessed_nums)?;
  
         if !at_beginning {
             relevant_val = get_relevant_val_at_end(unprocessed_nums);
@@ -53,57 +60,80 @@ pub fn find_next_val_of_history(mut nums: Vec<i32>, at_beginning: bool) -> i32 {
     }
 
     // extrapolate the history
-    let next_val_of_history: i32 = if at_beginning {
-        extrapolate_history_backward(relevant_vals)
+    let next_val_of_history: i64 = if at_beginning {
+        extrapolate_history_backward(relevant_vals)?
     } else {
-        extrapolate_history_forward(relevant_vals)
+        extrapolate_history_forward(relevant_vals)?
     };
 
-    return next_val_of_history;
+    return Ok(next_val_of_history);
 }
 
 // calculate the adjacent differences of all numbers
 // in the provided vector slice (in-place)
-fn calc_nums_difference(nums: &mut [i32]) {
+fn calc_nums_difference(nums: &mut [i64]) -> Result<(), SolveError> {
     for i in 0..nums.len() - 1 {
-        nums[i] = nums[i+1] - nums[i];
+        nums[i] = checked!(DAY, "calc_nums_difference",
+            nums[i+1].checked_sub(nums[i]), nums[i+1] - nums[i]);
     }
+
+    return Ok(());
 }
 
 #[inline(always)]
-fn get_relevant_val_at_beginning(nums: &[i32]) -> i32 {
+fn get_relevant_val_at_beginning(nums: &[i64]) -> i64 {
     return nums[0];
 }
 
+// (if only one number is left, there are no more differences,
+//  which is treated like a final row of zeros)
 #[inline(always)]
-fn get_relevant_val_at_end(nums: &[i32]) -> i32 {
+fn get_relevant_val_at_end(nums: &[i64]) -> i64 {
+    if nums.len() < 2 {
+        return 0;
+    }
+
     return nums[nums.len() - 2];
 }
 
 // extrapolate the history (i.e. predict the next number of the history)
-fn extrapolate_history_forward(relevant_vals: Vec<i32>) -> i32 {
+fn extrapolate_history_forward(relevant_vals: Vec<i64>) ->
+    Result<i64, SolveError> {
     let mut relevant_vals_iter = relevant_vals.iter().rev();
-    let mut prev: i32 = *relevant_vals_iter.next().unwrap();
-    let mut next_val: i32 = 0;
+
+    // there are no relevant values if all numbers of the history are 0
+    let mut prev: i64 = match relevant_vals_iter.next() {
+        Some(val) => *val,
+        None => return Ok(0),
+    };
+    let mut next_val: i64 = prev;
 
     for val in relevant_vals_iter {
-        next_val = val + prev;
+        next_val = checked!(DAY, "extrapolate_history_forward",
+            val.checked_add(prev), val + prev);
         prev = next_val;
     }
 
-    return next_val;
+    return Ok(next_val);
 }
 
 // extrapolate the history (i.e. predict the next number of the history)
-fn extrapolate_history_backward(relevant_vals: Vec<i32>) -> i32 {
+fn extrapolate_history_backward(relevant_vals: Vec<i64>) ->
+    Result<i64, SolveError> {
     let mut relevant_vals_iter = relevant_vals.iter().rev();
-    let mut prev: i32 = *relevant_vals_iter.next().unwrap();
-    let mut next_val: i32 = 0;
+
+    // there are no relevant values if all numbers of the history are 0
+    let mut prev: i64 = match relevant_vals_iter.next() {
+        Some(val) => *val,
+        None => return Ok(0),
+    };
+    let mut next_val: i64 = prev;
 
     for val in relevant_vals_iter {
-        next_val = val - prev;
+        next_val = checked!(DAY, "extrapolate_history_backward",
+            val.checked_sub(prev), val - prev);
         prev = next_val;
     }
 
-    return next_val;
+    return Ok(next_val);
 }
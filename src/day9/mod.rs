@@ -1,43 +1,74 @@
 mod helpers;
 
-pub fn puzzle1(file_content: String) -> String {
-    let mut ans: i32 = 0;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-    for mut line in file_content.split("\n") {
-        line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::checked;
+use crate::error::SolveError;
+use crate::scan::Scanner;
 
-        let nums: Vec<i32> = helpers::parse_nums_from_line(line);
+pub use helpers::parse_nums_from_line;
+pub use helpers::find_next_val_of_history;
 
-        let at_beginning: bool = false;
-        let next_val_of_history: i32 =
-            helpers::find_next_val_of_history(nums, at_beginning);
+const DAY: u8 = 9;
 
-        ans += next_val_of_history;
-    }
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
 
-    return ans.to_string();
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?[..], lap, solve1, solve2);
 }
 
-pub fn puzzle2(file_content: String) -> String {
-    let mut ans: i32 = 0;
+// the values of every history
+pub fn parse(file_content: &str) -> Result<Vec<Vec<i64>>, SolveError> {
+    let mut histories: Vec<Vec<i64>> = Vec::new();
 
-    for mut line in file_content.split("\n") {
-        line = line.trim();
+    for mut line in Scanner::new(file_content).lines() {
         if line.is_empty() {
             continue;
         }
 
-        let nums: Vec<i32> = helpers::parse_nums_from_line(line);
+        histories.push(helpers::parse_nums_from_line(&mut line)?);
+    }
+
+    return Ok(histories);
+}
+
+pub fn solve1(histories: &[Vec<i64>]) -> Result<Answer, SolveError> {
+    let mut ans: i64 = 0;
+
+    for nums in histories {
+        let at_beginning: bool = false;
+        let next_val_of_history: i64 =
+            helpers::find_next_val_of_history(nums.clone(), at_beginning)?;
+
+        ans = checked!(DAY, "puzzle1",
+            ans.checked_add(next_val_of_history), ans + next_val_of_history);
+    }
+
+    return Ok(Answer::from(ans));
+}
+
+pub fn solve2(histories: &[Vec<i64>]) -> Result<Answer, SolveError> {
+    let mut ans: i64 = 0;
 
+    for nums in histories {
         let at_beginning: bool = true;
-        let next_val_of_history: i32 =
-            helpers::find_next_val_of_history(nums, at_beginning);
+        let next_val_of_history: i64 =
+            helpers::find_next_val_of_history(nums.clone(), at_beginning)?;
 
-        ans += next_val_of_history;
+        ans = checked!(DAY, "puzzle2",
+            ans.checked_add(next_val_of_history), ans + next_val_of_history);
     }
 
-    return ans.to_string();
+    return Ok(Answer::from(ans));
 }
@@ -0,0 +1,84 @@
+// errors a solver can report instead of a (wrong) answer
+
+use alloc::string::String;
+use alloc::string::ToString;
+use core::fmt;
+use core::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    // an arithmetic operation overflowed
+    // (only detected if built with the "checked" feature)
+    #[cfg_attr(not(feature = "checked"), allow(dead_code))]
+    Overflow {
+        day: u8,
+        function: &'static str,
+        file: &'static str,
+        line: u32,
+    },
+
+    // the input doesn't have the format the solver expects
+    InvalidInput {
+        day: u8,
+        message: String,
+    },
+
+    // the solver panicked (i.e. because of a malformed input)
+    Panic(String),
+
+    // the solver didn't finish in the configured time
+    Timeout(Duration),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Overflow{day, function, file, line} =>
+                write!(f, "Arithmetic overflow in day {}, {} ({}:{})",
+                       day, function, file, line),
+            SolveError::InvalidInput{day, message} =>
+                write!(f, "Invalid input for day {}: {}", day, message),
+            SolveError::Panic(msg) => write!(f, "{}", msg),
+            SolveError::Timeout(timeout) =>
+                write!(f, "Solver didn't finish within {:?}", timeout),
+        }
+    }
+}
+
+impl core::error::Error for SolveError {}
+
+impl SolveError {
+    pub fn invalid_input(day: u8, message: &str) -> SolveError {
+        return SolveError::InvalidInput{day: day, message: message.to_string()};
+    }
+}
+
+// evaluate an arithmetic operation that might overflow:
+// with the "checked" feature the checked version of the operation is
+// used and an overflow is returned as SolveError::Overflow (naming the day,
+// function and line of the operation) from the enclosing function;
+// without the feature the plain (unchecked) operation is used
+macro_rules! checked {
+    ($day:expr, $function:expr, $checked:expr, $unchecked:expr) => {{
+        #[cfg(feature = "checked")]
+        let value = match $checked {
+            Some(value) => value,
+            None => return Err($crate::error::SolveError::Overflow{
+                day: $day,
+                function: $function,
+                file: file!(),
+                line: line!(),
+            }),
+        };
+
+        #[cfg(not(feature = "checked"))]
+        let value = {
+            let _ = ($day, $function);
+            $unchecked
+        };
+
+        value
+    }};
+}
+
+pub(crate) use checked;
@@ -0,0 +1,75 @@
+// hasher for the hash maps and sets of the solvers, which mostly hash tiny
+// keys (chars, short strings and integers); it's the multiply and rotate
+// hash of the Rust compiler ("FxHash"), which is much faster than the
+// default SipHash for such keys, but not resistant against inputs that are
+// crafted to collide (puzzle inputs aren't); alloc has no hash maps, so
+// without the "std" feature the maps and sets are B-tree maps and sets
+
+use core::hash::BuildHasherDefault;
+use core::hash::Hasher;
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+#[cfg(feature = "std")]
+pub type FxHashMap<K, V> = std::collections::HashMap<K, V, FxBuildHasher>;
+#[cfg(feature = "std")]
+pub type FxHashSet<T> = std::collections::HashSet<T, FxBuildHasher>;
+
+#[cfg(not(feature = "std"))]
+pub type FxHashMap<K, V> = alloc::collections::BTreeMap<K, V>;
+#[cfg(not(feature = "std"))]
+pub type FxHashSet<T> = alloc::collections::BTreeSet<T>;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+    const ROTATE: u32 = 5;
+
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(FxHasher::ROTATE) ^ word)
+            .wrapping_mul(FxHasher::SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    // hash the bytes in words of 8 bytes (and the rest byte by byte)
+    fn write(&mut self, bytes: &[u8]) {
+        let mut words = bytes.chunks_exact(8);
+
+        for word in &mut words {
+            self.add_to_hash(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+        for byte in words.remainder() {
+            self.add_to_hash(*byte as u64);
+        }
+    }
+
+    // integers are hashed as a single word instead of byte by byte
+    fn write_u8(&mut self, num: u8) {
+        self.add_to_hash(num as u64);
+    }
+
+    fn write_u16(&mut self, num: u16) {
+        self.add_to_hash(num as u64);
+    }
+
+    fn write_u32(&mut self, num: u32) {
+        self.add_to_hash(num as u64);
+    }
+
+    fn write_u64(&mut self, num: u64) {
+        self.add_to_hash(num);
+    }
+
+    fn write_usize(&mut self, num: usize) {
+        self.add_to_hash(num as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        return self.hash;
+    }
+}
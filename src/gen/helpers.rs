@@ -0,0 +1,42 @@
+// small deterministic pseudo random number generator (SplitMix64),
+// so that a seed always generates the same input on every platform
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng{state: seed};
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        return z ^ (z >> 31);
+    }
+
+    // random number in [low, high)
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low < high);
+        return low + self.next_u64() % (high - low);
+    }
+
+    // random signed number in [low, high)
+    pub fn range_signed(&mut self, low: i64, high: i64) -> i64 {
+        return low + self.range(0, (high - low) as u64) as i64;
+    }
+
+    // true with a probability of percent/100
+    pub fn chance(&mut self, percent: u64) -> bool {
+        return self.range(0, 100) < percent;
+    }
+
+    // random element of a non-empty slice
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.range(0, items.len() as u64) as usize];
+    }
+}
@@ -0,0 +1,234 @@
+// generator of random but valid puzzle inputs for every day; the same
+// seed always generates the same input, so failures can be reproduced
+
+mod helpers;
+
+pub use helpers::Rng;
+
+use crate::day5;
+
+const DIGIT_WORDS: [&str; 9] =
+    ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// generate a valid input for a day
+pub fn generate(day: u8, seed: u64) -> Result<String, String> {
+    let mut rng: Rng = Rng::new(seed);
+
+    let lines: Vec<String> = match day {
+        1 => generate_day1(&mut rng),
+        2 => generate_day2(&mut rng),
+        3 => generate_day3(&mut rng),
+        4 => generate_day4(&mut rng),
+        5 => generate_day5(&mut rng),
+        6 => generate_day6(&mut rng),
+        7 => generate_day7(&mut rng),
+        8 => generate_day8(&mut rng),
+        9 => generate_day9(&mut rng),
+        _ => return Err(format!("No input generator for AOC day {}", day)),
+    };
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+// lines of letters, digits and digit words with at least one digit
+fn generate_day1(rng: &mut Rng) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for _ in 0..rng.range(5, 20) {
+        let mut line: String = String::new();
+        let digit_pos: u64 = rng.range(0, 6);
+
+        for i in 0..6 {
+            if i == digit_pos || rng.chance(20) {
+                line.push((b'1' + rng.range(0, 9) as u8) as char);
+            } else if rng.chance(40) {
+                line += *rng.choose(&DIGIT_WORDS);
+            } else {
+                line.push((b'a' + rng.range(0, 26) as u8) as char);
+            }
+        }
+
+        lines.push(line);
+    }
+
+    return lines;
+}
+
+// games of draws of up to three different colors
+fn generate_day2(rng: &mut Rng) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for game in 1..=rng.range(3, 15) {
+        let mut draws: Vec<String> = Vec::new();
+
+        for _ in 0..rng.range(1, 5) {
+            let mut color_draws: Vec<String> = Vec::new();
+
+            for color in ["red", "green", "blue"] {
+                if color_draws.is_empty() || rng.chance(60) {
+                    color_draws.push(
+                        format!("{} {}", rng.range(1, 20), color));
+                }
+            }
+
+            draws.push(color_draws.join(", "));
+        }
+
+        lines.push(format!("Game {}: {}", game, draws.join("; ")));
+    }
+
+    return lines;
+}
+
+// grid of dots, symbols and separated numbers
+fn generate_day3(rng: &mut Rng) -> Vec<String> {
+    const SYMBOLS: [char; 10] =
+        ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+    let width: usize = rng.range(8, 20) as usize;
+    let mut lines: Vec<String> = Vec::new();
+
+    for _ in 0..rng.range(5, 15) {
+        let mut line: String = String::new();
+
+        while line.len() < width {
+            let num_len: usize = rng.range(1, 4) as usize;
+
+            if rng.chance(20) && line.len() + num_len < width {
+                // numbers are followed by a dot to keep them separated
+                line += &rng.range(10_u64.pow(num_len as u32 - 1),
+                                   10_u64.pow(num_len as u32)).to_string();
+                line.push('.');
+            } else if rng.chance(15) {
+                line.push(*rng.choose(&SYMBOLS));
+            } else {
+                line.push('.');
+            }
+        }
+
+        lines.push(line);
+    }
+
+    return lines;
+}
+
+// cards of 5 winning and 8 drawn numbers
+fn generate_day4(rng: &mut Rng) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for card in 1..=rng.range(3, 15) {
+        let winning_numbers: Vec<String> = (0..5).map(
+            |_| format!("{:>2}", rng.range(1, 100))).collect();
+        let drawn_numbers: Vec<String> = (0..8).map(
+            |_| format!("{:>2}", rng.range(1, 100))).collect();
+
+        lines.push(format!("Card {:>3}: {} | {}", card,
+                           winning_numbers.join(" "), drawn_numbers.join(" ")));
+    }
+
+    return lines;
+}
+
+// seed ranges followed by the seven maps
+fn generate_day5(rng: &mut Rng) -> Vec<String> {
+    let seeds: Vec<String> = (0..rng.range(1, 5)).map(
+        |_| format!("{} {}", rng.range(0, 100), rng.range(1, 20))).collect();
+
+    let mut lines: Vec<String> = vec![format!("seeds: {}", seeds.join(" "))];
+
+    for i in 0..day5::CATEGORIES.len() - 1 {
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:",
+                           day5::CATEGORIES[i], day5::CATEGORIES[i + 1]));
+
+        for _ in 0..rng.range(1, 5) {
+            lines.push(format!("{} {} {}", rng.range(0, 100),
+                               rng.range(0, 100), rng.range(1, 30)));
+        }
+    }
+
+    return lines;
+}
+
+// race times and record distances that can be beaten
+fn generate_day6(rng: &mut Rng) -> Vec<String> {
+    let mut times: String = String::from("Time:");
+    let mut distances: String = String::from("Distance:");
+
+    for _ in 0..rng.range(1, 5) {
+        let time: u64 = rng.range(2, 100);
+        let max_distance: u64 = (time / 2) * (time - time / 2);
+
+        times += &format!("  {:>4}", time);
+        distances += &format!(" {:>4}", rng.range(0, max_distance));
+    }
+
+    return vec![times, distances];
+}
+
+// hands of 5 cards with a bid
+fn generate_day7(rng: &mut Rng) -> Vec<String> {
+    const CARDS: [char; 13] =
+        ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+    let mut lines: Vec<String> = Vec::new();
+
+    for _ in 0..rng.range(3, 20) {
+        let hand: String = (0..5).map(|_| *rng.choose(&CARDS)).collect();
+        lines.push(format!("{} {}", hand, rng.range(1, 1000)));
+    }
+
+    return lines;
+}
+
+// directions and a network in which every jump leads closer to ZZZ,
+// so that ZZZ (and some node ending with Z) is reachable from every node
+fn generate_day8(rng: &mut Rng) -> Vec<String> {
+    let directions: String = (0..rng.range(1, 8)).map(
+        |_| *rng.choose(&['L', 'R'])).collect();
+
+    let node_count: usize = rng.range(3, 12) as usize;
+
+    let mut nodes: Vec<String> = vec![String::from("AAA")];
+    while nodes.len() < node_count - 1 {
+        let node: String = (0..3).map(
+            |_| (b'A' + rng.range(0, 26) as u8) as char).collect();
+
+        if !nodes.contains(&node) && node != "ZZZ" {
+            nodes.push(node);
+        }
+    }
+    nodes.push(String::from("ZZZ"));
+
+    let mut lines: Vec<String> = vec![directions, String::new()];
+    let last: usize = nodes.len() - 1;
+
+    for (i, node) in nodes.iter().enumerate() {
+        let left: usize = (i + 1).min(last);
+        let right: usize = (i + rng.range(1, 3) as usize).min(last);
+
+        lines.push(format!("{} = ({}, {})", node, nodes[left], nodes[right]));
+    }
+
+    return lines;
+}
+
+// histories that are polynomials of degree 3 or less
+fn generate_day9(rng: &mut Rng) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for _ in 0..rng.range(3, 10) {
+        let coefficients: Vec<i64> = (0..rng.range(1, 5)).map(
+            |_| rng.range_signed(-5, 6)).collect();
+
+        let history: Vec<String> = (0..rng.range(6, 12) as i64).map(|x| {
+            let value: i64 = coefficients.iter().rev().fold(
+                0, |value, coefficient| value * x + coefficient);
+            return value.to_string();
+        }).collect();
+
+        lines.push(history.join(" "));
+    }
+
+    return lines;
+}
@@ -0,0 +1,102 @@
+use std::fmt::Write;
+
+use crate::json::Json;
+
+pub struct Node {
+    pub id: String,
+    pub attributes: Vec<(&'static str, Json)>,
+}
+
+pub struct Edge {
+    pub source: String,         // id of the node the edge starts at
+    pub target: String,         // id of the node the edge ends at
+    pub label: Option<String>,
+}
+
+pub struct Graph {
+    pub name: String,
+    pub directed: bool,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+impl Graph {
+    // Graphviz DOT with the attributes of every node and the edge labels
+    pub fn to_dot(&self) -> String {
+        let (keyword, edge_op): (&str, &str) = match self.directed {
+            true => ("digraph", "->"),
+            false => ("graph", "--"),
+        };
+
+        let mut dot: String = format!("{} {} {{\n", keyword, quote(&self.name));
+
+        for node in &self.nodes {
+            let attributes: Vec<String> = node.attributes.iter().map(
+                |(key, value)| format!("{}={}", key, dot_value(value)))
+                .collect();
+
+            match attributes.is_empty() {
+                true => writeln!(dot, "    {};", quote(&node.id)),
+                false => writeln!(dot, "    {} [{}];", quote(&node.id),
+                                  attributes.join(", ")),
+            }.unwrap();
+        }
+
+        for edge in &self.edges {
+            write!(dot, "    {} {} {}", quote(&edge.source), edge_op,
+                   quote(&edge.target)).unwrap();
+            if let Some(label) = &edge.label {
+                write!(dot, " [label={}]", quote(label)).unwrap();
+            }
+            dot += ";\n";
+        }
+
+        dot += "}\n";
+
+        return dot;
+    }
+
+    // node-link JSON (the format of networkx and d3): the attributes of a
+    // node are stored next to its id
+    pub fn to_json(&self) -> Json {
+        let nodes: Vec<Json> = self.nodes.iter().map(|node| {
+            let mut pairs: Vec<(&str, Json)> =
+                vec![("id", Json::string(&node.id))];
+            pairs.extend(node.attributes.iter().cloned());
+
+            return Json::object(pairs);
+        }).collect();
+
+        let links: Vec<Json> = self.edges.iter().map(|edge| {
+            let mut pairs: Vec<(&str, Json)> = vec![
+                ("source", Json::string(&edge.source)),
+                ("target", Json::string(&edge.target)),
+            ];
+            if let Some(label) = &edge.label {
+                pairs.push(("label", Json::string(label)));
+            }
+
+            return Json::object(pairs);
+        }).collect();
+
+        return Json::object(vec![
+            ("directed", Json::Bool(self.directed)),
+            ("multigraph", Json::Bool(true)),
+            ("graph", Json::object(vec![("name", Json::string(&self.name))])),
+            ("nodes", Json::Array(nodes)),
+            ("links", Json::Array(links)),
+        ]);
+    }
+}
+
+// quoted DOT id, which may contain any character but quotes
+fn quote(id: &str) -> String {
+    return format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""));
+}
+
+fn dot_value(value: &Json) -> String {
+    return match value {
+        Json::String(text) => quote(text),
+        _ => value.to_string(),
+    };
+}
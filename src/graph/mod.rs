@@ -0,0 +1,196 @@
+// graph-shaped puzzle data exported as Graphviz DOT or node-link JSON, so
+// it can be inspected with other tools than the printed vectors
+
+mod helpers;
+
+pub use helpers::Edge;
+pub use helpers::Graph;
+pub use helpers::Node;
+
+use std::collections::HashMap;
+
+use crate::day3;
+use crate::day3::PartNumber;
+use crate::day8;
+use crate::day8::JumpNodes;
+use crate::fxhash::FxHashMap;
+use crate::json::Json;
+use crate::viz;
+use crate::viz::Cell;
+
+pub const FORMATS: [&str; 2] = ["dot", "json"];
+
+pub trait GraphExport {
+    fn graph(&self) -> Graph;
+
+    fn to_dot(&self) -> String {
+        return self.graph().to_dot();
+    }
+
+    fn to_json(&self) -> Json {
+        return self.graph().to_json();
+    }
+}
+
+// export the graph of an input of a day in one of the FORMATS
+pub fn export(day: u8, file_content: &str, format: &str) ->
+    Result<String, String> {
+    let graph: Box<dyn GraphExport> = match day {
+        3 => Box::new(Schematic::parse(file_content)?),
+        8 => Box::new(Network::parse(file_content)?),
+        _ => return Err(format!("No graph for AOC day {}", day)),
+    };
+
+    return match format {
+        "dot" => Ok(graph.to_dot()),
+        "json" => Ok(format!("{}\n", graph.to_json())),
+        _ => Err(format!("Unknown graph format '{}'", format)),
+    };
+}
+
+// day 8: every node with its left and right jump
+struct Network<'a> {
+    nodes: Vec<&'a str>,    // name of every node index
+    jumps: Vec<JumpNodes>,  // left and right jump of every node
+}
+
+impl<'a> Network<'a> {
+    fn parse(file_content: &'a str) -> Result<Network<'a>, String> {
+        let node_locations: FxHashMap<&str, usize> =
+            day8::map_node_locations(file_content);
+        let jumps: Vec<JumpNodes> =
+            day8::parse_network(file_content, &node_locations)
+                .map_err(|err| err.to_string())?;
+
+        // a node defined twice would leave an index without a name
+        if node_locations.len() != jumps.len() {
+            return Err(String::from("A node of the network is defined twice"));
+        }
+
+        let mut nodes: Vec<&str> = vec![""; jumps.len()];
+        for (node, idx) in node_locations {
+            nodes[idx] = node;
+        }
+
+        return Ok(Network{nodes: nodes, jumps: jumps});
+    }
+}
+
+impl GraphExport for Network<'_> {
+    // the ..A nodes are where the ghosts start, the ..Z nodes where they end
+    fn graph(&self) -> Graph {
+        let nodes: Vec<Node> = self.nodes.iter().map(|node| Node{
+            id: node.to_string(),
+            attributes: vec![
+                ("start", Json::Bool(node.ends_with('A'))),
+                ("end", Json::Bool(node.ends_with('Z'))),
+            ],
+        }).collect();
+
+        let mut edges: Vec<Edge> = Vec::new();
+        for (node, jump_nodes) in self.nodes.iter().zip(&self.jumps) {
+            for (label, jump_node) in ["L", "R"].iter().zip(jump_nodes) {
+                edges.push(Edge{
+                    source: node.to_string(),
+                    target: self.nodes[*jump_node].to_string(),
+                    label: Some(label.to_string()),
+                });
+            }
+        }
+
+        return Graph{name: String::from("day8"), directed: true,
+                     nodes: nodes, edges: edges};
+    }
+}
+
+// day 3: the numbers of the schematic and the symbols they are adjacent to
+struct Schematic<'a> {
+    rows: Vec<&'a str>,
+    part_numbers: Vec<(usize, PartNumber)>,  // row and part number
+    cells: HashMap<(usize, usize), Cell>,
+}
+
+impl<'a> Schematic<'a> {
+    fn parse(file_content: &'a str) -> Result<Schematic<'a>, String> {
+        let rows: Vec<&str> = day3::get_schematic_lines(file_content)
+            .map_err(|err| err.to_string())?;
+
+        let mut part_numbers: Vec<(usize, PartNumber)> = Vec::new();
+        for i in 0..rows.len() {
+            for part_number in day3::get_part_numbers(&rows, i)
+                .map_err(|err| err.to_string())? {
+                part_numbers.push((i, part_number));
+            }
+        }
+
+        let cells: HashMap<(usize, usize), Cell> =
+            viz::schematic_cells(&rows).map_err(|err| err.to_string())?;
+
+        return Ok(Schematic{rows: rows, part_numbers: part_numbers,
+                            cells: cells});
+    }
+}
+
+impl GraphExport for Schematic<'_> {
+    // numbers and symbols are named after their position ("n ROW COLUMN"
+    // and "s ROW COLUMN"), since neither of them is unique
+    fn graph(&self) -> Graph {
+        let mut nodes: Vec<Node> = Vec::new();
+        let mut edges: Vec<Edge> = Vec::new();
+
+        for (row, part_number) in &self.part_numbers {
+            // the first digit of the number is its leftmost adjacent field
+            // in its own row
+            let column: usize = part_number.adjacent_fields.iter()
+                .filter(|field| field.line() == *row &&
+                        self.rows[*row].as_bytes()[field.column()]
+                            .is_ascii_digit())
+                .map(|field| field.column()).min().unwrap_or(0);
+            let id: String = format!("n {} {}", row, column);
+
+            nodes.push(Node{id: id.clone(), attributes: vec![
+                ("kind", Json::string("number")),
+                ("number", Json::Integer(part_number.num as i128)),
+                ("row", Json::Integer(*row as i128)),
+                ("column", Json::Integer(column as i128)),
+                ("part", Json::Bool(
+                    self.cells.get(&(*row, column)) ==
+                        Some(&Cell::PartNumber))),
+            ]});
+
+            for field in &part_number.adjacent_fields {
+                let symbol: u8 =
+                    self.rows[field.line()].as_bytes()[field.column()];
+
+                if !symbol.is_ascii_digit() && symbol != b'.' {
+                    edges.push(Edge{
+                        source: id.clone(),
+                        target: format!("s {} {}",
+                                        field.line(), field.column()),
+                        label: None,
+                    });
+                }
+            }
+        }
+
+        for (i, row) in self.rows.iter().enumerate() {
+            for (j, symbol) in row.chars().enumerate() {
+                if symbol.is_ascii_digit() || symbol == '.' {
+                    continue;
+                }
+
+                nodes.push(Node{id: format!("s {} {}", i, j), attributes: vec![
+                    ("kind", Json::string("symbol")),
+                    ("symbol", Json::string(&symbol.to_string())),
+                    ("row", Json::Integer(i as i128)),
+                    ("column", Json::Integer(j as i128)),
+                    ("gear", Json::Bool(
+                        self.cells.get(&(i, j)) == Some(&Cell::Gear))),
+                ]});
+            }
+        }
+
+        return Graph{name: String::from("day3"), directed: false,
+                     nodes: nodes, edges: edges};
+    }
+}
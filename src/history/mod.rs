@@ -0,0 +1,149 @@
+// saved benchmark results, read from and appended to a plain text file with
+// one "TIMESTAMP REVISION DAY PART STRATEGY INPUT_HASH RUNS MEDIAN_NS" entry
+// per line ('#' starts a comment), i.e.:
+//   # timestamp revision day part strategy input_hash runs median_ns
+//   1700000000 3faff06 7 1 compare 8d4e3c1a2b0f9e77 10 421337
+// the timestamp is in seconds since the Unix epoch
+
+use std::process::Command;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::bench::Bench;
+use crate::records;
+
+pub const DEFAULT_FILE: &str = "bench-history.txt";
+
+const HEADER: &str =
+    "# timestamp revision day part strategy input_hash runs median_ns\n";
+const FORMAT: &str =
+    "TIMESTAMP REVISION DAY PART STRATEGY INPUT_HASH RUNS MEDIAN_NS";
+
+pub struct Entry {
+    pub timestamp: u64,
+    pub revision: String,  // git revision the solvers were built from
+    pub day: u8,
+    pub puzzle: u8,
+    pub strategy: String,
+    pub input_hash: u64,
+    pub runs: usize,
+    pub median: Duration,
+}
+
+impl Entry {
+    // entry of a benchmark that was just run
+    pub fn new(bench: &Bench, timestamp: u64, revision: &str) -> Entry {
+        return Entry{
+            timestamp: timestamp,
+            revision: revision.to_string(),
+            day: bench.day,
+            puzzle: bench.puzzle,
+            strategy: bench.strategy.to_string(),
+            input_hash: bench.input_hash,
+            runs: bench.times.len(),
+            median: bench.median(),
+        };
+    }
+
+    fn parse(line: &str) -> Option<Entry> {
+        let components: Vec<&str> = line.split_whitespace().collect();
+        let [timestamp, revision, day, puzzle, strategy, input_hash, runs,
+             median] = components.as_slice() else {
+            return None;
+        };
+
+        return Some(Entry{
+            timestamp: timestamp.parse().ok()?,
+            revision: revision.to_string(),
+            day: day.parse().ok()?,
+            puzzle: puzzle.parse().ok()?,
+            strategy: strategy.to_string(),
+            input_hash: u64::from_str_radix(input_hash, 16).ok()?,
+            runs: runs.parse().ok()?,
+            median: Duration::from_nanos(median.parse().ok()?),
+        });
+    }
+
+    fn to_line(&self) -> String {
+        return format!("{} {} {} {} {} {:016x} {} {}\n",
+                       self.timestamp, self.revision, self.day, self.puzzle,
+                       self.strategy, self.input_hash, self.runs,
+                       self.median.as_nanos());
+    }
+
+    // whether the entry measured the same solver with the same input
+    // as a benchmark
+    fn matches(&self, bench: &Bench) -> bool {
+        return self.day == bench.day && self.puzzle == bench.puzzle &&
+               self.strategy == bench.strategy &&
+               self.input_hash == bench.input_hash;
+    }
+}
+
+pub struct History {
+    entries: Vec<Entry>,  // in the order they were saved
+}
+
+impl History {
+    // read the history file; a missing file is an empty history,
+    // malformed lines are reported with their number
+    pub fn read(path: &str) -> Result<History, String> {
+        let entries: Vec<Entry> =
+            records::read(path, "bench history", FORMAT, Entry::parse)?;
+
+        return Ok(History{entries: entries});
+    }
+
+    // the last saved entry of the same solver and input as a benchmark
+    pub fn baseline(&self, bench: &Bench) -> Option<&Entry> {
+        return self.entries.iter().rev().find(|entry| entry.matches(bench));
+    }
+}
+
+// append entries to the history file (creating it with a header
+// if it doesn't exist yet)
+pub fn append(path: &str, entries: &[Entry]) -> Result<(), String> {
+    let lines: Vec<String> = entries.iter().map(Entry::to_line).collect();
+
+    return records::append(path, "bench history", HEADER, &lines);
+}
+
+// seconds since the Unix epoch
+pub fn timestamp() -> u64 {
+    return SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs()).unwrap_or(0);
+}
+
+// short hash of the checked out git revision ("unknown" outside of a git
+// repository), with a "+dirty" suffix if there are uncommitted changes
+pub fn revision() -> String {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git").args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        return Some(String::from_utf8_lossy(&output.stdout).trim()
+                    .to_string());
+    };
+
+    let revision: String = match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(revision) if !revision.is_empty() => revision,
+        _ => return String::from("unknown"),
+    };
+
+    return match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{}+dirty", revision),
+        _ => revision,
+    };
+}
+
+// relative change of a median compared with its baseline (0.1 is 10%
+// slower, -0.1 is 10% faster)
+pub fn change(baseline: Duration, median: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0.0;
+    }
+
+    return median.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+}
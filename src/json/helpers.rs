@@ -0,0 +1,275 @@
+use super::Json;
+
+// nesting depth of arrays and objects at which the parser gives up
+// (instead of overflowing the stack on hostile input)
+const MAX_DEPTH: usize = 128;
+
+// recursive descent parser over the bytes of a JSON document; errors
+// carry the byte offset they occurred at
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+// parse a complete JSON document (RFC 8259)
+pub fn parse(text: &str) -> Result<Json, String> {
+    let mut parser: Parser = Parser{bytes: text.as_bytes(), pos: 0, depth: 0};
+
+    let value: Json = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < parser.bytes.len() {
+        return Err(parser.error("the end of the document"));
+    }
+
+    return Ok(value);
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &str) -> String {
+        return match self.bytes.get(self.pos) {
+            Some(_) => format!("Expected {} at byte {}", expected, self.pos),
+            None => format!("Expected {} at the end of the document",
+                            expected),
+        };
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.bytes.get(self.pos).is_some_and(
+            |b| matches!(b, b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        return self.bytes.get(self.pos).copied();
+    }
+
+    // consume a literal if it comes next
+    fn eat(&mut self, literal: &str) -> bool {
+        if self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            return true;
+        }
+
+        return false;
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+
+        return match self.peek() {
+            Some(b'{') => self.nested(Parser::object),
+            Some(b'[') => self.nested(Parser::array),
+            Some(b'"') => Ok(Json::String(self.string()?)),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ if self.eat("null") => Ok(Json::Null),
+            _ if self.eat("true") => Ok(Json::Bool(true)),
+            _ if self.eat("false") => Ok(Json::Bool(false)),
+            _ => Err(self.error("a value")),
+        };
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Json, String>) ->
+        Result<Json, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(format!("Nested deeper than {} levels at byte {}",
+                               MAX_DEPTH, self.pos));
+        }
+
+        self.depth += 1;
+        let value: Result<Json, String> = parse(self);
+        self.depth -= 1;
+
+        return value;
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        let mut pairs: Vec<(String, Json)> = Vec::new();
+        self.pos += 1;  // '{'
+
+        self.skip_whitespace();
+        if self.eat("}") {
+            return Ok(Json::Object(pairs));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("a key"));
+            }
+            let key: String = self.string()?;
+
+            self.skip_whitespace();
+            if !self.eat(":") {
+                return Err(self.error("':'"));
+            }
+            pairs.push((key, self.value()?));
+
+            self.skip_whitespace();
+            if self.eat("}") {
+                return Ok(Json::Object(pairs));
+            }
+            if !self.eat(",") {
+                return Err(self.error("',' or '}'"));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        let mut values: Vec<Json> = Vec::new();
+        self.pos += 1;  // '['
+
+        self.skip_whitespace();
+        if self.eat("]") {
+            return Ok(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            self.skip_whitespace();
+            if self.eat("]") {
+                return Ok(Json::Array(values));
+            }
+            if !self.eat(",") {
+                return Err(self.error("',' or ']'"));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let mut string: String = String::new();
+        self.pos += 1;  // '"'
+
+        loop {
+            // copy everything up to the next quote or escape at once
+            let start: usize = self.pos;
+            while self.peek().is_some_and(|b| b != b'"' && b != b'\\' &&
+                                               b >= 0x20) {
+                self.pos += 1;
+            }
+            // the input is a str and the run ends at an ASCII byte,
+            // so the run is valid UTF-8
+            string += std::str::from_utf8(&self.bytes[start..self.pos])
+                .unwrap();
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(string);
+                },
+                Some(b'\\') => {
+                    self.pos += 1;
+                    string.push(self.escape()?);
+                },
+                _ => return Err(self.error("'\"'")),
+            }
+        }
+    }
+
+    // character of an escape sequence (after the backslash)
+    fn escape(&mut self) -> Result<char, String> {
+        let chr: char = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+                return self.unicode_escape();
+            },
+            _ => return Err(self.error("an escape sequence")),
+        };
+
+        self.pos += 1;
+        return Ok(chr);
+    }
+
+    // "\uXXXX", where characters outside of the BMP are written as a
+    // surrogate pair of two such escapes
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let start: usize = self.pos;
+        let high: u32 = self.hex4()?;
+
+        let code_point: u32 = match high {
+            0xd800..=0xdbff if self.eat("\\u") => {
+                let low: u32 = self.hex4()?;
+                if !(0xdc00..=0xdfff).contains(&low) {
+                    return Err(format!(
+                        "Invalid surrogate pair at byte {}", start));
+                }
+                0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+            },
+            _ => high,
+        };
+
+        return char::from_u32(code_point).ok_or_else(
+            || format!("Invalid unicode escape at byte {}", start));
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits: Option<&[u8]> = self.bytes.get(self.pos..self.pos + 4);
+        let value: Option<u32> = digits.filter(
+            |digits| digits.iter().all(u8::is_ascii_hexdigit)).and_then(
+            |digits| u32::from_str_radix(
+                std::str::from_utf8(digits).ok()?, 16).ok());
+
+        let Some(value) = value else {
+            return Err(self.error("4 hex digits"));
+        };
+
+        self.pos += 4;
+        return Ok(value);
+    }
+
+    // integers without fraction and exponent stay exact, all other
+    // numbers are floats
+    fn number(&mut self) -> Result<Json, String> {
+        let start: usize = self.pos;
+        let digits = |parser: &mut Parser| -> usize {
+            let digits_start: usize = parser.pos;
+            while parser.peek().is_some_and(|b| b.is_ascii_digit()) {
+                parser.pos += 1;
+            }
+            return parser.pos - digits_start;
+        };
+
+        self.eat("-");
+        // no leading zeros
+        if !self.eat("0") && digits(self) == 0 {
+            return Err(self.error("a digit"));
+        }
+
+        let mut is_integer: bool = true;
+        if self.eat(".") {
+            is_integer = false;
+            if digits(self) == 0 {
+                return Err(self.error("a digit"));
+            }
+        }
+        if self.eat("e") || self.eat("E") {
+            is_integer = false;
+            let _ = self.eat("+") || self.eat("-");
+            if digits(self) == 0 {
+                return Err(self.error("a digit"));
+            }
+        }
+
+        let text: &str = std::str::from_utf8(&self.bytes[start..self.pos])
+            .unwrap();
+
+        if is_integer {
+            if let Ok(num) = text.parse() {
+                return Ok(Json::Integer(num));
+            }
+        }
+
+        return text.parse().map(Json::Float).map_err(
+            |_| format!("Invalid number at byte {}", start));
+    }
+}
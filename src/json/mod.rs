@@ -0,0 +1,131 @@
+// minimal JSON value type used for the machine-readable output
+// of the runner and to read JSON files (no external crates needed)
+
+mod helpers;
+
+use std::fmt;
+
+use crate::answer::Answer;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Integer(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),  // keeps the insertion order of the keys
+}
+
+impl Json {
+    // build an object from a list of key-value pairs
+    pub fn object(pairs: Vec<(&str, Json)>) -> Json {
+        return Json::Object(
+            pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect());
+    }
+
+    pub fn string(s: &str) -> Json {
+        return Json::String(s.to_string());
+    }
+
+    // numbers stay numbers, so they can't be mistaken for text answers;
+    // an unsolved puzzle has no answer
+    pub fn answer(answer: &Answer) -> Json {
+        return match answer {
+            Answer::Unsigned(num) => Json::Integer(*num as i128),
+            Answer::Signed(num) => Json::Integer(*num as i128),
+            Answer::Text(text) => Json::string(text),
+            Answer::Unsolved => Json::Null,
+        };
+    }
+
+    pub fn parse(text: &str) -> Result<Json, String> {
+        return helpers::parse(text);
+    }
+
+    // value of a key of an object (the last one if the key is repeated)
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        return match self {
+            Json::Object(pairs) => pairs.iter().rev().find(
+                |(k, _)| k == key).map(|(_, value)| value),
+            _ => None,
+        };
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        return match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        };
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        return match self {
+            Json::Integer(n) => Some(*n),
+            _ => None,
+        };
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Json)]> {
+        return match self {
+            Json::Object(pairs) => Some(pairs),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Integer(n) => write!(f, "{}", n),
+            // JSON has no representation for NaN/infinity
+            Json::Float(n) if !n.is_finite() => write!(f, "null"),
+            Json::Float(n) => write!(f, "{}", n),
+            Json::String(s) => write_escaped(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(pairs) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+// write a string as a quoted JSON string, escaping all
+// characters that aren't allowed to appear in it verbatim
+fn write_escaped(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for chr in s.chars() {
+        match chr {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+
+    return write!(f, "\"");
+}
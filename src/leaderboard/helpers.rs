@@ -0,0 +1,61 @@
+// date and time in UTC of a timestamp, i.e. "2023-12-01 05:11:12"
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (days, secs_of_day): (u64, u64) = (timestamp / 86400,
+                                           timestamp % 86400);
+    let (year, month, day): (u64, u64, u64) = civil_from_days(days);
+
+    return format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", year, month, day,
+                   secs_of_day / 3600, secs_of_day / 60 % 60,
+                   secs_of_day % 60);
+}
+
+// short duration with its two largest units, i.e. "5m 41s" or "2d 03h"
+pub fn format_duration(secs: u64) -> String {
+    return match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        3600..=86399 => format!("{}h {:02}m", secs / 3600, secs / 60 % 60),
+        _ => format!("{}d {:02}h", secs / 86400, secs / 3600 % 24),
+    };
+}
+
+// time from the first to the second timestamp, i.e. "+5m 41s"; negative
+// if the second one is earlier (i.e. in a hand edited export)
+pub fn format_gap(from: u64, to: u64) -> String {
+    return match to >= from {
+        true => format!("+{}", format_duration(to - from)),
+        false => format!("-{}", format_duration(from - to)),
+    };
+}
+
+// count with the singular or plural of a noun, i.e. "1 member"
+pub fn plural(count: usize, noun: &str) -> String {
+    return match count {
+        1 => format!("{} {}", count, noun),
+        _ => format!("{} {}s", count, noun),
+    };
+}
+
+// year, month and day of the days since 1970-01-01 in the proleptic
+// Gregorian calendar (Howard Hinnant's "civil_from_days")
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // count from 0000-03-01, so that the leap day ends a 400-year era
+    let days: u64 = days + 719468;
+    let era: u64 = days / 146097;
+    let day_of_era: u64 = days % 146097;
+    let year_of_era: u64 = (day_of_era - day_of_era / 1460 +
+                            day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year: u64 =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march: u64 = (5 * day_of_year + 2) / 153;
+
+    let day: u64 = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month: u64 = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year: u64 = year_of_era + era * 400 + (month <= 2) as u64;
+
+    return (year, month, day);
+}
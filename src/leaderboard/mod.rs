@@ -0,0 +1,192 @@
+// viewer of the JSON export of a private leaderboard, which looks like:
+//   {"event": "2023", "owner_id": 123, "members": {
+//     "123": {"id": 123, "name": "Alice", "local_score": 250, "stars": 3,
+//             "completion_day_level": {
+//               "1": {"1": {"get_star_ts": 1701407472, "star_index": 12},
+//                     "2": {"get_star_ts": 1701407835, "star_index": 40}},
+//               "2": {"1": {"get_star_ts": 1701493958, "star_index": 95}}}},
+//     ...}}
+// where the name of anonymous members is null; the days this crate solves
+// are shown next to the stars of the members
+
+mod helpers;
+
+use crate::json::Json;
+use crate::solver;
+
+const DAYS: u8 = 25;
+
+// timestamps (seconds since the Unix epoch) of the stars of a day
+pub struct DayStars {
+    pub day: u8,
+    pub part1: u64,
+    pub part2: Option<u64>,
+}
+
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub days: Vec<DayStars>,  // ordered by day, only days with a star
+}
+
+pub struct Leaderboard {
+    pub event: String,
+    pub members: Vec<Member>,  // ordered by their local score
+}
+
+impl Member {
+    fn from_json(json: &Json) -> Result<Member, String> {
+        let number = |key: &str| -> Result<u64, String> {
+            return json.get(key).and_then(Json::as_integer).and_then(
+                |n| u64::try_from(n).ok()).ok_or_else(
+                || format!("expected a number '{}'", key));
+        };
+
+        let mut days: Vec<DayStars> = Vec::new();
+        let completed: &[(String, Json)] =
+            match json.get("completion_day_level") {
+                Some(completed) => completed.as_object().ok_or_else(
+                    || String::from("expected an object \
+                                     'completion_day_level'"))?,
+                None => &[],
+            };
+
+        for (day, parts) in completed {
+            let star = |part: &str| -> Option<u64> {
+                return parts.get(part)?.get("get_star_ts")?.as_integer()
+                    .and_then(|ts| u64::try_from(ts).ok());
+            };
+
+            let day: u8 = match day.parse() {
+                Ok(day) if (1..=DAYS).contains(&day) => day,
+                _ => return Err(format!("invalid day '{}'", day)),
+            };
+            let part1: u64 = star("1").ok_or_else(|| format!(
+                "expected the time of the first star of day {}", day))?;
+
+            days.push(DayStars{day: day, part1: part1, part2: star("2")});
+        }
+        days.sort_by_key(|day| day.day);
+
+        return Ok(Member{
+            id: number("id")?,
+            name: json.get("name").and_then(Json::as_str).map(String::from),
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+            days: days,
+        });
+    }
+
+    // anonymous members are shown the way the website shows them
+    pub fn display_name(&self) -> String {
+        return match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        };
+    }
+
+    fn day(&self, day: u8) -> Option<&DayStars> {
+        return self.days.iter().find(|stars| stars.day == day);
+    }
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &Json) -> Result<Leaderboard, String> {
+        let event: String = match json.get("event") {
+            Some(Json::String(event)) => event.clone(),
+            Some(Json::Integer(event)) => event.to_string(),
+            _ => return Err(String::from("Expected a string 'event'")),
+        };
+
+        let members_json: &[(String, Json)] =
+            json.get("members").and_then(Json::as_object).ok_or_else(
+                || String::from("Expected an object 'members'"))?;
+
+        let mut members: Vec<Member> = Vec::new();
+        for (id, member) in members_json {
+            members.push(Member::from_json(member).map_err(
+                |err| format!("Member {}: {}", id, err))?);
+        }
+
+        // ties are broken by the stars and then the id, which keeps the
+        // order stable
+        members.sort_by(|a, b| b.local_score.cmp(&a.local_score)
+                        .then(b.stars.cmp(&a.stars)).then(a.id.cmp(&b.id)));
+
+        return Ok(Leaderboard{event: event, members: members});
+    }
+
+    // table of the stars of every member by day ('*' for both parts, '+'
+    // for only the first one), followed by the times of the stars and
+    // how long the second part took
+    pub fn render(&self) -> String {
+        let mut lines: Vec<String> = Vec::new();
+
+        lines.push(format!("Private leaderboard {} ({})", self.event,
+                           helpers::plural(self.members.len(), "member")));
+        lines.push(String::new());
+
+        let tens: String = (1..=DAYS).map(|day| match day / 10 {
+            0 => ' ',
+            tens => char::from(b'0' + tens),
+        }).collect();
+        let units: String =
+            (1..=DAYS).map(|day| char::from(b'0' + day % 10)).collect();
+        lines.push(format!("{:12}{}", "", tens));
+        lines.push(format!("{:12}{}", "", units));
+
+        let solved: String = (1..=DAYS).map(|day| {
+            let puzzles: usize = solver::get_solver(day).map_or(
+                0, |solver| solver.implemented_puzzles().len());
+            return star_symbol(puzzles);
+        }).collect();
+        lines.push(format!("{:12}{}  this crate", "", solved));
+
+        for (i, member) in self.members.iter().enumerate() {
+            let stars: String = (1..=DAYS).map(
+                |day| star_symbol(member.day(day).map_or(
+                    0, |stars| 1 + stars.part2.is_some() as usize))).collect();
+
+            lines.push(format!("{:>3}) {:>5}  {}  {}", i + 1,
+                               member.local_score, stars,
+                               member.display_name()));
+        }
+
+        for member in &self.members {
+            lines.push(String::new());
+            lines.push(format!("{} (local score {}, {})",
+                               member.display_name(), member.local_score,
+                               helpers::plural(member.stars as usize, "star")));
+
+            if member.days.is_empty() {
+                lines.push(String::from("  no stars yet"));
+            }
+
+            for stars in &member.days {
+                let mut line: String = format!(
+                    "  day {:>2}: part 1 {}", stars.day,
+                    helpers::format_timestamp(stars.part1));
+
+                if let Some(part2) = stars.part2 {
+                    line += &format!(
+                        ", part 2 {} ({})", helpers::format_timestamp(part2),
+                        helpers::format_gap(stars.part1, part2));
+                }
+
+                lines.push(line);
+            }
+        }
+
+        return lines.join("\n") + "\n";
+    }
+}
+
+fn star_symbol(stars: usize) -> char {
+    return match stars {
+        0 => '.',
+        1 => '+',
+        _ => '*',
+    };
+}
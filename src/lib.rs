@@ -0,0 +1,23 @@
+// parsers and solvers of all days; they only depend on core and alloc, so
+// without the default "std" feature the library is built with #![no_std]
+// (i.e. for embedded test rigs or kernels), while the runner in main.rs with
+// its file I/O and printing needs std
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub mod answer;
+pub mod error;
+pub mod fxhash;
+pub mod scan;
@@ -1,102 +1,1062 @@
 use std::env;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
 use std::process::exit;
-use std::{fs::File, io::Read};
-
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+use std::time::Duration;
+
+use adventofcode23::day1;
+use adventofcode23::day2;
+use adventofcode23::day3;
+use adventofcode23::day4;
+use adventofcode23::day5;
+use adventofcode23::day6;
+use adventofcode23::day7;
+use adventofcode23::day8;
+use adventofcode23::day9;
+use adventofcode23::answer;
+use adventofcode23::error;
+use adventofcode23::fxhash;
+use adventofcode23::scan;
+
+mod answers;
+mod bench;
+mod cache;
+mod check;
+mod cli;
+mod client;
+mod config;
+mod gen;
+mod graph;
+mod history;
+mod json;
+mod leaderboard;
+mod mem;
+mod records;
+mod repl;
+mod robust;
+mod runner;
+mod scaffold;
+mod server;
+mod snapshot;
+mod solver;
+mod svg;
+mod term;
+mod viz;
+mod watch;
+
+use answer::Answer;
+use answers::Answers;
+use bench::Bench;
+use cache::Cache;
+use check::Problem;
+use cli::Command;
+use cli::Matches;
+use client::Client;
+use client::Submission;
+use client::Submissions;
+use client::Verdict;
+use config::Config;
+use config::OutputFormat;
+use history::Entry;
+use history::History;
+use json::Json;
+use leaderboard::Leaderboard;
+use robust::RobustOptions;
+use runner::Run;
+use solver::BothSolution;
+use solver::Solution;
+use solver::Solver;
+use watch::WatchOptions;
+
+// counts allocations of the solvers if --mem is passed
+#[global_allocator]
+static ALLOCATOR: mem::CountingAllocator = mem::CountingAllocator;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() == 1 ||
-       args.len() == 2 && ["-h", "--help"].contains(&args[1].as_str()) {
-        println!("Usage: cargo run DAY PUZZLE path/to/input.txt");
+    if args.len() == 1 {
+        println!("{}", cli::help(None));
         exit(0);
     }
 
-    // get Advent of Code day
-    let aoc_day: u8 = args[1].parse().expect(
-        &format!("Provided AOC day need to be between 1 and 25!"));
+    // read the config files and let the command line options override them
+    let mut config: Config = Config::load().unwrap_or_else(|err| {
+        exit_with_error(&err);
+    });
+
+    let args: Vec<String> = config::apply_cli_options(
+        &mut config, &args[1..], cli::has_option).unwrap_or_else(
+            |err| exit_with_error(&err));
+
+    if args.is_empty() || ["-h", "--help"].contains(&args[0].as_str()) {
+        println!("{}", cli::help(None));
+        exit(0);
+    }
 
-    let aoc_puzzle_of_day: u8 = args[2].parse().unwrap();  // puzzle number
+    // "DAY PUZZLE [INPUT]" is short for "run DAY PUZZLE [INPUT]" (where
+    // run checks the range of any integer day); only the settings may come
+    // before the command, the options of a command follow it
+    let (command, args): (&Command, &[String]) =
+        match cli::get_command(&args[0]) {
+            Some(command) => (command, &args[1..]),
+            None if args[0].parse::<i128>().is_ok() =>
+                (cli::get_command("run").unwrap(), &args[..]),
+            None if args[0].starts_with('-') => exit_with_error(&format!(
+                "Option '{}' must follow the command it belongs to (only \
+                 settings like --jobs may come before the command)\n\
+                 Run '{} help' for all commands.", args[0], cli::BIN)),
+            None => exit_with_error(&format!(
+                "Unknown command '{}'\nRun '{} help' for all commands.",
+                args[0], cli::BIN)),
+        };
 
-    let input_file_path: &str = args[3].as_str();  // path to input file
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", cli::help(Some(command)));
+        exit(0);
+    }
 
-    let invalid_puzzle_num_str: String = String::from("Invalid puzzle number");
+    let matches: Matches = cli::parse(command, args).unwrap_or_else(|err| {
+        exit_with_error(&format!(
+            "{}\n\n{}\nRun '{} help {}' for more information.",
+            err, cli::usage(command), cli::BIN, command.name));
+    });
 
-    let mut input_file: File =
-        File::open(input_file_path).expect(
-            &format!("Couldn't open input file '{}'!", input_file_path));
+    if matches.flag("--no-cache") {
+        config.cache_dir = None;
+    }
 
-    let mut file_content: String = String::new();
-    input_file.read_to_string(&mut file_content).expect(
-        &format!("Couldn't read input file '{}'!", input_file_path));
+    match command.name {
+        "run" => run_puzzle(&mut config, &matches),
+        "all" => run_all(&config, &matches),
+        "bench" => run_bench(&config, &matches),
+        "verify" => verify_answers(&config, &matches),
+        "fetch" => fetch_input(&config, &matches),
+        "submit" => submit_answer(&config, &matches),
+        "leaderboard" => show_leaderboard(&matches),
+        "scaffold" => scaffold_day(&config, &matches),
+        "gen" => generate_input(&matches),
+        "list" => list_solvers(&matches),
+        "check" => check_input(&config, &matches),
+        "serve" => run_server(&config, &matches),
+        "repl" => run_repl(&config, &matches),
+        "watch" => run_watch(&config, &matches),
+        "snapshot" => print_snapshot(&config, &matches),
+        "robust" => run_robust(&config, &matches),
+        "viz" => render_visualization(&config, &matches),
+        "show" => show_input(&config, &matches),
+        "graph" => export_graph(&config, &matches),
+        "config" => show_config(&config),
+        "cache" => clear_cache(&config),
+        "completions" => print_completions(&matches),
+        "help" => println!("{}", cli::help(
+            matches.value("COMMAND").and_then(cli::get_command))),
+        "version" => println!("{} {}", cli::BIN, env!("CARGO_PKG_VERSION")),
+        _ => unreachable!("command '{}' has no handler", command.name),
+    }
+}
 
-    let result: String = match aoc_day {
-        1 => match aoc_puzzle_of_day {
-            1 => day1::puzzle1(file_content),
-            2 => day1::puzzle2(file_content),
-            _ => invalid_puzzle_num_str
-        },
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    exit(1);
+}
 
-        2 => match aoc_puzzle_of_day {
-            1 => day2::puzzle1(file_content),
-            2 => day2::puzzle2(file_content),
-            _ => invalid_puzzle_num_str
+// path of the input file passed as INPUT or the input of the day
+fn input_path(config: &Config, matches: &Matches, day: u8) -> String {
+    return match matches.value("INPUT") {
+        Some(path) => path.to_string(),
+        None => config.input_path(day),
+    };
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    return fs::read_to_string(path).map_err(
+        |err| format!("Couldn't read input file '{}': {}", path, err));
+}
+
+// solve a single puzzle of a day
+fn run_puzzle(config: &mut Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+
+    if matches.flag("--json") {
+        config.format = OutputFormat::Json;
+    }
+    if matches.flag("--mem") {
+        mem::enable();
+    }
+
+    if matches.value("PUZZLE") == Some("both") {
+        run_both(config, matches, aoc_day);
+        return;
+    }
+    let aoc_puzzle_of_day: u8 = matches.get("PUZZLE").unwrap();
+
+    // an unknown strategy is reported before the input is read
+    let strategy: Option<&str> = matches.value("--strategy");
+    if let Err(err) = solver::get_solver(aoc_day).unwrap().strategy(strategy) {
+        exit_with_error(&err);
+    }
+
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    let solution: Solution = match cache::solve(
+        config, aoc_day, aoc_puzzle_of_day, strategy, file_content) {
+        Ok(solution) => solution,
+        Err(err) => {
+            println!("Result of AOC day {}, puzzle {}: {}",
+                     aoc_day, aoc_puzzle_of_day, err);
+            exit(1);
         },
+    };
+
+    match config.format {
+        OutputFormat::Json =>
+            println!("{}", solution.to_json(aoc_day, aoc_puzzle_of_day)),
+        OutputFormat::Text =>
+            print_solution(aoc_day, aoc_puzzle_of_day, &solution),
+    }
+
+    if solution.answer.is_err() {
+        exit(1);
+    }
+}
+
+fn print_solution(aoc_day: u8, aoc_puzzle_of_day: u8, solution: &Solution) {
+    let cached: &str = if solution.cached { " (cached)" } else { "" };
+
+    match &solution.answer {
+        Ok(answer) => println!("Result of AOC day {}, puzzle {}: {}{}",
+                               aoc_day, aoc_puzzle_of_day, answer, cached),
+        Err(err) => eprintln!("Solver failed: {}", err),
+    }
+
+    if let Some(stats) = &solution.memory {
+        println!("Memory: {} allocations, {} deallocations, \
+                  {} bytes allocated, {} bytes peak",
+                 stats.allocations, stats.deallocations,
+                 stats.bytes_allocated, stats.peak_live_bytes);
+    }
+}
+
+// solve both puzzles of a day from a single parse of the input, which
+// isn't cached, since it's about timing the parse and each puzzle
+fn run_both(config: &Config, matches: &Matches, aoc_day: u8) {
+    if matches.value("--strategy").is_some() {
+        exit_with_error("--strategy can't be used with both, \
+                         which always uses the default strategy");
+    }
+
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    let solution: BothSolution =
+        solver::solve_both(aoc_day, file_content, config.timeout)
+        .unwrap_or_else(|err| exit_with_error(&err));
+
+    match config.format {
+        OutputFormat::Json => println!("{}", solution.to_json(aoc_day)),
+        OutputFormat::Text => print_both_solution(aoc_day, &solution),
+    }
 
-        3 => match aoc_puzzle_of_day {
-            1 => day3::puzzle1(file_content),
-            2 => day3::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+    if !solution.is_ok() {
+        exit(1);
+    }
+}
+
+fn print_both_solution(aoc_day: u8, solution: &BothSolution) {
+    match (&solution.answers, solution.failed_stage()) {
+        (Ok(answers), _) => {
+            for (i, answer) in answers.iter().enumerate() {
+                match answer {
+                    Ok(answer) => println!(
+                        "Result of AOC day {}, puzzle {}: {}",
+                        aoc_day, i + 1, answer),
+                    Err(err) => eprintln!("Solver failed (puzzle {}): {}",
+                                          i + 1, err),
+                }
+            }
         },
+        (Err(err), Some(stage)) =>
+            eprintln!("Solver failed ({}): {}", stage, err),
+        (Err(err), None) => eprintln!("Solver failed: {}", err),
+    }
 
-        4 => match aoc_puzzle_of_day {
-            1 => day4::puzzle1(file_content),
-            2 => day4::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+    let times: Vec<String> = solution.elapsed.iter().zip(
+        BothSolution::STAGES).map(
+        |(elapsed, stage)| format!("{} {:.3?}", stage, elapsed)).collect();
+    if !times.is_empty() {
+        println!("Time: {}", times.join(", "));
+    }
+
+    if let Some(stats) = &solution.memory {
+        println!("Memory: {} allocations, {} deallocations, \
+                  {} bytes allocated, {} bytes peak",
+                 stats.allocations, stats.deallocations,
+                 stats.bytes_allocated, stats.peak_live_bytes);
+    }
+}
+
+// print the catalog of all solvers
+fn list_solvers(matches: &Matches) {
+    if matches.flag("--json") {
+        let solvers: Vec<Json> =
+            solver::SOLVERS.iter().map(|solver| solver.to_json()).collect();
+        println!("{}", Json::Array(solvers));
+        return;
+    }
+
+    for solver in &solver::SOLVERS {
+        let puzzles: Vec<String> = solver.implemented_puzzles().iter().map(
+            |p| p.to_string()).collect();
+
+        println!("Day {}: {}", solver.day, solver.title);
+        println!("  url:      {}", solver.url());
+        println!("  input:    {}", solver.input_format);
+        println!("  puzzles:  {}", puzzles.join(", "));
+
+        for puzzle in solver.implemented_puzzles() {
+            println!("  puzzle {}: {}", puzzle,
+                     solver.default_strategy().complexity[
+                         puzzle as usize - 1]);
+        }
+
+        // days with a single strategy don't need to be told apart
+        if solver.strategies.len() > 1 {
+            for (i, strategy) in solver.strategies.iter().enumerate() {
+                let default: &str = if i == 0 { " (default)" } else { "" };
+                println!("  strategy: {}{}: {}",
+                         strategy.name, default, strategy.about);
+            }
+        }
+    }
+}
+
+// start the server with the provided options
+fn run_server(config: &Config, matches: &Matches) {
+    let host: &str = matches.value("--host").unwrap_or("127.0.0.1");
+    let port: u16 = matches.get("--port").unwrap_or(8023);
+
+    if matches.flag("--mem") {
+        mem::enable();
+    }
+
+    if let Err(err) = server::serve(host, port, config.timeout) {
+        exit_with_error(&format!("Server failed: {}", err));
+    }
+}
+
+fn run_repl(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = input_path(config, matches, aoc_day);
+
+    if let Err(err) = repl::run(aoc_day, &input_file_path) {
+        exit_with_error(&err);
+    }
+}
+
+fn run_watch(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+
+    // watch both puzzles of the day if no puzzle is specified
+    let parts: Vec<u8> = match matches.get("PUZZLE") {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let options: WatchOptions = WatchOptions{
+        day: aoc_day,
+        parts: parts,
+        input_file_path: matches.value("--input").map(|p| p.to_string())
+                                .unwrap_or(config.input_path(aoc_day)),
+        answers_file_path: config.answers_file.clone(),
+        poll_interval: Duration::from_millis(
+            matches.get("--interval").unwrap_or(500)),
+    };
+
+    if let Err(err) = watch::run(config, &options) {
+        exit_with_error(&err);
+    }
+}
+
+// read the answers file of the config (if there is one)
+fn read_answers(config: &Config) -> Option<Answers> {
+    return config.answers_file.as_ref().map(
+        |path| Answers::read(path).unwrap_or_else(|err| exit_with_error(&err)));
+}
+
+// run all puzzles of all days for which an input exists
+fn run_all(config: &Config, matches: &Matches) {
+    if matches.flag("--mem") {
+        mem::enable();
+    }
+
+    let answers: Option<Answers> = read_answers(config);
+
+    let days: Vec<u8> = solver::SOLVERS.iter().map(|s| s.day).collect();
+    let runs: Vec<Run> =
+        runner::run_days(config, &days, answers.as_ref(), false);
+
+    match config.format {
+        OutputFormat::Json => {
+            let runs: Vec<Json> = runs.iter().map(|r| r.to_json()).collect();
+            println!("{}", Json::Array(runs));
         },
+        OutputFormat::Text => {
+            for run in &runs {
+                print_run(run);
+            }
+        },
+    }
+
+    // fail if any of the answers is wrong
+    if runs.iter().any(|run| run.is_correct() == Some(false)) {
+        exit(1);
+    }
+}
 
-        5 => match aoc_puzzle_of_day {
-            1 => day5::puzzle1(file_content),
-            2 => day5::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+fn print_run(run: &Run) {
+    let solution: &Solution = match &run.solution {
+        Ok(solution) => solution,
+        Err(err) => {
+            println!("Day {}, puzzle {}: skipped ({})",
+                     run.day, run.puzzle, err);
+            return;
         },
+    };
+
+    let answer: String = match &solution.answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("failed ({})", err),
+    };
+
+    let verdict: String = match (run.is_correct(), &run.expected) {
+        (Some(true), _) => String::from(" [correct]"),
+        (Some(false), Some(expected)) => format!(" [expected {}]", expected),
+        _ => String::new(),
+    };
+
+    let cached: &str = if solution.cached { " (cached)" } else { "" };
+
+    println!("Day {}, puzzle {}: {}{} in {:.3?}{}",
+             run.day, run.puzzle, answer, verdict, solution.elapsed, cached);
+
+    if let Some(stats) = &solution.memory {
+        println!("  {} allocations, {} deallocations, \
+                  {} bytes allocated, {} bytes peak",
+                 stats.allocations, stats.deallocations,
+                 stats.bytes_allocated, stats.peak_live_bytes);
+    }
+}
+
+// solve the puzzles of one or all days repeatedly and report their runtime
+fn run_bench(config: &Config, matches: &Matches) {
+    let runs: u64 = matches.get("--runs").unwrap_or(10);
+    if runs == 0 {
+        exit_with_error(&cli::invalid_value("--runs", "0", "at least 1"));
+    }
+
+    let all_strategies: bool = matches.flag("--all-strategies");
+    if all_strategies && matches.value("--strategy").is_some() {
+        exit_with_error("Options '--strategy' and '--all-strategies' \
+                         can't be combined");
+    }
+
+    let days: Vec<u8> = match matches.get("DAY") {
+        Some(day) => vec![day],
+        None if matches.value("--input").is_some() =>
+            exit_with_error("Option '--input' needs a DAY"),
+        None if matches.value("--strategy").is_some() =>
+            exit_with_error("Option '--strategy' needs a DAY"),
+        None => solver::SOLVERS.iter().map(|s| s.day).collect(),
+    };
+
+    let mut benches: Vec<Bench> = Vec::new();
+    let mut failed: bool = false;
+
+    for day in days {
+        let solver: &Solver = solver::get_solver(day).unwrap();
+
+        let puzzles: Vec<u8> = match matches.get("PUZZLE") {
+            Some(puzzle) if solver.puzzle(puzzle).is_none() =>
+                exit_with_error(&format!(
+                    "Puzzle {} of day {} isn't solved yet", puzzle, day)),
+            Some(puzzle) => vec![puzzle],
+            None => solver.implemented_puzzles(),
+        };
+
+        let strategies: Vec<&str> = match all_strategies {
+            true => solver.strategy_names(),
+            false => vec![solver.strategy(matches.value("--strategy"))
+                          .unwrap_or_else(|err| exit_with_error(&err)).name],
+        };
+
+        let input_file_path: String = match matches.value("--input") {
+            Some(path) => path.to_string(),
+            None => config.input_path(day),
+        };
+
+        // days without an input are only skipped when benching all days
+        let file_content: String = match read_input(&input_file_path) {
+            Ok(file_content) => file_content,
+            Err(err) if matches.value("DAY").is_some() => exit_with_error(&err),
+            Err(err) => {
+                eprintln!("Day {}: skipped ({})", day, err);
+                continue;
+            },
+        };
+
+        for puzzle in puzzles {
+            let mut answers: Vec<(&str, Answer)> = Vec::new();
+
+            for strategy in &strategies {
+                // a strategy may not solve every puzzle (yet)
+                if solver.strategy(Some(strategy)).unwrap()
+                    .puzzle(puzzle).is_none() {
+                    continue;
+                }
+
+                match bench::run(day, puzzle, Some(strategy), &file_content,
+                                 runs) {
+                    Ok(bench) => {
+                        answers.push((strategy, bench.answer.clone()));
+                        benches.push(bench);
+                    },
+                    Err(err) => {
+                        eprintln!("Day {}, puzzle {}: failed ({})",
+                                  day, puzzle, err);
+                        failed = true;
+                    },
+                }
+            }
+
+            let answers: Vec<(&str, &Answer)> = answers.iter().map(
+                |(strategy, answer)| (*strategy, answer)).collect();
+            if let Err(err) = solver::compare_answers(&answers) {
+                eprintln!("Day {}, puzzle {}: failed ({})", day, puzzle, err);
+                failed = true;
+            }
+        }
+    }
+
+    let history_path: &str =
+        matches.value("--history").unwrap_or(history::DEFAULT_FILE);
+    let threshold: u64 = matches.get("--threshold").unwrap_or(10);
+
+    // compare with the history before the new results are saved to it
+    let history: Option<History> = match matches.flag("--compare") {
+        true => Some(History::read(history_path)
+                     .unwrap_or_else(|err| exit_with_error(&err))),
+        false => None,
+    };
+    let mut regressions: usize = 0;
 
-        6 => match aoc_puzzle_of_day {
-            1 => day6::puzzle1(file_content),
-            2 => day6::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+    match config.format {
+        OutputFormat::Json => {
+            let mut jsons: Vec<Json> = Vec::new();
+
+            for bench in &benches {
+                let mut json: Json = bench.to_json();
+
+                if let (Some(history), Json::Object(pairs)) =
+                    (&history, &mut json) {
+                    let baseline: Json = match history.baseline(bench) {
+                        Some(baseline) => {
+                            let regressed: bool =
+                                is_regression(bench, baseline, threshold);
+                            regressions += regressed as usize;
+
+                            Json::object(vec![
+                                ("revision", Json::string(&baseline.revision)),
+                                ("timestamp",
+                                 Json::Integer(baseline.timestamp as i128)),
+                                ("median_us", Json::Float(
+                                    baseline.median.as_secs_f64() *
+                                    1_000_000.0)),
+                                ("change", Json::Float(history::change(
+                                    baseline.median, bench.median()))),
+                                ("regression", Json::Bool(regressed)),
+                            ])
+                        },
+                        None => Json::Null,
+                    };
+                    pairs.push((String::from("baseline"), baseline));
+                }
+
+                jsons.push(json);
+            }
+
+            println!("{}", Json::Array(jsons));
         },
+        OutputFormat::Text => {
+            for bench in &benches {
+                // the strategies only need to be told apart when comparing
+                let strategy: String = match all_strategies {
+                    true => format!(" [{}]", bench.strategy),
+                    false => String::new(),
+                };
+
+                println!("Day {}, puzzle {}{}: min {:.3?}, mean {:.3?}, \
+                          max {:.3?} ({} runs)",
+                         bench.day, bench.puzzle, strategy, bench.min(),
+                         bench.mean(), bench.max(), bench.times.len());
+
+                let Some(history) = &history else {
+                    continue;
+                };
+
+                match history.baseline(bench) {
+                    Some(baseline) => {
+                        let regressed: bool =
+                            is_regression(bench, baseline, threshold);
+                        regressions += regressed as usize;
 
-        7 => match aoc_puzzle_of_day {
-            1 => day7::puzzle1(file_content),
-            2 => day7::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+                        println!("  median {:.3?} vs {:.3?} of {} \
+                                  ({:+.1}%): {}",
+                                 bench.median(), baseline.median,
+                                 baseline.revision,
+                                 history::change(baseline.median,
+                                                 bench.median()) * 100.0,
+                                 if regressed { "regression" } else { "ok" });
+                    },
+                    None => println!("  median {:.3?}: no baseline",
+                                     bench.median()),
+                }
+            }
         },
+    }
+
+    if matches.flag("--save") {
+        let timestamp: u64 = history::timestamp();
+        let revision: String = history::revision();
+        let entries: Vec<Entry> = benches.iter().map(
+            |bench| Entry::new(bench, timestamp, &revision)).collect();
+
+        history::append(history_path, &entries)
+            .unwrap_or_else(|err| exit_with_error(&err));
+    }
+
+    if regressions > 0 {
+        eprintln!("{} of {} benchmarks regressed by more than {}%",
+                  regressions, benches.len(), threshold);
+    }
+
+    if failed || regressions > 0 {
+        exit(1);
+    }
+}
+
+// whether the median of a benchmark is slower than the one of its
+// baseline by more than the threshold (in percent)
+fn is_regression(bench: &Bench, baseline: &Entry, threshold: u64) -> bool {
+    return history::change(baseline.median, bench.median()) * 100.0 >
+           threshold as f64;
+}
+
+// check the answers of one or all days against the answers file
+fn verify_answers(config: &Config, matches: &Matches) {
+    let answers: Answers = read_answers(config).unwrap_or_else(|| {
+        exit_with_error("No answers file to verify against \
+                         (set it with --answers or in the config file)");
+    });
+
+    let days: Vec<u8> = match matches.get("DAY") {
+        Some(day) => vec![day],
+        None => solver::SOLVERS.iter().map(|s| s.day).collect(),
+    };
+
+    let all_strategies: bool = matches.flag("--all-strategies");
+    let runs: Vec<Run> =
+        runner::run_days(config, &days, Some(&answers), all_strategies);
+
+    if config.format == OutputFormat::Json {
+        let runs: Vec<Json> = runs.iter().map(|r| r.to_json()).collect();
+        println!("{}", Json::Array(runs));
+    }
+
+    let mut wrong: usize = 0;
+    for run in &runs {
+        let verdict: String = match (&run.solution, &run.expected) {
+            (Err(err), _) => format!("skipped ({})", err),
+            (Ok(Solution{answer: Err(err), ..}), _) =>
+                format!("failed ({})", err),
+            (Ok(Solution{answer: Ok(Answer::Unsolved), ..}), _) =>
+                String::from("unsolved"),
+            (Ok(_), None) => String::from("no expected answer"),
+            (Ok(Solution{answer: Ok(answer), ..}), Some(expected)) =>
+                if answer.matches(expected) {
+                    String::from("ok")
+                } else {
+                    format!("WRONG (got {}, expected {})", answer, expected)
+                },
+        };
+
+        if run.is_correct() == Some(false) {
+            wrong += 1;
+        }
+
+        if config.format == OutputFormat::Text {
+            let strategy: String = match all_strategies {
+                true => format!(" [{}]", run.strategy),
+                false => String::new(),
+            };
+
+            println!("Day {}, puzzle {}{}: {}",
+                     run.day, run.puzzle, strategy, verdict);
+        }
+    }
+
+    // without an expected answer, the strategies can still be compared
+    // with each other
+    let mut checks: usize = runs.len();
+    if all_strategies {
+        for day in &days {
+            for puzzle in 1..=2 {
+                let answers: Vec<(&str, &Answer)> = runs.iter().filter_map(
+                    |run| match &run.solution {
+                        Ok(Solution{answer: Ok(answer), ..})
+                            if run.day == *day && run.puzzle == puzzle =>
+                            Some((run.strategy, answer)),
+                        _ => None,
+                    }).collect();
+                if answers.len() < 2 {
+                    continue;
+                }
 
-        8 => match aoc_puzzle_of_day {
-            1 => day8::puzzle1(file_content),
-            2 => day8::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+                if let Err(err) = solver::compare_answers(&answers) {
+                    if config.format == OutputFormat::Text {
+                        println!("Day {}, puzzle {}: {}", day, puzzle, err);
+                    }
+                    wrong += 1;
+                }
+                checks += 1;
+            }
+        }
+    }
+
+    if wrong > 0 {
+        exit_with_error(&format!("{} of {} answers are wrong",
+                                 wrong, checks));
+    }
+}
+
+// check an input file against the format of a day and report every problem
+fn check_input(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    let problems: Vec<Problem> = check::check(aoc_day, &file_content)
+        .unwrap_or_else(|err| exit_with_error(&err));
+
+    match config.format {
+        OutputFormat::Json => {
+            let problems: Vec<Json> =
+                problems.iter().map(|problem| problem.to_json()).collect();
+            println!("{}", Json::Array(problems));
+        },
+        OutputFormat::Text if problems.is_empty() =>
+            println!("{}: valid input for day {}", input_file_path, aoc_day),
+        OutputFormat::Text => {
+            for problem in &problems {
+                println!("{}: {}", input_file_path, problem);
+            }
+            println!("{} problems in the input for day {}",
+                     problems.len(), aoc_day);
         },
+    }
+
+    if !problems.is_empty() {
+        exit(1);
+    }
+}
+
+// client for the server of the config, which needs a session and the
+// plain HTTP proxy to reach the server through
+fn client(config: &Config) -> Client {
+    let Some(session) = &config.session else {
+        exit_with_error("No session to log in with; set 'session' in the \
+                         config (the session cookie of adventofcode.com)");
+    };
+    let Some(base_url) = &config.base_url else {
+        exit_with_error("No server to connect to; set 'base_url' in the \
+                         config to the http:// URL of a local TLS proxy to \
+                         https://adventofcode.com (HTTPS isn't supported)");
+    };
 
-        9 => match aoc_puzzle_of_day {
-            1 => day9::puzzle1(file_content),
-            2 => day9::puzzle2(file_content),
-            _ => invalid_puzzle_num_str,
+    return Client::new(base_url, session).unwrap_or_else(
+        |err| exit_with_error(&err));
+}
+
+// download the input of a day into the input directory, unless it was
+// downloaded before
+fn fetch_input(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = config.input_path(aoc_day);
+
+    // an empty input is a placeholder (see 'scaffold')
+    let exists: bool = fs::metadata(&input_file_path).is_ok_and(
+        |metadata| metadata.len() > 0);
+    if exists && !matches.flag("--force") {
+        println!("{} exists already (--force downloads it again)",
+                 input_file_path);
+        return;
+    }
+
+    let input: String = client(config).fetch_input(aoc_day).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    let write_input = || -> std::io::Result<()> {
+        fs::create_dir_all(&config.input_dir)?;
+        return fs::write(&input_file_path, &input);
+    };
+    if let Err(err) = write_input() {
+        exit_with_error(&format!("Couldn't write {}: {}",
+                                 input_file_path, err));
+    }
+
+    println!("Saved the input of day {} to {}", aoc_day, input_file_path);
+}
+
+// submit the answer of a puzzle (solving it unless --answer is passed),
+// unless earlier verdicts already tell that it's wrong
+fn submit_answer(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let aoc_puzzle_of_day: u8 = matches.get("PUZZLE").unwrap();
+
+    let answer: Answer = match matches.value("--answer") {
+        Some(answer) => Answer::parse(answer),
+        None => {
+            let input_file_path: String = config.input_path(aoc_day);
+            let file_content: String = read_input(&input_file_path)
+                .unwrap_or_else(|err| exit_with_error(&err));
+
+            match cache::solve(config, aoc_day, aoc_puzzle_of_day, None,
+                               file_content).and_then(|s| s.answer.map_err(
+                                   |err| format!("Solver failed: {}", err))) {
+                Ok(answer) if answer.is_solved() => answer,
+                Ok(_) => exit_with_error(&format!(
+                    "Day {}, puzzle {} is unsolved", aoc_day,
+                    aoc_puzzle_of_day)),
+                Err(err) => exit_with_error(&err),
+            }
         },
+    };
+
+    if let Err(err) = client::check_answer(&answer) {
+        exit_with_error(&err);
+    }
+
+    let submissions_path: String = config.submissions_path();
+    let submissions: Submissions = Submissions::read(&submissions_path)
+        .unwrap_or_else(|err| exit_with_error(&err));
+    if let Some(reason) =
+        submissions.reject(aoc_day, aoc_puzzle_of_day, &answer) {
+        exit_with_error(&reason);
+    }
+
+    let verdict: Verdict = client(config).submit(
+        aoc_day, aoc_puzzle_of_day, &answer).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    let submission: Submission =
+        Submission::new(aoc_day, aoc_puzzle_of_day, &answer, &verdict);
+    if let Err(err) = client::record(&submissions_path, &submission) {
+        exit_with_error(&err);
+    }
+
+    println!("Day {}, puzzle {}, answer {}: {}",
+             aoc_day, aoc_puzzle_of_day, answer, verdict);
+
+    if verdict != Verdict::Correct {
+        exit(1);
+    }
+}
+
+// print the stars of every member of a private leaderboard by day
+fn show_leaderboard(matches: &Matches) {
+    let path: &str = matches.value("PATH").unwrap();
+    let file_content: String = fs::read_to_string(path).unwrap_or_else(
+        |err| exit_with_error(&format!(
+            "Couldn't read leaderboard '{}': {}", path, err)));
+
+    let leaderboard: Leaderboard = Json::parse(&file_content).and_then(
+        |json| Leaderboard::from_json(&json)).unwrap_or_else(
+        |err| exit_with_error(&format!("{}: {}", path, err)));
 
-        _ => String::from("Invalid AOC day number")
+    print!("{}", leaderboard.render());
+}
+
+// generate the module, test and input of a new day and register it
+fn scaffold_day(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = match matches.get("N") {
+        Some(day) if (1..=25).contains(&day) => day,
+        _ => exit_with_error(&cli::invalid_value(
+            "N", matches.value("N").unwrap(), "a day from 1 to 25")),
     };
+    if solver::get_solver(aoc_day).is_some() {
+        exit_with_error(&format!("Day {} has a solver already", aoc_day));
+    }
 
-    println!("Result of AOC day {}, puzzle {}: {}",
-             aoc_day, aoc_puzzle_of_day, result);
+    let title: String = match matches.value("--title") {
+        Some(title) => title.to_string(),
+        None => format!("Day {}", aoc_day),
+    };
+    let root: PathBuf = PathBuf::from(matches.value("--root").unwrap_or("."));
+
+    let written: Vec<PathBuf> = scaffold::scaffold(
+        &root, aoc_day, &title, Path::new(&config.input_path(aoc_day)))
+        .unwrap_or_else(|err| exit_with_error(&err));
+
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+}
+
+// print the effective settings and where they come from
+fn show_config(config: &Config) {
+    for (key, value, source) in config.show() {
+        println!("{:<10} = {:<20} ({})", key, value, source);
+    }
+}
+
+// remove all cached answers (the only ACTION of the cache command)
+fn clear_cache(config: &Config) {
+    let Some(cache_dir) = &config.cache_dir else {
+        println!("The cache is disabled (cache_dir is empty)");
+        return;
+    };
+
+    match Cache::new(cache_dir).clear() {
+        Ok(removed) => println!("Removed {} cached answers from {}",
+                                removed, cache_dir),
+        Err(err) => exit_with_error(&err),
+    }
+}
+
+// print the intermediate structures of a day (see tests/snapshots.rs)
+fn print_snapshot(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    match solver::catch_panic(|| snapshot::dump(aoc_day, &file_content)) {
+        Ok(Ok(dump)) => print!("{}", dump),
+        Ok(Err(err)) => exit_with_error(&err),
+        Err(err) =>
+            exit_with_error(&format!("Couldn't parse input file: {}", err)),
+    }
+}
+
+// draw the input of a day as an SVG image
+fn render_visualization(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    let svg: String =
+        match solver::catch_panic(|| viz::render(aoc_day, &file_content)) {
+            Ok(Ok(svg)) => svg,
+            Ok(Err(err)) => exit_with_error(&err),
+            Err(err) => exit_with_error(
+                &format!("Couldn't parse input file: {}", err)),
+        };
+
+    match matches.value("--output") {
+        Some(path) => fs::write(path, svg).unwrap_or_else(|err| {
+            exit_with_error(&format!("Couldn't write {}: {}", path, err));
+        }),
+        None => print!("{}", svg),
+    }
+}
+
+// print the input of a day for the terminal, colored unless disabled
+// (or not printed to a terminal)
+fn show_input(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+    let color: bool =
+        term::use_color(matches.value("--color").unwrap_or("auto"));
+
+    match solver::catch_panic(|| term::render(aoc_day, &file_content, color)) {
+        Ok(Ok(output)) => print!("{}", output),
+        Ok(Err(err)) => exit_with_error(&err),
+        Err(err) =>
+            exit_with_error(&format!("Couldn't parse input file: {}", err)),
+    }
+}
+
+// print the graph of the input of a day in DOT or JSON
+fn export_graph(config: &Config, matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let input_file_path: String = input_path(config, matches, aoc_day);
+    let file_content: String = read_input(&input_file_path).unwrap_or_else(
+        |err| exit_with_error(&err));
+    let format: &str = matches.value("--format").unwrap_or("dot");
+
+    match solver::catch_panic(
+        || graph::export(aoc_day, &file_content, format)) {
+        Ok(Ok(graph)) => print!("{}", graph),
+        Ok(Err(err)) => exit_with_error(&err),
+        Err(err) =>
+            exit_with_error(&format!("Couldn't parse input file: {}", err)),
+    }
+}
+
+// print a random but valid input of a day
+fn generate_input(matches: &Matches) {
+    let aoc_day: u8 = matches.get("DAY").unwrap();
+    let seed: u64 = matches.get("--seed").unwrap_or(0);
+
+    match gen::generate(aoc_day, seed) {
+        Ok(input) => print!("{}", input),
+        Err(err) => exit_with_error(&err),
+    }
+}
+
+// feed N mutated inputs to the solvers of a day and report every crash
+fn run_robust(config: &Config, matches: &Matches) {
+    // a hanging solver is only detected with a timeout
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    let options: RobustOptions = RobustOptions{
+        day: matches.get("DAY").unwrap(),
+        iterations: matches.get("N").unwrap(),
+        seed: matches.get("--seed").unwrap_or(0),
+        timeout: config.timeout.unwrap_or(DEFAULT_TIMEOUT),
+        crash_dir: PathBuf::from(
+            matches.value("--crash-dir").unwrap_or("crashes")),
+    };
+
+    let summary: robust::Summary = robust::run(&options).unwrap_or_else(
+        |err| exit_with_error(&err));
+
+    for crash in &summary.crashes {
+        println!("Puzzle {}, seed {} {:?}: {}",
+                 crash.puzzle, crash.seed, crash.mutations, crash.error);
+        println!("  saved to {}", crash.path.display());
+    }
+
+    println!("Day {}: {} mutated inputs, {} answers, {} errors, {} crashes",
+             options.day, options.iterations, summary.answers, summary.errors,
+             summary.crashes.len());
+
+    if !summary.crashes.is_empty() {
+        exit(1);
+    }
+}
+
+fn print_completions(matches: &Matches) {
+    match cli::completions(matches.value("SHELL").unwrap()) {
+        Ok(script) => print!("{}", script),
+        Err(err) => exit_with_error(&err),
+    }
 }
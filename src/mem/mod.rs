@@ -0,0 +1,126 @@
+// counting global allocator to profile the memory usage of the solvers;
+// counting is disabled until enable() is called, so that the normal
+// runs don't pay for the bookkeeping
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemStats {
+    pub allocations: u64,
+    pub deallocations: u64,
+    pub bytes_allocated: u64,
+    pub peak_live_bytes: u64,
+    live_bytes: i64,  // may become negative if memory of a previous
+                      // measurement (i.e. the input) is freed
+}
+
+// every thread has its own counters, so that solvers running in parallel
+// (i.e. in the server) don't distort each others numbers
+// (const initialization makes sure that accessing them never allocates)
+thread_local! {
+    static STATS: Cell<MemStats> = const {
+        Cell::new(MemStats{
+            allocations: 0,
+            deallocations: 0,
+            bytes_allocated: 0,
+            peak_live_bytes: 0,
+            live_bytes: 0,
+        })
+    };
+}
+
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc(layout);
+        if !ptr.is_null() {
+            record(1, 0, layout.size(), 0);
+        }
+
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr: *mut u8 = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(1, 0, layout.size(), 0);
+        }
+
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, 1, 0, layout.size());
+    }
+
+    // a reallocation counts as an allocation of the new
+    // and a deallocation of the old memory block
+    unsafe fn realloc(
+        &self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr: *mut u8 = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(1, 1, new_size, layout.size());
+        }
+
+        return new_ptr;
+    }
+}
+
+// start counting allocations
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    return ENABLED.load(Ordering::Relaxed);
+}
+
+// run a closure and count all allocations of the current thread during it;
+// returns no statistics if counting isn't enabled
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemStats>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    // try_with fails if the thread is already shutting down;
+    // the counters can be ignored in that case
+    let _ = STATS.try_with(|stats| stats.set(MemStats::default()));
+
+    let result: T = f();
+
+    let stats: Option<MemStats> = STATS.try_with(|stats| stats.get()).ok();
+
+    return (result, stats);
+}
+
+fn record(
+    allocations: u64, deallocations: u64,
+    allocated_bytes: usize, deallocated_bytes: usize) {
+    if !is_enabled() {
+        return;
+    }
+
+    let _ = STATS.try_with(|stats| {
+        let mut s: MemStats = stats.get();
+
+        s.allocations += allocations;
+        s.deallocations += deallocations;
+        s.bytes_allocated += allocated_bytes as u64;
+        s.live_bytes += allocated_bytes as i64 - deallocated_bytes as i64;
+
+        if s.live_bytes > s.peak_live_bytes as i64 {
+            s.peak_live_bytes = s.live_bytes as u64;
+        }
+
+        stats.set(s);
+    });
+}
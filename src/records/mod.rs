@@ -0,0 +1,75 @@
+// plain text files of records with one entry per line ('#' starts a
+// comment), which are only ever appended to; used by the bench history and
+// the record of submitted answers
+
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+// read the entries of a records file with a parser of a single entry; a
+// missing file has no entries, malformed lines are reported with their
+// number and the expected format of an entry (the name of the file is
+// for the error messages, i.e. "bench history")
+pub fn read<T>(path: &str, name: &str, format: &str,
+               parse_entry: fn(&str) -> Option<T>) -> Result<Vec<T>, String> {
+    let file_content: String = match fs::read_to_string(path) {
+        Ok(file_content) => file_content,
+        Err(_) if !Path::new(path).exists() => return Ok(Vec::new()),
+        Err(err) => return Err(format!(
+            "Couldn't read {} '{}': {}", name, path, err)),
+    };
+
+    return parse(&file_content, format, parse_entry).map_err(
+        |err| format!("{}: {}", path, err));
+}
+
+pub fn parse<T>(file_content: &str, format: &str,
+                parse_entry: fn(&str) -> Option<T>) -> Result<Vec<T>, String> {
+    let mut entries: Vec<T> = Vec::new();
+
+    for (i, mut line) in file_content.split("\n").enumerate() {
+        // strip comments and whitespace
+        if let Some(comment_start_idx) = line.find('#') {
+            line = &line[..comment_start_idx];
+        }
+
+        line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_entry(line) {
+            Some(entry) => entries.push(entry),
+            None => return Err(format!(
+                "line {}: expected '{}', got '{}'", i + 1, format, line)),
+        };
+    }
+
+    return Ok(entries);
+}
+
+// append the lines of entries to a records file (creating it and its
+// directory with a header if it doesn't exist yet)
+pub fn append(path: &str, name: &str, header: &str, lines: &[String]) ->
+    Result<(), String> {
+    let error = |err: io::Error| format!(
+        "Couldn't write {} '{}': {}", name, path, err);
+
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(error)?;
+    }
+
+    let is_new: bool = !Path::new(path).exists();
+    let mut file: fs::File = fs::OpenOptions::new()
+        .create(true).append(true).open(path).map_err(error)?;
+
+    let mut content: String = String::new();
+    if is_new {
+        content += header;
+    }
+    content += &lines.concat();
+
+    // a single write, so the lines of parallel runs don't interleave
+    return file.write_all(content.as_bytes()).map_err(error);
+}
@@ -0,0 +1,368 @@
+use std::borrow::Borrow;
+
+use crate::answer::Answer;
+use crate::day1;
+use crate::day2;
+use crate::day3;
+use crate::day4;
+use crate::day5;
+use crate::day6;
+use crate::day7;
+use crate::day7::CardRanks;
+use crate::day7::Hand;
+use crate::day7::Rank;
+use crate::day8;
+use crate::day9;
+use crate::error::SolveError;
+
+// day specific part of a REPL session, holding the parsed input
+pub trait DaySession {
+    // names and descriptions of all day specific commands
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    // solve puzzle 1 or 2 from the parsed input (default strategy)
+    fn solve(&self, puzzle: u8) -> Result<Answer, SolveError>;
+
+    // evaluate a day specific command; returns None for unknown commands
+    fn eval(&mut self, command: &str, args: &[&str]) ->
+        Option<Result<String, String>>;
+}
+
+// parse the input of a day into its session; None for days without
+// a session (i.e. new days of the scaffold)
+pub fn new_session(day: u8, file_content: &str) ->
+    Result<Option<Box<dyn DaySession + '_>>, SolveError> {
+    return Ok(Some(match day {
+        1 => Box::new(PuzzleSession{
+            parsed: day1::parse(file_content),
+            solve1: day1::solve1,
+            solve2: day1::solve2,
+        }),
+        2 => Box::new(PuzzleSession{
+            parsed: day2::parse(file_content)?,
+            solve1: day2::solve1,
+            solve2: day2::solve2,
+        }),
+        3 => Box::new(PuzzleSession{
+            parsed: day3::parse(file_content)?,
+            solve1: day3::solve1,
+            solve2: day3::solve2,
+        }),
+        4 => Box::new(PuzzleSession{
+            parsed: day4::parse(file_content)?,
+            solve1: day4::solve1,
+            solve2: day4::solve2,
+        }),
+        5 => Box::new(Day5Session::new(file_content)?),
+        6 => Box::new(PuzzleSession{
+            parsed: day6::parse(file_content)?,
+            solve1: day6::solve1,
+            solve2: day6::solve2,
+        }),
+        7 => Box::new(Day7Session::new(file_content)?),
+        8 => Box::new(Day8Session::new(file_content)?),
+        9 => Box::new(Day9Session::new(file_content)?),
+        _ => return Ok(None),
+    }));
+}
+
+// session for days without any day specific commands; P is the parsed
+// input and T what the solvers take of it (e.g. a slice of a vector)
+struct PuzzleSession<P, T: ?Sized> {
+    parsed: P,
+    solve1: fn(&T) -> Result<Answer, SolveError>,
+    solve2: fn(&T) -> Result<Answer, SolveError>,
+}
+
+impl<P: Borrow<T>, T: ?Sized> DaySession for PuzzleSession<P, T> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        return &[];
+    }
+
+    fn solve(&self, puzzle: u8) -> Result<Answer, SolveError> {
+        return match puzzle {
+            1 => (self.solve1)(self.parsed.borrow()),
+            _ => (self.solve2)(self.parsed.borrow()),
+        };
+    }
+
+    fn eval(&mut self, _command: &str, _args: &[&str]) ->
+        Option<Result<String, String>> {
+        return None;
+    }
+}
+
+struct Day5Session<'a> {
+    almanac: day5::Almanac<'a>,
+}
+
+impl<'a> Day5Session<'a> {
+    fn new(file_content: &'a str) -> Result<Day5Session<'a>, SolveError> {
+        return Ok(Day5Session{almanac: day5::parse(file_content)?});
+    }
+}
+
+impl DaySession for Day5Session<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        return &[("map SEED", "pass a seed number through every map")];
+    }
+
+    fn solve(&self, puzzle: u8) -> Result<Answer, SolveError> {
+        return match puzzle {
+            1 => day5::solve1(&self.almanac),
+            _ => day5::solve2(&self.almanac),
+        };
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) ->
+        Option<Result<String, String>> {
+        if command != "map" {
+            return None;
+        }
+
+        let mut num: u64 = match parse_single_arg(args) {
+            Ok(num) => num,
+            Err(err) => return Some(Err(err)),
+        };
+
+        // print the mapped number of every category, i.e.
+        // "seed 79 -> soil 81 -> ... -> location 82"
+        let mut steps: Vec<String> =
+            vec![format!("{} {}", day5::CATEGORIES[0], num)];
+
+        for (i, map) in self.almanac.maps.iter().enumerate() {
+            num = match day5::map_number(num, map) {
+                Ok(num) => num,
+                Err(err) => return Some(Err(err.to_string())),
+            };
+            steps.push(format!("{} {}", day5::CATEGORIES[i + 1], num));
+        }
+
+        return Some(Ok(steps.join(" -> ")));
+    }
+}
+
+struct Day7Session<'a> {
+    hands: Vec<Hand<'a>>,
+    ranked_hands_puzzle1: Vec<Hand<'a>>,
+    ranked_hands_puzzle2: Vec<Hand<'a>>,
+}
+
+impl<'a> Day7Session<'a> {
+    fn new(file_content: &'a str) -> Result<Day7Session<'a>, SolveError> {
+        let card_ranks_puzzle1: CardRanks =
+            day7::get_card_ranks_puzzle1();
+        let card_ranks_puzzle2: CardRanks =
+            day7::get_card_ranks_puzzle2();
+
+        return Ok(Day7Session{
+            hands: day7::parse(file_content)?,
+            ranked_hands_puzzle1:
+                day7::rank_hands(file_content, false, &card_ranks_puzzle1)?,
+            ranked_hands_puzzle2:
+                day7::rank_hands(file_content, true, &card_ranks_puzzle2)?,
+        });
+    }
+}
+
+impl DaySession for Day7Session<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        return &[("hand CARDS", "show the rank breakdown of a hand")];
+    }
+
+    fn solve(&self, puzzle: u8) -> Result<Answer, SolveError> {
+        return match puzzle {
+            1 => day7::solve1(&self.hands),
+            _ => day7::solve2(&self.hands),
+        };
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) ->
+        Option<Result<String, String>> {
+        if command != "hand" {
+            return None;
+        }
+
+        if args.len() != 1 || args[0].len() != 5 ||
+           !day7::is_valid_hand(args[0]) {
+            return Some(Err(String::from("Expected a hand of 5 cards")));
+        }
+
+        let cards: &str = args[0];
+        let mut breakdown: Vec<String> = Vec::new();
+
+        // count how often each card appears in the hand
+        let mut card_counts: Vec<(char, u8)> = Vec::new();
+        for card in cards.chars() {
+            match card_counts.iter_mut().find(|(c, _)| *c == card) {
+                Some((_, count)) => *count += 1,
+                None => card_counts.push((card, 1)),
+            }
+        }
+
+        let card_counts_str: Vec<String> = card_counts.iter().map(
+            |(card, count)| format!("{}x{}", count, card)).collect();
+        breakdown.push(format!("cards:   {}", card_counts_str.join(" ")));
+
+        let puzzle_results = [
+            ("puzzle 1", false, &self.ranked_hands_puzzle1),
+            ("puzzle 2", true, &self.ranked_hands_puzzle2),
+        ];
+
+        for (name, with_joker, ranked_hands) in puzzle_results {
+            let rank: Rank = day7::get_rank(cards, with_joker);
+            let mut line: String = format!("{}: {:?}", name, rank);
+
+            // if the hand is part of the input, also show its final rank
+            let position: Option<usize> =
+                ranked_hands.iter().position(|hand| hand.cards() == cards);
+
+            if let Some(i) = position {
+                let hand: &Hand = &ranked_hands[i];
+                line += &format!(" (rank {} of {}, bid {}, winnings {})",
+                                 i + 1, ranked_hands.len(), hand.bid(),
+                                 (i as u32 + 1) * hand.bid());
+            }
+
+            breakdown.push(line);
+        }
+
+        return Some(Ok(breakdown.join("\n")));
+    }
+}
+
+struct Day8Session<'a> {
+    network: day8::Network<'a>,
+    node_names: Vec<&'a str>,  // name of every node by its network index
+}
+
+impl<'a> Day8Session<'a> {
+    fn new(file_content: &'a str) -> Result<Day8Session<'a>, SolveError> {
+        let network: day8::Network = day8::parse(file_content)?;
+
+        let mut node_names: Vec<&str> =
+            vec![""; network.node_locations.len()];
+        for (name, idx) in &network.node_locations {
+            node_names[*idx] = name;
+        }
+
+        return Ok(Day8Session{network: network, node_names: node_names});
+    }
+}
+
+impl DaySession for Day8Session<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        return &[("walk N [NODE]",
+                  "walk N steps through the network (starting at AAA)")];
+    }
+
+    fn solve(&self, puzzle: u8) -> Result<Answer, SolveError> {
+        return match puzzle {
+            1 => day8::solve1(&self.network),
+            _ => day8::solve2(&self.network),
+        };
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) ->
+        Option<Result<String, String>> {
+        if command != "walk" {
+            return None;
+        }
+
+        let (steps, start_node): (&str, &str) = match args {
+            [steps] => (steps, "AAA"),
+            [steps, node] => (steps, *node),
+            _ => return Some(Err(String::from("Expected a number of steps"))),
+        };
+
+        let steps: usize = match parse_single_arg(&[steps]) {
+            Ok(steps) => steps,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let start: Option<&usize> = self.network.node_locations.get(start_node);
+        let mut current_node: usize = match start {
+            Some(node) => *node,
+            None => return Some(Err(format!("Unknown node '{}'", start_node))),
+        };
+
+        let mut walk: Vec<String> =
+            vec![self.node_names[current_node].to_string()];
+
+        for step in 0..steps {
+            let directions: &Vec<u8> = &self.network.directions;
+            let direction: usize =
+                directions[step % directions.len()] as usize;
+
+            current_node = self.network.network[current_node][direction];
+            walk.push(format!("{} {}", ['L', 'R'][direction],
+                              self.node_names[current_node]));
+        }
+
+        return Some(Ok(walk.join(" -> ")));
+    }
+}
+
+struct Day9Session {
+    histories: Vec<Vec<i64>>,
+}
+
+impl Day9Session {
+    fn new(file_content: &str) -> Result<Day9Session, SolveError> {
+        return Ok(Day9Session{histories: day9::parse(file_content)?});
+    }
+}
+
+impl DaySession for Day9Session {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        return &[("extrapolate LINE",
+                  "extrapolate the history of a line in both directions")];
+    }
+
+    fn solve(&self, puzzle: u8) -> Result<Answer, SolveError> {
+        return match puzzle {
+            1 => day9::solve1(&self.histories),
+            _ => day9::solve2(&self.histories),
+        };
+    }
+
+    fn eval(&mut self, command: &str, args: &[&str]) ->
+        Option<Result<String, String>> {
+        if command != "extrapolate" {
+            return None;
+        }
+
+        let line: usize = match parse_single_arg(args) {
+            Ok(line) if line >= 1 && line <= self.histories.len() => line,
+            _ => return Some(Err(format!(
+                "Expected a line number between 1 and {}",
+                self.histories.len()))),
+        };
+
+        let history: &Vec<i64> = &self.histories[line - 1];
+        let extrapolate = |at_beginning: bool| -> Result<i64, String> {
+            return day9::find_next_val_of_history(history.clone(), at_beginning)
+                .map_err(|err| err.to_string());
+        };
+
+        let (previous_val, next_val): (i64, i64) =
+            match (extrapolate(true), extrapolate(false)) {
+                (Ok(previous_val), Ok(next_val)) => (previous_val, next_val),
+                (Err(err), _) | (_, Err(err)) => return Some(Err(err)),
+            };
+
+        let history_str: Vec<String> =
+            history.iter().map(|n| n.to_string()).collect();
+
+        return Some(Ok(format!("({}) {} ({})",
+                               previous_val, history_str.join(" "), next_val)));
+    }
+}
+
+// parse the single argument of a command as a number
+fn parse_single_arg<T: std::str::FromStr>(args: &[&str]) -> Result<T, String> {
+    return match args {
+        [arg] => arg.parse().map_err(|_| format!("'{}' is not a number", arg)),
+        _ => Err(String::from("Expected exactly one number")),
+    };
+}
@@ -0,0 +1,140 @@
+// interactive read-eval-print loop to explore the parsed input of a day;
+// the input is read and parsed once and kept around between commands
+
+mod helpers;
+
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::answer::Answer;
+use crate::error::SolveError;
+use crate::solver;
+
+use helpers::DaySession;
+
+// commands that are available for every day
+const COMMON_COMMANDS: [(&str, &str); 6] = [
+    ("part1", "solve puzzle 1 for the loaded input"),
+    ("part2", "solve puzzle 2 for the loaded input"),
+    ("history", "list all previously entered commands"),
+    ("!N", "repeat command N of the history"),
+    ("help", "show this help"),
+    ("quit", "leave the REPL (same as exit or Ctrl-D)"),
+];
+
+pub fn run(day: u8, input_file_path: &str) -> Result<(), String> {
+    if solver::get_solver(day).is_none() {
+        return Err(format!("No solver for AOC day {}", day));
+    }
+
+    let file_content: String = fs::read_to_string(input_file_path).map_err(
+        |err| format!("Couldn't read input file '{}': {}",
+                      input_file_path, err))?;
+
+    // parse the input once; all day specific commands work on this
+    let session: Result<Result<Option<Box<dyn DaySession + '_>>, _>, String> =
+        solver::catch_panic(|| helpers::new_session(day, &file_content));
+
+    let mut session: Box<dyn DaySession + '_> = match session {
+        Ok(Ok(Some(session))) => session,
+        Ok(Ok(None)) => return Err(format!("No REPL session for AOC day {}",
+                                           day)),
+        Ok(Err(err)) => return Err(err.to_string()),
+        Err(err) => return Err(format!("Couldn't parse input file: {}", err)),
+    };
+
+    println!("Loaded '{}' for AOC day {}; type 'help' for all commands",
+             input_file_path, day);
+
+    let mut history: Vec<String> = Vec::new();
+    let stdin: io::Stdin = io::stdin();
+    let mut stdin_lines = stdin.lock().lines();
+
+    loop {
+        print!("day{}> ", day);
+        io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let mut line: String = match stdin_lines.next() {
+            Some(line) => line.map_err(|err| err.to_string())?,
+            None => {  // end of input (Ctrl-D)
+                println!();
+                break;
+            },
+        };
+
+        line = line.trim().to_string();
+        if line.is_empty() {
+            continue;
+        }
+
+        // replace a history reference with the referenced command
+        if let Some(history_idx) = line.strip_prefix('!') {
+            match history_idx.parse::<usize>() {
+                Ok(i) if i >= 1 && i <= history.len() => {
+                    line = history[i - 1].clone();
+                    println!("{}", line);
+                },
+                _ => {
+                    println!("No command '{}' in the history", history_idx);
+                    continue;
+                },
+            }
+        }
+
+        history.push(line.clone());
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (command, args): (&str, &[&str]) = (words[0], &words[1..]);
+
+        match command {
+            "quit" | "exit" => break,
+            "help" => print_help(session.as_ref()),
+            "history" => {
+                for (i, entry) in history.iter().enumerate() {
+                    println!("{:>4}  {}", i + 1, entry);
+                }
+            },
+            "part1" => solve_puzzle(session.as_ref(), 1),
+            "part2" => solve_puzzle(session.as_ref(), 2),
+            _ => {
+                match solver::catch_panic(|| session.eval(command, args)) {
+                    Ok(Some(Ok(output))) => println!("{}", output),
+                    Ok(Some(Err(err))) => println!("Error: {}", err),
+                    Ok(None) => println!(
+                        "Unknown command '{}'; type 'help' for all commands",
+                        command),
+                    Err(err) => println!("Command failed: {}", err),
+                }
+            },
+        }
+    }
+
+    return Ok(());
+}
+
+// solve a puzzle from the parsed input of the session instead of solving
+// the input from scratch, so the time is without the parse
+fn solve_puzzle(session: &dyn DaySession, puzzle: u8) {
+    let start: Instant = Instant::now();
+    let answer: Result<Result<Answer, SolveError>, String> =
+        solver::catch_panic(|| session.solve(puzzle));
+    let elapsed: Duration = start.elapsed();
+
+    match answer {
+        Ok(Ok(answer)) => println!("{} ({:.3?})", answer, elapsed),
+        Ok(Err(err)) => println!("Solver failed: {}", err),
+        Err(msg) => println!("Solver failed: {}", msg),
+    }
+}
+
+fn print_help(session: &dyn DaySession) {
+    let commands = COMMON_COMMANDS.iter().chain(session.commands().iter());
+
+    for (command, description) in commands {
+        println!("  {:<16} {}", command, description);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::gen::Rng;
+
+// ways in which a valid input gets broken
+#[derive(Debug, Clone, Copy)]
+pub enum Mutation {
+    FlipByte,       // flip a single bit of a random byte
+    Truncate,       // cut off the input at a random byte
+    DeleteLine,     // remove a random line
+    DuplicateLine,  // repeat a random line right after itself
+}
+
+const MUTATIONS: [Mutation; 4] = [
+    Mutation::FlipByte,
+    Mutation::Truncate,
+    Mutation::DeleteLine,
+    Mutation::DuplicateLine,
+];
+
+// apply between one and three random mutations to an input; flipped bytes
+// can produce invalid UTF-8, which is replaced like when reading a file
+pub fn mutate(input: &str, rng: &mut Rng) -> (String, Vec<Mutation>) {
+    let mut bytes: Vec<u8> = input.as_bytes().to_vec();
+    let mut mutations: Vec<Mutation> = Vec::new();
+
+    for _ in 0..rng.range(1, 4) {
+        if bytes.is_empty() {
+            break;
+        }
+
+        let mutation: Mutation = *rng.choose(&MUTATIONS);
+        let pos: usize = rng.range(0, bytes.len() as u64) as usize;
+
+        match mutation {
+            Mutation::FlipByte => bytes[pos] ^= 1 << rng.range(0, 8),
+            Mutation::Truncate => bytes.truncate(pos),
+            Mutation::DeleteLine | Mutation::DuplicateLine => {
+                let (start, end): (usize, usize) = line_bounds(&bytes, pos);
+                let line: Vec<u8> = bytes[start..end].to_vec();
+
+                match mutation {
+                    Mutation::DeleteLine => {
+                        bytes.drain(start..end);
+                    },
+                    _ => {
+                        bytes.splice(end..end, line);
+                    },
+                }
+            },
+        }
+
+        mutations.push(mutation);
+    }
+
+    return (String::from_utf8_lossy(&bytes).to_string(), mutations);
+}
+
+// start and end (including the newline) of the line around a byte
+fn line_bounds(bytes: &[u8], pos: usize) -> (usize, usize) {
+    let start: usize = match bytes[..pos].iter().rposition(|b| *b == b'\n') {
+        Some(newline_idx) => newline_idx + 1,
+        None => 0,
+    };
+
+    let end: usize = match bytes[pos..].iter().position(|b| *b == b'\n') {
+        Some(newline_idx) => pos + newline_idx + 1,
+        None => bytes.len(),
+    };
+
+    return (start, end);
+}
+
+// shrink an input that still fails the check, first by removing whole
+// lines and then by removing ever smaller chunks of characters
+pub fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<&str> = input.split_inclusive('\n').collect();
+
+    let mut i: usize = lines.len();
+    while i > 0 {
+        i -= 1;
+
+        let removed: &str = lines.remove(i);
+        if !fails(&lines.concat()) {
+            lines.insert(i, removed);
+        }
+    }
+
+    let mut chars: Vec<char> = lines.concat().chars().collect();
+    let mut chunk_len: usize = chars.len() / 2;
+
+    while chunk_len > 0 {
+        let mut start: usize = 0;
+
+        while start + chunk_len <= chars.len() {
+            let mut candidate: Vec<char> = chars.clone();
+            candidate.drain(start..start + chunk_len);
+
+            if fails(&candidate.iter().collect::<String>()) {
+                chars = candidate;
+            } else {
+                start += chunk_len;
+            }
+        }
+
+        chunk_len /= 2;
+    }
+
+    return chars.iter().collect();
+}
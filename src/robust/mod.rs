@@ -0,0 +1,145 @@
+// robustness driver: breaks valid generated inputs in random ways and
+// checks that every solver either answers or reports an error, but never
+// panics or hangs; crashing inputs are minimized and saved to disk
+
+mod helpers;
+
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::cache;
+use crate::error::SolveError;
+use crate::gen;
+use crate::gen::Rng;
+use crate::solver;
+use crate::solver::Solution;
+use crate::solver::Solver;
+
+use helpers::Mutation;
+
+pub struct RobustOptions {
+    pub day: u8,              // day of the solvers to check
+    pub iterations: u64,      // number of mutated inputs
+    pub seed: u64,            // seed of the first generated input
+    pub timeout: Duration,    // max. time a solver may take per input
+    pub crash_dir: PathBuf,   // directory to save the crashing inputs to
+}
+
+// a mutated input a solver panicked or hung on
+pub struct Crash {
+    pub puzzle: u8,
+    pub seed: u64,
+    pub mutations: Vec<Mutation>,
+    pub error: SolveError,
+    pub path: PathBuf,        // where the (minimized) input was saved
+}
+
+// number of inputs the solvers answered, rejected or crashed on
+pub struct Summary {
+    pub answers: u64,
+    pub errors: u64,
+    pub crashes: Vec<Crash>,
+}
+
+pub fn run(options: &RobustOptions) -> Result<Summary, String> {
+    let solver: &Solver = solver::get_solver(options.day).ok_or_else(
+        || format!("No solver for AOC day {}", options.day))?;
+
+    // the panics are expected and reported as crashes,
+    // so don't let the default hook print each of them
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let summary: Result<Summary, String> = check_mutations(options, solver);
+
+    panic::set_hook(default_hook);
+
+    return summary;
+}
+
+fn check_mutations(options: &RobustOptions, solver: &Solver) ->
+    Result<Summary, String> {
+    let mut summary: Summary =
+        Summary{answers: 0, errors: 0, crashes: Vec::new()};
+
+    for i in 0..options.iterations {
+        let seed: u64 = options.seed.wrapping_add(i);
+        let input: String = gen::generate(options.day, seed)?;
+
+        let mut rng: Rng = Rng::new(seed);
+        let (mutated_input, mutations): (String, Vec<Mutation>) =
+            helpers::mutate(&input, &mut rng);
+
+        for puzzle in solver.implemented_puzzles() {
+            // the unmutated input is valid, so it has to be answered
+            if let Err(err) = solve(options, puzzle, &input) {
+                return Err(format!(
+                    "Generated input (seed {}) failed puzzle {}: {}",
+                    seed, puzzle, err));
+            }
+
+            let error: SolveError =
+                match solve(options, puzzle, &mutated_input) {
+                    Ok(_) => {
+                        summary.answers += 1;
+                        continue;
+                    },
+                    Err(err @ (SolveError::Panic(_) |
+                               SolveError::Timeout(_))) => err,
+                    Err(_) => {
+                        summary.errors += 1;
+                        continue;
+                    },
+                };
+
+            // hangs aren't minimized, since every attempt takes the timeout
+            let crashing_input: String = match error {
+                SolveError::Panic(_) => helpers::minimize(
+                    &mutated_input,
+                    |input| matches!(solve(options, puzzle, input),
+                                     Err(SolveError::Panic(_)))),
+                _ => mutated_input.clone(),
+            };
+
+            summary.crashes.push(Crash{
+                puzzle: puzzle,
+                seed: seed,
+                mutations: mutations.clone(),
+                error: error,
+                path: save_crash(options, puzzle, &crashing_input)?,
+            });
+        }
+    }
+
+    return Ok(summary);
+}
+
+// solve a puzzle for an input and only keep the answer or error
+fn solve(options: &RobustOptions, puzzle: u8, input: &str) ->
+    Result<Answer, SolveError> {
+    let solution: Solution = solver::solve_with_timeout(
+        options.day, puzzle, None, input.to_string(), Some(options.timeout))
+        .unwrap();
+
+    return solution.answer;
+}
+
+// save a crashing input as dayD-puzzleP-HASH.txt in the crash directory
+fn save_crash(options: &RobustOptions, puzzle: u8, input: &str) ->
+    Result<PathBuf, String> {
+    fs::create_dir_all(&options.crash_dir).map_err(
+        |err| format!("Couldn't create '{}': {}",
+                      options.crash_dir.display(), err))?;
+
+    let path: PathBuf = options.crash_dir.join(format!(
+        "day{}-puzzle{}-{:016x}.txt",
+        options.day, puzzle, cache::hash_input(input)));
+
+    fs::write(&path, input).map_err(
+        |err| format!("Couldn't write '{}': {}", path.display(), err))?;
+
+    return Ok(path);
+}
@@ -0,0 +1,134 @@
+// run the puzzles of several days at once (i.e. for the "all" command)
+
+use std::fs;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::thread;
+
+use crate::answer::Answer;
+use crate::answers::Answers;
+use crate::cache;
+use crate::config::Config;
+use crate::json::Json;
+use crate::solver;
+use crate::solver::Solution;
+
+pub struct Run {
+    pub day: u8,
+    pub puzzle: u8,
+    pub strategy: &'static str,              // name of the solver strategy
+    pub solution: Result<Solution, String>,  // error if input is missing
+    pub expected: Option<String>,            // answer from the answers file
+}
+
+impl Run {
+    // whether the answer matches the expected answer (if there is one)
+    pub fn is_correct(&self) -> Option<bool> {
+        let answer: &Answer = match &self.solution {
+            // an unsolved puzzle is neither correct nor wrong
+            Ok(Solution{answer: Ok(Answer::Unsolved), ..}) => return None,
+            Ok(Solution{answer: Ok(answer), ..}) => answer,
+            _ => return self.expected.as_ref().map(|_| false),
+        };
+
+        return self.expected.as_ref().map(
+            |expected| answer.matches(expected));
+    }
+
+    pub fn to_json(&self) -> Json {
+        let mut pairs: Vec<(String, Json)> = match &self.solution {
+            Ok(solution) => match solution.to_json(self.day, self.puzzle) {
+                Json::Object(pairs) => pairs,
+                _ => Vec::new(),
+            },
+            Err(err) => vec![
+                (String::from("day"), Json::Integer(self.day as i128)),
+                (String::from("part"), Json::Integer(self.puzzle as i128)),
+                (String::from("answer"), Json::Null),
+                (String::from("error"), Json::string(err)),
+            ],
+        };
+
+        pairs.push((String::from("strategy"), Json::string(self.strategy)));
+
+        if let Some(expected) = &self.expected {
+            pairs.push((String::from("expected"),
+                        Json::answer(&Answer::parse(expected))));
+        }
+
+        if let Some(correct) = self.is_correct() {
+            pairs.push((String::from("correct"), Json::Bool(correct)));
+        }
+
+        return Json::Object(pairs);
+    }
+}
+
+// run all implemented puzzles of the provided days with the default or
+// all strategies of their solvers, using as many parallel jobs as
+// configured; runs are returned in order of the days
+pub fn run_days(
+    config: &Config, days: &[u8], answers: Option<&Answers>,
+    all_strategies: bool) -> Vec<Run> {
+    let mut tasks: Vec<(u8, u8, &'static str)> = Vec::new();
+    for day in days {
+        if let Some(solver) = solver::get_solver(*day) {
+            let strategies: Vec<&'static str> = match all_strategies {
+                true => solver.strategy_names(),
+                false => vec![solver.default_strategy().name],
+            };
+
+            for puzzle in solver.implemented_puzzles() {
+                for strategy in &strategies {
+                    // a strategy may not solve every puzzle (yet)
+                    if solver.strategy(Some(strategy)).unwrap()
+                        .puzzle(puzzle).is_some() {
+                        tasks.push((*day, puzzle, *strategy));
+                    }
+                }
+            }
+        }
+    }
+
+    // index of the next task a worker should run
+    let next_task: AtomicUsize = AtomicUsize::new(0);
+    let runs: Mutex<Vec<Option<Run>>> =
+        Mutex::new((0..tasks.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..config.jobs.min(tasks.len()) {
+            scope.spawn(|| loop {
+                let i: usize = next_task.fetch_add(1, Ordering::Relaxed);
+                if i >= tasks.len() {
+                    break;
+                }
+
+                let (day, puzzle, strategy): (u8, u8, &'static str) = tasks[i];
+                let run: Run = Run{
+                    day: day,
+                    puzzle: puzzle,
+                    strategy: strategy,
+                    solution: run_puzzle(config, day, puzzle, strategy),
+                    expected: answers.and_then(|a| a.get(day, puzzle))
+                                     .map(|a| a.to_string()),
+                };
+
+                runs.lock().unwrap()[i] = Some(run);
+            });
+        }
+    });
+
+    return runs.into_inner().unwrap().into_iter().flatten().collect();
+}
+
+fn run_puzzle(config: &Config, day: u8, puzzle: u8, strategy: &str) ->
+    Result<Solution, String> {
+    let input_file_path: String = config.input_path(day);
+
+    let file_content: String = fs::read_to_string(&input_file_path).map_err(
+        |err| format!("Couldn't read input file '{}': {}",
+                      input_file_path, err))?;
+
+    return cache::solve(config, day, puzzle, Some(strategy), file_content);
+}
@@ -0,0 +1,110 @@
+// templates of the files of a new day, where "{day}" is replaced with the
+// number of the day and "{title}" with the title of its puzzle (as a Rust
+// string literal)
+
+pub const MOD_TEMPLATE: &str = r#"mod helpers;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::answer;
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+
+const DAY: u8 = {day};
+
+pub fn puzzle1(file_content: String) -> Result<Answer, SolveError> {
+    return solve1(&parse(&file_content)?);
+}
+
+pub fn puzzle2(file_content: String) -> Result<Answer, SolveError> {
+    return solve2(&parse(&file_content)?);
+}
+
+pub fn both(file_content: String, lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return answer::both(&parse(&file_content)?[..], lap, solve1, solve2);
+}
+
+// parse the input once for both puzzles
+pub fn parse(file_content: &str) -> Result<Vec<&str>, SolveError> {
+    return helpers::parse_lines(file_content);
+}
+
+pub fn solve1(_lines: &[&str]) -> Result<Answer, SolveError> {
+    return Ok(Answer::Unsolved);
+}
+
+pub fn solve2(_lines: &[&str]) -> Result<Answer, SolveError> {
+    return Ok(Answer::Unsolved);
+}
+"#;
+
+pub const HELPERS_TEMPLATE: &str = r#"use alloc::vec::Vec;
+
+use crate::error::SolveError;
+use crate::scan::Scanner;
+
+use super::DAY;
+
+// split the input into its non-empty lines
+pub fn parse_lines(file_content: &str) -> Result<Vec<&str>, SolveError> {
+    let mut lines: Vec<&str> = Vec::new();
+
+    for mut line in Scanner::new(file_content).lines() {
+        if !line.is_empty() {
+            lines.push(line.rest());
+        }
+    }
+
+    if lines.is_empty() {
+        return Err(Scanner::new(file_content).error("a line")
+                   .invalid_input(DAY));
+    }
+
+    return Ok(lines);
+}
+"#;
+
+pub const TEST_TEMPLATE: &str = r#"// solve the example of day {day}
+// through the library; replace the placeholder with the example of the
+// puzzle and the unsolved answers with the answers of the example
+
+use adventofcode23::answer::Answer;
+use adventofcode23::day{day};
+
+const EXAMPLE: &str = "placeholder\n";
+
+#[test]
+fn solves_the_example() {
+    assert_eq!(day{day}::puzzle1(EXAMPLE.to_string()),
+               Ok(Answer::Unsolved));
+    assert_eq!(day{day}::puzzle2(EXAMPLE.to_string()),
+               Ok(Answer::Unsolved));
+}
+"#;
+
+// entry of the day in the SOLVERS table of src/solver/mod.rs; the stubs
+// aren't registered as puzzles until they are solved, so that the catalog
+// and the leaderboard don't count the day as solved
+pub const SOLVER_TEMPLATE: &str = r#"    Solver{
+        day: {day},
+        title: {title},
+        input_format: "unknown",
+        strategies: &[Strategy{
+            name: "default",
+            about: "not solved yet",
+            puzzles: [None, None],
+            complexity: ["unknown", "unknown"],
+        }],
+        both: day{day}::both,
+        sources: [include_str!("../day{day}/mod.rs"),
+                  include_str!("../day{day}/helpers.rs")],
+    },
+"#;
+
+pub fn fill(template: &str, day: u8, title: &str) -> String {
+    return template.replace("{day}", &day.to_string())
+                   .replace("{title}", &format!("{:?}", title));
+}
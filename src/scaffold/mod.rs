@@ -0,0 +1,136 @@
+// generate the files of a new day and register it with the library, the
+// runner and the solver registry, i.e. for day 10:
+//   src/day10/mod.rs      parse and puzzle stubs that return unsolved
+//   src/day10/helpers.rs  parser of the input
+//   tests/day10.rs        test of a placeholder example
+//   inputs/day10.txt      empty puzzle input (for 'fetch' to replace; a
+//                         relative input path is below the root)
+// and "pub mod day10;" in src/lib.rs, "use adventofcode23::day10;" in
+// src/main.rs and its entry in SOLVERS in src/solver/mod.rs; existing
+// files are never overwritten
+
+mod helpers;
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+// a file to write and whether it replaces an existing one
+struct Change {
+    path: PathBuf,
+    content: String,
+    is_new: bool,
+}
+
+// create the files of a day in the crate at a root directory and return
+// the paths of all written files; nothing is written unless every file
+// can be created and every registration be inserted
+pub fn scaffold(root: &Path, day: u8, title: &str, input_path: &Path) ->
+    Result<Vec<PathBuf>, String> {
+    if !root.join("Cargo.toml").is_file() || !root.join("src").is_dir() {
+        return Err(format!("'{}' isn't the root directory of the crate",
+                           root.display()));
+    }
+
+    let fill = |template: &str| helpers::fill(template, day, title);
+    let module_dir: PathBuf = root.join("src").join(format!("day{}", day));
+
+    let mut changes: Vec<Change> = vec![
+        Change{path: module_dir.join("mod.rs"),
+               content: fill(helpers::MOD_TEMPLATE), is_new: true},
+        Change{path: module_dir.join("helpers.rs"),
+               content: fill(helpers::HELPERS_TEMPLATE), is_new: true},
+        Change{path: root.join("tests").join(format!("day{}.rs", day)),
+               content: fill(helpers::TEST_TEMPLATE), is_new: true},
+        Change{path: root.join(input_path),
+               content: String::new(), is_new: true},
+    ];
+
+    for change in &changes {
+        if change.path.exists() {
+            return Err(format!("{} exists already, not overwriting it",
+                               change.path.display()));
+        }
+    }
+
+    let lib_path: PathBuf = root.join("src").join("lib.rs");
+    let lib: String = insert_after_last(
+        &read(&lib_path)?, "pub mod day", &format!("pub mod day{};", day))
+        .map_err(|err| format!("{}: {}", lib_path.display(), err))?;
+
+    let main_path: PathBuf = root.join("src").join("main.rs");
+    let main: String = insert_after_last(
+        &read(&main_path)?, "use adventofcode23::day",
+        &format!("use adventofcode23::day{};", day))
+        .map_err(|err| format!("{}: {}", main_path.display(), err))?;
+
+    let solver_path: PathBuf = root.join("src").join("solver").join("mod.rs");
+    let solver: String = insert_after_last(
+        &read(&solver_path)?, "use crate::day",
+        &format!("use crate::day{};", day)).and_then(
+        |solver| add_solver(&solver, &fill(helpers::SOLVER_TEMPLATE)))
+        .map_err(|err| format!("{}: {}", solver_path.display(), err))?;
+
+    changes.push(Change{path: lib_path, content: lib, is_new: false});
+    changes.push(Change{path: main_path, content: main, is_new: false});
+    changes.push(Change{path: solver_path, content: solver, is_new: false});
+
+    let mut written: Vec<PathBuf> = Vec::new();
+    for change in changes {
+        if change.is_new {
+            if let Some(dir) = change.path.parent() {
+                fs::create_dir_all(dir).map_err(|err| format!(
+                    "Couldn't create {}: {}", dir.display(), err))?;
+            }
+        }
+
+        fs::write(&change.path, &change.content).map_err(|err| format!(
+            "Couldn't write {}: {}", change.path.display(), err))?;
+        written.push(change.path);
+    }
+
+    return Ok(written);
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    return fs::read_to_string(path).map_err(
+        |err| format!("Couldn't read {}: {}", path.display(), err));
+}
+
+// insert a line after the last line that starts with a prefix
+fn insert_after_last(content: &str, prefix: &str, line: &str) ->
+    Result<String, String> {
+    if content.lines().any(|l| l == line) {
+        return Err(format!("'{}' is there already", line));
+    }
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let last_idx: usize = lines.iter().rposition(
+        |l| l.starts_with(prefix)).ok_or_else(
+        || format!("no line starting with '{}' to insert '{}' after",
+                   prefix, line))?;
+
+    lines.insert(last_idx + 1, line);
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+// append an entry to the SOLVERS table and increase its length
+fn add_solver(content: &str, entry: &str) -> Result<String, String> {
+    const TABLE_START: &str = "pub const SOLVERS: [Solver; ";
+    let not_found = || format!("no table '{}N] = [' ... '];'", TABLE_START);
+
+    let start_idx: usize =
+        content.find(TABLE_START).ok_or_else(not_found)? + TABLE_START.len();
+    let len_end_idx: usize =
+        start_idx + content[start_idx..].find(']').ok_or_else(not_found)?;
+    let len: usize =
+        content[start_idx..len_end_idx].parse().map_err(|_| not_found())?;
+    let end_idx: usize =
+        len_end_idx + content[len_end_idx..].find("\n];\n").ok_or_else(
+            not_found)? + 1;
+
+    return Ok(format!("{}{}{}{}{}", &content[..start_idx], len + 1,
+                      &content[len_end_idx..end_idx], entry,
+                      &content[end_idx..]));
+}
@@ -0,0 +1,192 @@
+// cursor over the text of a puzzle input that the parsers of the days
+// consume piece by piece instead of slicing it at found indices; it tracks
+// the byte offset into the whole input, so every parse error can point at
+// the position it happened at
+
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::SolveError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanError {
+    pub offset: usize,     // byte offset of the error in the whole input
+    pub expected: String,  // what the parser expected at the offset
+}
+
+impl ScanError {
+    // the invalid input error of a day for the parse error
+    pub fn invalid_input(&self, day: u8) -> SolveError {
+        return SolveError::invalid_input(day, &self.to_string());
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "Expected {} at byte {}", self.expected, self.offset);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    text: &'a str,  // part of the text that isn't consumed yet
+    offset: usize,  // byte offset of the unconsumed text in the whole input
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(text: &'a str) -> Scanner<'a> {
+        return Scanner{text: text, offset: 0};
+    }
+
+    // every line of the unconsumed text, trimmed like the solvers read
+    // them; empty lines are kept, since some formats depend on them
+    pub fn lines(&self) -> Vec<Scanner<'a>> {
+        let mut lines: Vec<Scanner> = Vec::new();
+        let mut offset: usize = self.offset;
+
+        for line in self.text.split('\n') {
+            let trimmed: &str = line.trim_start();
+            lines.push(Scanner{
+                text: trimmed.trim_end(),
+                offset: offset + line.len() - trimmed.len(),
+            });
+            offset += line.len() + 1;
+        }
+
+        return lines;
+    }
+
+    // byte offset of the next character in the whole input
+    pub fn offset(&self) -> usize {
+        return self.offset;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.text.is_empty();
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        return self.text.chars().next();
+    }
+
+    pub fn starts_with(&self, literal: &str) -> bool {
+        return self.text.starts_with(literal);
+    }
+
+    // parse error at the current position
+    pub fn error(&self, expected: &str) -> ScanError {
+        return ScanError{offset: self.offset, expected: expected.to_string()};
+    }
+
+    // consume the first bytes of the text
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (taken, rest): (&str, &str) = self.text.split_at(len);
+
+        self.text = rest;
+        self.offset += len;
+
+        return taken;
+    }
+
+    // consume a literal the text has to continue with
+    pub fn expect_literal(&mut self, literal: &str) -> Result<(), ScanError> {
+        if !self.starts_with(literal) {
+            return Err(self.error(&format!("'{}'", literal)));
+        }
+
+        self.advance(literal.len());
+
+        return Ok(());
+    }
+
+    // consume an unsigned decimal number
+    pub fn take_uint<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        let len: usize = self.text.bytes().take_while(
+            |byte| byte.is_ascii_digit()).count();
+
+        return self.take_number(len);
+    }
+
+    // consume a decimal number with an optional minus sign
+    pub fn take_int<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        let sign: usize = self.starts_with("-") as usize;
+        let digits: usize = self.text[sign..].bytes().take_while(
+            |byte| byte.is_ascii_digit()).count();
+
+        // a lone minus sign isn't a number
+        return self.take_number(if digits == 0 { 0 } else { sign + digits });
+    }
+
+    fn take_number<T: FromStr>(&mut self, len: usize) -> Result<T, ScanError> {
+        if len == 0 {
+            return Err(self.error("a number"));
+        }
+
+        let num: T = self.text[..len].parse().map_err(
+            |_| self.error("a number that fits into its type"))?;
+        self.advance(len);
+
+        return Ok(num);
+    }
+
+    // consume unsigned numbers separated by any number of spaces
+    // (i.e. numbers that are aligned in columns) until something else
+    // than a number follows
+    pub fn take_uints<T: FromStr>(&mut self) -> Result<Vec<T>, ScanError> {
+        let mut nums: Vec<T> = Vec::new();
+
+        loop {
+            let mut next: Scanner = *self;
+            next.take_while(|c| c == ' ');
+
+            if !next.peek().is_some_and(|c| c.is_ascii_digit()) {
+                return Ok(nums);
+            }
+
+            nums.push(next.take_uint()?);
+            *self = next;
+        }
+    }
+
+    // consume the characters as long as they match the predicate
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let len: usize = self.text.find(|c: char| !predicate(c))
+            .unwrap_or(self.text.len());
+
+        return self.advance(len);
+    }
+
+    // consume one or more items separated by the separator
+    pub fn sep_by<T>(
+        &mut self, separator: &str,
+        mut item: impl FnMut(&mut Scanner<'a>) -> Result<T, ScanError>) ->
+        Result<Vec<T>, ScanError> {
+        let mut items: Vec<T> = vec![item(self)?];
+
+        while self.starts_with(separator) {
+            self.advance(separator.len());
+            items.push(item(self)?);
+        }
+
+        return Ok(items);
+    }
+
+    // consume the rest of the text
+    pub fn rest(&mut self) -> &'a str {
+        return self.advance(self.text.len());
+    }
+
+    // make sure that the whole text was consumed
+    pub fn expect_end(&self) -> Result<(), ScanError> {
+        if !self.is_empty() {
+            return Err(self.error("the end of the line"));
+        }
+
+        return Ok(());
+    }
+}
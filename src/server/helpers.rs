@@ -0,0 +1,101 @@
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+
+// upper limit for request bodies (puzzle inputs are only a few KiB)
+const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,  // JSON encoded response body
+}
+
+// read a single HTTP/1.1 request (request line, headers and body)
+pub fn read_request(stream: &TcpStream) -> io::Result<Request> {
+    let mut reader: BufReader<&TcpStream> = BufReader::new(stream);
+
+    let mut request_line: String = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // request line looks like: "METHOD /path HTTP/1.1"
+    let request_line_components: Vec<&str> =
+        request_line.split_whitespace().collect();
+
+    if request_line_components.len() != 3 ||
+       !request_line_components[2].starts_with("HTTP/1.") {
+        return Err(invalid_data("Malformed request line"));
+    }
+
+    let method: String = request_line_components[0].to_string();
+    let path: String = request_line_components[1].to_string();
+
+    // read all headers; we only care about the length of the body
+    let mut content_length: usize = 0;
+
+    loop {
+        let mut header: String = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Err(invalid_data("Connection closed inside of headers"));
+        }
+
+        let header: &str = header.trim_end();
+        if header.is_empty() {  // empty line marks the end of the headers
+            break;
+        }
+
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse().map_err(
+                    |_| invalid_data("Invalid Content-Length header"))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(invalid_data("Request body too large"));
+    }
+
+    let mut body: Vec<u8> = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    return Ok(Request{method: method, path: path, body: body});
+}
+
+// write a response with a JSON body and close the connection afterwards
+pub fn write_response(
+    mut stream: &TcpStream, response: &Response) -> io::Result<()> {
+    write!(stream,
+           "HTTP/1.1 {} {}\r\n\
+            Content-Type: application/json\r\n\
+            Content-Length: {}\r\n\
+            Connection: close\r\n\r\n{}",
+           response.status, get_reason_phrase(response.status),
+           response.body.len(), response.body)?;
+
+    return stream.flush();
+}
+
+fn get_reason_phrase(status: u16) -> &'static str {
+    return match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    };
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, msg);
+}
@@ -0,0 +1,124 @@
+// local HTTP server that exposes all solvers as a small JSON API:
+//   GET  /days                 -> list of all available solvers
+//   POST /solve/{day}/{part}   -> solve a puzzle for the input in the body
+
+mod helpers;
+
+use std::io;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use crate::json::Json;
+use crate::solver;
+use crate::solver::Solution;
+
+use helpers::Request;
+use helpers::Response;
+
+// time a client gets to send its whole request, so that a client that
+// connects and never sends anything doesn't keep its thread forever
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+// listen for requests on the provided address and port
+// (port 0 lets the operating system pick a free port)
+pub fn serve(host: &str, port: u16, timeout: Option<Duration>) ->
+    io::Result<()> {
+    let listener: TcpListener = TcpListener::bind((host, port))?;
+
+    // print the actual address, so callers using port 0 know where to connect
+    println!("Listening on http://{}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        match stream {
+            // handle every connection in its own thread, so that a slow
+            // solver doesn't block all other requests
+            Ok(stream) => {
+                thread::spawn(move || handle_connection(stream, timeout));
+            },
+            Err(err) => eprintln!("Failed to accept connection: {}", err),
+        }
+    }
+
+    return Ok(());
+}
+
+fn handle_connection(stream: TcpStream, timeout: Option<Duration>) {
+    if let Err(err) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+        eprintln!("Failed to set the read timeout: {}", err);
+        return;
+    }
+
+    let response: Response = match helpers::read_request(&stream) {
+        Ok(request) => route(&request, timeout),
+        // timed out reads are reported as either kind, depending on the OS
+        Err(err) if matches!(err.kind(), io::ErrorKind::WouldBlock |
+                                         io::ErrorKind::TimedOut) =>
+            error_response(408, "Timed out reading the request"),
+        Err(err) => error_response(400, &err.to_string()),
+    };
+
+    if let Err(err) = helpers::write_response(&stream, &response) {
+        eprintln!("Failed to send response: {}", err);
+    }
+}
+
+// dispatch a request to the handler of its path
+fn route(request: &Request, timeout: Option<Duration>) -> Response {
+    let path_components: Vec<&str> =
+        request.path.trim_matches('/').split('/').collect();
+
+    return match path_components.as_slice() {
+        ["days"] => match request.method.as_str() {
+            "GET" => list_days(),
+            _ => error_response(405, "Only GET is allowed for /days"),
+        },
+
+        ["solve", day, part] => match request.method.as_str() {
+            "POST" => solve(day, part, &request.body, timeout),
+            _ => error_response(405, "Only POST is allowed for /solve"),
+        },
+
+        _ => error_response(404, &format!("Unknown path '{}'", request.path)),
+    };
+}
+
+fn list_days() -> Response {
+    let days: Vec<Json> =
+        solver::SOLVERS.iter().map(|solver| solver.to_json()).collect();
+
+    let body: Json = Json::object(vec![("days", Json::Array(days))]);
+
+    return Response{status: 200, body: body.to_string()};
+}
+
+fn solve(
+    day: &str, part: &str, body: &[u8], timeout: Option<Duration>) ->
+    Response {
+    let (day, part): (u8, u8) = match (day.parse(), part.parse()) {
+        (Ok(day), Ok(part)) => (day, part),
+        _ => return error_response(404, "Day and part need to be numbers"),
+    };
+
+    let input: String = match String::from_utf8(body.to_vec()) {
+        Ok(input) => input,
+        Err(_) => return error_response(400, "Input needs to be valid UTF-8"),
+    };
+
+    let solution: Solution =
+        match solver::solve_with_timeout(day, part, None, input, timeout) {
+            Ok(solution) => solution,
+            Err(err) => return error_response(404, &err),
+        };
+
+    let status: u16 = if solution.answer.is_ok() {200} else {422};
+    let body: Json = solution.to_json(day, part);
+
+    return Response{status: status, body: body.to_string()};
+}
+
+fn error_response(status: u16, msg: &str) -> Response {
+    let body: Json = Json::object(vec![("error", Json::string(msg))]);
+    return Response{status: status, body: body.to_string()};
+}
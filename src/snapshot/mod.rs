@@ -0,0 +1,127 @@
+// textual dumps of the intermediate structures the solvers parse the input
+// into; the golden snapshot tests diff these against the files in
+// tests/snapshots, so hash maps and sets are sorted to keep them stable
+
+use crate::day3;
+use crate::day3::PartNumber;
+use crate::day4;
+use crate::day5;
+use crate::day5::Map;
+use crate::day7;
+use crate::day7::CardRanks;
+use crate::day7::Hand;
+use crate::day8;
+use crate::day8::JumpNodes;
+use crate::error::SolveError;
+use crate::fxhash::FxHashMap;
+use crate::fxhash::FxHashSet;
+use crate::scan::Scanner;
+
+// dump the intermediate structures of a day for the provided input
+pub fn dump(day: u8, file_content: &str) -> Result<String, String> {
+    let lines: Vec<String> = match day {
+        3 => dump_day3(file_content),
+        4 => dump_day4(file_content),
+        5 => dump_day5(file_content),
+        7 => dump_day7(file_content),
+        8 => dump_day8(file_content),
+        _ => return Err(format!("No snapshot for AOC day {}", day)),
+    }.map_err(|err| err.to_string())?;
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+// part numbers of every line of the schematic
+fn dump_day3(file_content: &str) -> Result<Vec<String>, SolveError> {
+    let file_lines: Vec<&str> = day3::get_schematic_lines(file_content)?;
+
+    let mut lines: Vec<String> = vec![String::from("# part numbers")];
+
+    for i in 0..file_lines.len() {
+        let part_numbers: Vec<PartNumber> =
+            day3::get_part_numbers(&file_lines, i)?;
+
+        for part_number in part_numbers {
+            lines.push(format!("line {}: {:?}", i, part_number));
+        }
+    }
+
+    return Ok(lines);
+}
+
+// won copies of every card (index 0 is the unused placeholder)
+fn dump_day4(file_content: &str) -> Result<Vec<String>, SolveError> {
+    let card_copies: Vec<FxHashSet<u32>> =
+        day4::collect_card_copies(file_content)?;
+
+    let mut lines: Vec<String> = vec![String::from("# card copies")];
+
+    for (card, won_cards) in card_copies.iter().enumerate() {
+        let mut won_cards: Vec<&u32> = won_cards.iter().collect();
+        won_cards.sort();
+        lines.push(format!("card {}: {:?}", card, won_cards));
+    }
+
+    return Ok(lines);
+}
+
+// destination offsets and source ranges of every map
+fn dump_day5(file_content: &str) -> Result<Vec<String>, SolveError> {
+    let file_lines: Vec<Scanner> = Scanner::new(file_content).lines();
+    let maps: Vec<Map> = day5::get_all_maps(&file_lines)?;
+
+    let mut lines: Vec<String> = vec![String::from("# maps")];
+
+    for (i, map) in maps.iter().enumerate() {
+        lines.push(format!("{}-to-{}: {:?}",
+                           day5::CATEGORIES[i], day5::CATEGORIES[i + 1], map));
+    }
+
+    return Ok(lines);
+}
+
+// hands of both puzzles, sorted from the weakest to the strongest
+fn dump_day7(file_content: &str) -> Result<Vec<String>, SolveError> {
+    let puzzles: [(&str, bool, CardRanks); 2] = [
+        ("puzzle 1", false, day7::get_card_ranks_puzzle1()),
+        ("puzzle 2", true, day7::get_card_ranks_puzzle2()),
+    ];
+
+    let mut lines: Vec<String> = Vec::new();
+
+    for (name, with_joker, card_ranks) in puzzles {
+        let hands: Vec<Hand> =
+            day7::rank_hands(file_content, with_joker, &card_ranks)?;
+
+        lines.push(format!("# ranked hands ({})", name));
+        for (i, hand) in hands.iter().enumerate() {
+            lines.push(format!("rank {}: {:?}", i + 1, hand));
+        }
+    }
+
+    return Ok(lines);
+}
+
+// index of every node and the left/right jumps of every index
+fn dump_day8(file_content: &str) -> Result<Vec<String>, SolveError> {
+    let node_locations: FxHashMap<&str, usize> =
+        day8::map_node_locations(file_content);
+    let network: Vec<JumpNodes> =
+        day8::parse_network(file_content, &node_locations)?;
+
+    let mut node_locations: Vec<(&str, usize)> =
+        node_locations.into_iter().collect();
+    node_locations.sort_by_key(|(_, idx)| *idx);
+
+    let mut lines: Vec<String> = vec![String::from("# node locations")];
+    for (node, idx) in node_locations {
+        lines.push(format!("{}: {}", node, idx));
+    }
+
+    lines.push(String::from("# network"));
+    for (idx, jump_nodes) in network.iter().enumerate() {
+        lines.push(format!("{}: {:?}", idx, jump_nodes));
+    }
+
+    return Ok(lines);
+}
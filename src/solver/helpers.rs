@@ -0,0 +1,52 @@
+use std::any::Any;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::error::SolveError;
+
+use super::BothFn;
+use super::PuzzleFn;
+
+// run a solver and turn a panic (i.e. caused by a malformed input)
+// into an error message instead of tearing down the whole process
+pub fn run_guarded(puzzle_fn: PuzzleFn, input: String) ->
+    Result<Answer, SolveError> {
+    return match catch_panic(move || puzzle_fn(input)) {
+        Ok(answer) => answer,
+        Err(msg) => Err(SolveError::Panic(msg)),
+    };
+}
+
+// run a solver of both puzzles like run_guarded()
+pub fn run_both_guarded(both_fn: BothFn, input: String,
+                        lap: &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError> {
+    return match catch_panic(move || both_fn(input, lap)) {
+        Ok(answers) => answers,
+        Err(msg) => Err(SolveError::Panic(msg)),
+    };
+}
+
+// run a closure and turn a panic into an error message
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    return match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Ok(value),
+        Err(payload) => Err(get_panic_message(payload)),
+    };
+}
+
+// extract the message of a panic payload
+// (panic!/assert!/expect store either a &str or a String)
+fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        return msg.to_string();
+    }
+
+    if let Some(msg) = payload.downcast_ref::<String>() {
+        return msg.clone();
+    }
+
+    return String::from("Solver failed for unknown reason");
+}
@@ -0,0 +1,571 @@
+// registry of all implemented puzzle solvers, so that the different
+// run modes (command line, server, ...) don't each need to know every day
+
+mod helpers;
+
+pub use helpers::catch_panic;
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::answer::Answer;
+use crate::answer::BothAnswers;
+use crate::day1;
+use crate::day2;
+use crate::day3;
+use crate::day4;
+use crate::day5;
+use crate::day6;
+use crate::day7;
+use crate::day8;
+use crate::day9;
+use crate::error::SolveError;
+use crate::json::Json;
+use crate::mem;
+use crate::mem::MemStats;
+
+pub type PuzzleFn = fn(String) -> Result<Answer, SolveError>;
+
+// solves both puzzles from a single parse of the input and calls its
+// second argument after the parse and after puzzle 1 (see answer::both)
+pub type BothFn = fn(String, &mut dyn FnMut()) ->
+    Result<BothAnswers, SolveError>;
+
+// one way of solving the puzzles of a day
+pub struct Strategy {
+    pub name: &'static str,               // name to select it with
+    pub about: &'static str,              // short summary of the approach
+    pub puzzles: [Option<PuzzleFn>; 2],   // solutions to puzzle 1 and 2
+                                          // (None if not implemented yet)
+    pub complexity: [&'static str; 2],    // known runtime complexity
+                                          // of the solutions
+}
+
+pub struct Solver {
+    pub day: u8,                          // Advent of Code day of the solver
+    pub title: &'static str,              // title of the puzzle
+    pub input_format: &'static str,       // short summary of the input
+    pub strategies: &'static [Strategy],  // all ways to solve the puzzles
+                                          // (the first one is the default)
+    pub both: BothFn,                     // both puzzles of the default
+                                          // strategy from a single parse
+    pub sources: [&'static str; 2],       // source code of the solutions
+                                          // (see cache::fingerprint)
+}
+
+impl Solver {
+    pub fn default_strategy(&self) -> &'static Strategy {
+        return &self.strategies[0];
+    }
+
+    // look up a strategy by its name (the default one if None)
+    pub fn strategy(&self, name: Option<&str>) ->
+        Result<&'static Strategy, String> {
+        let Some(name) = name else {
+            return Ok(self.default_strategy());
+        };
+
+        return self.strategies.iter().find(|s| s.name == name).ok_or_else(
+            || format!("Unknown strategy '{}' for AOC day {} \
+                        (expected one of {})", name, self.day,
+                       self.strategy_names().join(", ")));
+    }
+
+    pub fn strategy_names(&self) -> Vec<&'static str> {
+        return self.strategies.iter().map(|strategy| strategy.name).collect();
+    }
+
+    // get the default solution function of puzzle 1 or 2 of the day
+    pub fn puzzle(&self, puzzle: u8) -> Option<PuzzleFn> {
+        return self.default_strategy().puzzle(puzzle);
+    }
+
+    // numbers of all puzzles of the day that are implemented
+    pub fn implemented_puzzles(&self) -> Vec<u8> {
+        return (1..=2).filter(|p| self.puzzle(*p).is_some()).collect();
+    }
+
+    pub fn url(&self) -> String {
+        return format!("https://adventofcode.com/2023/day/{}", self.day);
+    }
+
+    // machine-readable representation of the catalog entry of the solver
+    pub fn to_json(&self) -> Json {
+        let parts: Vec<Json> = self.implemented_puzzles().iter().map(
+            |p| Json::Integer(*p as i128)).collect();
+
+        let complexity: Vec<Json> = self.implemented_puzzles().iter().map(
+            |p| Json::string(self.default_strategy().complexity[
+                *p as usize - 1])).collect();
+
+        let strategies: Vec<Json> = self.strategies.iter().map(
+            |strategy| strategy.to_json()).collect();
+
+        return Json::object(vec![
+            ("day", Json::Integer(self.day as i128)),
+            ("title", Json::string(self.title)),
+            ("url", Json::String(self.url())),
+            ("input_format", Json::string(self.input_format)),
+            ("parts", Json::Array(parts)),
+            ("complexity", Json::Array(complexity)),
+            ("strategies", Json::Array(strategies)),
+        ]);
+    }
+}
+
+impl Strategy {
+    pub fn puzzle(&self, puzzle: u8) -> Option<PuzzleFn> {
+        return match puzzle {
+            1 | 2 => self.puzzles[puzzle as usize - 1],
+            _ => None,
+        };
+    }
+
+    pub fn to_json(&self) -> Json {
+        let complexity: Vec<Json> = self.complexity.iter().map(
+            |complexity| Json::string(complexity)).collect();
+
+        return Json::object(vec![
+            ("name", Json::string(self.name)),
+            ("about", Json::string(self.about)),
+            ("complexity", Json::Array(complexity)),
+        ]);
+    }
+}
+
+// result of a single solver run
+pub struct Solution {
+    pub answer: Result<Answer, SolveError>,  // answer or why it failed
+    pub elapsed: Duration,                   // time it took to solve it
+    pub memory: Option<MemStats>,            // allocations of the solver
+                                             // (only counted with --mem)
+    pub cached: bool,                        // answer is from the cache
+}
+
+impl Solution {
+    // machine-readable representation of the solution
+    pub fn to_json(&self, day: u8, puzzle: u8) -> Json {
+        let (answer, error): (Json, Json) = match &self.answer {
+            Ok(answer) => (Json::answer(answer), Json::Null),
+            Err(err) => (Json::Null, Json::String(err.to_string())),
+        };
+
+        return Json::object(vec![
+            ("day", Json::Integer(day as i128)),
+            ("part", Json::Integer(puzzle as i128)),
+            ("title", title_to_json(day)),
+            ("answer", answer),
+            ("time_ms", Json::Float(self.elapsed.as_secs_f64() * 1000.0)),
+            ("error", error),
+            ("memory", memory_to_json(&self.memory)),
+            ("cached", Json::Bool(self.cached)),
+        ]);
+    }
+}
+
+// result of a run of both puzzles from a single parse of the input
+pub struct BothSolution {
+    pub answers: Result<BothAnswers, SolveError>,  // answer of each puzzle
+                                                   // or why the whole run
+                                                   // failed (i.e. the parse)
+    pub elapsed: Vec<Duration>,    // time of the parse, puzzle 1 and puzzle
+                                   // 2, up to the one that made the whole
+                                   // run fail (none if it timed out)
+    pub memory: Option<MemStats>,  // allocations of the whole run
+                                   // (only counted with --mem)
+}
+
+impl BothSolution {
+    pub const STAGES: [&'static str; 3] = ["parse", "puzzle 1", "puzzle 2"];
+
+    // stage in which the whole run failed (None if it didn't fail or
+    // timed out)
+    pub fn failed_stage(&self) -> Option<&'static str> {
+        if self.answers.is_ok() || self.elapsed.is_empty() {
+            return None;
+        }
+
+        return Some(BothSolution::STAGES[self.elapsed.len() - 1]);
+    }
+
+    // whether the run and both puzzles succeeded
+    pub fn is_ok(&self) -> bool {
+        return match &self.answers {
+            Ok(answers) => answers.iter().all(|answer| answer.is_ok()),
+            Err(_) => false,
+        };
+    }
+
+    // machine-readable representation of the solution
+    pub fn to_json(&self, day: u8) -> Json {
+        // a failed puzzle has no answer but an error, and the other way
+        // around
+        let (answers, errors, error): (Json, Json, Json) = match &self.answers {
+            Ok(answers) => (
+                Json::Array(answers.iter().map(|answer| match answer {
+                    Ok(answer) => Json::answer(answer),
+                    Err(_) => Json::Null,
+                }).collect()),
+                Json::Array(answers.iter().map(|answer| match answer {
+                    Ok(_) => Json::Null,
+                    Err(err) => Json::String(err.to_string()),
+                }).collect()),
+                Json::Null),
+            Err(err) => (Json::Null, Json::Null,
+                         Json::String(err.to_string())),
+        };
+
+        let mut time_ms: Vec<(&str, Json)> = Vec::new();
+        for (i, stage) in ["parse", "puzzle1", "puzzle2"].iter().enumerate() {
+            time_ms.push((stage, match self.elapsed.get(i) {
+                Some(elapsed) => Json::Float(elapsed.as_secs_f64() * 1000.0),
+                None => Json::Null,
+            }));
+        }
+
+        return Json::object(vec![
+            ("day", Json::Integer(day as i128)),
+            ("part", Json::string("both")),
+            ("title", title_to_json(day)),
+            ("answers", answers),
+            ("time_ms", Json::object(time_ms)),
+            ("error", error),
+            ("errors", errors),
+            ("memory", memory_to_json(&self.memory)),
+            ("cached", Json::Bool(false)),
+        ]);
+    }
+}
+
+fn title_to_json(day: u8) -> Json {
+    return match get_solver(day) {
+        Some(solver) => Json::string(solver.title),
+        None => Json::Null,
+    };
+}
+
+fn memory_to_json(memory: &Option<MemStats>) -> Json {
+    return match memory {
+        Some(stats) => Json::object(vec![
+            ("allocations", Json::Integer(stats.allocations as i128)),
+            ("deallocations", Json::Integer(stats.deallocations as i128)),
+            ("bytes_allocated", Json::Integer(stats.bytes_allocated as i128)),
+            ("peak_live_bytes", Json::Integer(stats.peak_live_bytes as i128)),
+        ]),
+        None => Json::Null,
+    };
+}
+
+// catalog of all solvers; in the complexities, n denotes the
+// size of the input and all other variables are explained per day
+pub const SOLVERS: [Solver; 9] = [
+    Solver{
+        day: 1,
+        title: "Trebuchet?!",
+        input_format: "lines of text containing digits and digit words",
+        strategies: &[Strategy{
+            name: "default",
+            about: "scan every line for its first and last digit",
+            puzzles: [Some(day1::puzzle1), Some(day1::puzzle2)],
+            complexity: ["O(n)", "O(n)"],
+        }],
+        both: day1::both,
+        sources: [include_str!("../day1/mod.rs"),
+                  include_str!("../day1/helpers.rs")],
+    },
+    Solver{
+        day: 2,
+        title: "Cube Conundrum",
+        input_format: "'Game ID: ' followed by '; ' separated draws \
+                       of ', ' separated 'COUNT COLOR' pairs",
+        strategies: &[Strategy{
+            name: "default",
+            about: "compare the draws of every game with the cube counts",
+            puzzles: [Some(day2::puzzle1), Some(day2::puzzle2)],
+            complexity: ["O(n)", "O(n)"],
+        }],
+        both: day2::both,
+        sources: [include_str!("../day2/mod.rs"),
+                  include_str!("../day2/helpers.rs")],
+    },
+    Solver{
+        day: 3,
+        title: "Gear Ratios",
+        input_format: "grid of digits, '.' and symbols (engine schematic)",
+        strategies: &[Strategy{
+            name: "default",
+            about: "look for symbols around every number of the schematic",
+            puzzles: [Some(day3::puzzle1), Some(day3::puzzle2)],
+            complexity: ["O(n)", "O(n)"],
+        }],
+        both: day3::both,
+        sources: [include_str!("../day3/mod.rs"),
+                  include_str!("../day3/helpers.rs")],
+    },
+    Solver{
+        day: 4,
+        title: "Scratchcards",
+        input_format: "'Card ID: ' followed by winning numbers, ' | ' \
+                       and drawn numbers",
+        strategies: &[Strategy{
+            name: "default",
+            about: "count the matches of every card and win copies",
+            puzzles: [Some(day4::puzzle1), Some(day4::puzzle2)],
+            complexity: ["O(n)", "O(n + c^2) for c cards"],
+        }],
+        both: day4::both,
+        sources: [include_str!("../day4/mod.rs"),
+                  include_str!("../day4/helpers.rs")],
+    },
+    Solver{
+        day: 5,
+        title: "If You Give A Seed A Fertilizer",
+        input_format: "'seeds: ' line followed by seven 'X-to-Y map:' \
+                       blocks of 'DESTINATION SOURCE LENGTH' lines",
+        strategies: &[
+            Strategy{
+                name: "ranges",
+                about: "map whole seed ranges through the maps",
+                puzzles: [Some(day5::puzzle1), Some(day5::puzzle2)],
+                complexity: ["O(n + s * m^2) for s seeds and m map ranges",
+                             "O(n + r * m^2) for r seed ranges \
+                              and m map ranges"],
+            },
+            Strategy{
+                name: "brute-force",
+                about: "map every single seed through the maps",
+                puzzles: [Some(day5::puzzle1_brute_force),
+                          Some(day5::puzzle2_brute_force)],
+                complexity: ["O(s * m) for s seeds and m map ranges",
+                             "O(S * m) for a total seed range length S \
+                              and m map ranges"],
+            },
+        ],
+        both: day5::both,
+        sources: [include_str!("../day5/mod.rs"),
+                  include_str!("../day5/helpers.rs")],
+    },
+    Solver{
+        day: 6,
+        title: "Wait For It",
+        input_format: "'Time:' and 'Distance:' lines of race numbers",
+        strategies: &[
+            Strategy{
+                name: "binary-search",
+                about: "search the shortest and longest charge times",
+                puzzles: [Some(day6::puzzle1), Some(day6::puzzle2)],
+                complexity: ["O(r * log t) for r races of max. time t",
+                             "O(log t) for a race time t"],
+            },
+            Strategy{
+                name: "closed-form",
+                about: "solve the quadratic equation of the distance",
+                puzzles: [Some(day6::puzzle1_closed_form),
+                          Some(day6::puzzle2_closed_form)],
+                complexity: ["O(r) for r races", "O(1)"],
+            },
+        ],
+        both: day6::both,
+        sources: [include_str!("../day6/mod.rs"),
+                  include_str!("../day6/helpers.rs")],
+    },
+    Solver{
+        day: 7,
+        title: "Camel Cards",
+        input_format: "lines of 'HAND BID' with hands of 5 cards",
+        strategies: &[
+            Strategy{
+                name: "compare",
+                about: "sort the hands by comparing their cards",
+                puzzles: [Some(day7::puzzle1), Some(day7::puzzle2)],
+                complexity: ["O(h * log h) for h hands",
+                             "O(h * log h) for h hands"],
+            },
+            Strategy{
+                name: "packed",
+                about: "sort the hands by keys packed into integers",
+                puzzles: [Some(day7::puzzle1_packed),
+                          Some(day7::puzzle2_packed)],
+                complexity: ["O(h * log h) for h hands",
+                             "O(h * log h) for h hands"],
+            },
+        ],
+        both: day7::both,
+        sources: [include_str!("../day7/mod.rs"),
+                  include_str!("../day7/helpers.rs")],
+    },
+    Solver{
+        day: 8,
+        title: "Haunted Wasteland",
+        input_format: "line of L/R directions followed by \
+                       'NODE = (LEFT, RIGHT)' lines",
+        strategies: &[
+            Strategy{
+                name: "lcm",
+                about: "walk every ghost alone and take the LCM of the steps",
+                puzzles: [Some(day8::puzzle1), Some(day8::puzzle2)],
+                complexity: ["O(n + s) for s steps",
+                             "O(n + a * s) for a start nodes \
+                              and s steps each"],
+            },
+            Strategy{
+                name: "simulation",
+                about: "walk all ghosts at once until they all arrive",
+                puzzles: [Some(day8::puzzle1),
+                          Some(day8::puzzle2_simulation)],
+                complexity: ["O(n + s) for s steps",
+                             "O(n + a * S) for a start nodes \
+                              and S total steps"],
+            },
+        ],
+        both: day8::both,
+        sources: [include_str!("../day8/mod.rs"),
+                  include_str!("../day8/helpers.rs")],
+    },
+    Solver{
+        day: 9,
+        title: "Mirage Maintenance",
+        input_format: "lines of space separated integer histories",
+        strategies: &[Strategy{
+            name: "default",
+            about: "extrapolate every history with its difference pyramid",
+            puzzles: [Some(day9::puzzle1), Some(day9::puzzle2)],
+            complexity: ["O(l * k^2) for l histories of k numbers",
+                         "O(l * k^2) for l histories of k numbers"],
+        }],
+        both: day9::both,
+        sources: [include_str!("../day9/mod.rs"),
+                  include_str!("../day9/helpers.rs")],
+    },
+];
+
+// look up the solver of an Advent of Code day
+pub fn get_solver(day: u8) -> Option<&'static Solver> {
+    return SOLVERS.iter().find(|solver| solver.day == day);
+}
+
+// solve a puzzle of a day for the provided input with the named strategy
+// of the solver (the default one if None); invalid day or puzzle numbers
+// are reported as errors, while a solver that fails on a malformed input
+// is reported in the returned Solution
+pub fn solve_strategy(day: u8, puzzle: u8, strategy: Option<&str>,
+                      input: String) -> Result<Solution, String> {
+    let puzzle_fn: PuzzleFn = get_puzzle_fn(day, puzzle, strategy)?;
+
+    return Ok(run_puzzle_fn(puzzle_fn, input));
+}
+
+// solve a puzzle like solve_strategy(), but give up after the provided
+// timeout (the solver keeps running in the background until the process
+// exits)
+pub fn solve_with_timeout(
+    day: u8, puzzle: u8, strategy: Option<&str>, input: String,
+    timeout: Option<Duration>) -> Result<Solution, String> {
+    // invalid arguments are reported before the solver thread is started
+    let puzzle_fn: PuzzleFn = get_puzzle_fn(day, puzzle, strategy)?;
+
+    let Some(timeout) = timeout else {
+        return Ok(run_puzzle_fn(puzzle_fn, input));
+    };
+
+    return Ok(run_with_timeout(timeout, move || run_puzzle_fn(
+        puzzle_fn, input)).unwrap_or(Solution{
+            answer: Err(SolveError::Timeout(timeout)),
+            elapsed: timeout,
+            memory: None,
+            cached: false,
+        }));
+}
+
+// solve both puzzles of a day with the default strategy from a single
+// parse of the input, giving up after the provided timeout like
+// solve_with_timeout()
+pub fn solve_both(day: u8, input: String, timeout: Option<Duration>) ->
+    Result<BothSolution, String> {
+    let both_fn: BothFn = match get_solver(day) {
+        Some(solver) => solver.both,
+        None => return Err(String::from("Invalid AOC day number")),
+    };
+
+    let Some(timeout) = timeout else {
+        return Ok(run_both_fn(both_fn, input));
+    };
+
+    return Ok(run_with_timeout(timeout, move || run_both_fn(
+        both_fn, input)).unwrap_or(BothSolution{
+            answers: Err(SolveError::Timeout(timeout)),
+            elapsed: Vec::new(),
+            memory: None,
+        }));
+}
+
+// check that the strategies of a puzzle found the same answer, given as
+// pairs of strategy name and answer
+pub fn compare_answers(answers: &[(&str, &Answer)]) -> Result<(), String> {
+    if answers.iter().all(|(_, answer)| *answer == answers[0].1) {
+        return Ok(());
+    }
+
+    let answers: Vec<String> = answers.iter().map(
+        |(strategy, answer)| format!("{}: {}", strategy, answer)).collect();
+
+    return Err(format!("strategies disagree ({})", answers.join(", ")));
+}
+
+// look up the solution function of a puzzle of a day
+fn get_puzzle_fn(day: u8, puzzle: u8, strategy: Option<&str>) ->
+    Result<PuzzleFn, String> {
+    let solver: &Solver = match get_solver(day) {
+        Some(solver) => solver,
+        None => return Err(String::from("Invalid AOC day number")),
+    };
+
+    return match solver.strategy(strategy)?.puzzle(puzzle) {
+        Some(puzzle_fn) => Ok(puzzle_fn),
+        None => Err(String::from("Invalid puzzle number")),
+    };
+}
+
+fn run_puzzle_fn(puzzle_fn: PuzzleFn, input: String) -> Solution {
+    let start: Instant = Instant::now();
+    let (answer, memory): (Result<Answer, SolveError>, Option<MemStats>) =
+        mem::measure(|| helpers::run_guarded(puzzle_fn, input));
+    let elapsed: Duration = start.elapsed();
+
+    return Solution{answer: answer, elapsed: elapsed, memory: memory,
+                    cached: false};
+}
+
+// time the parse and both puzzles by the laps of the solver; the last
+// stage that was started ends with the run
+fn run_both_fn(both_fn: BothFn, input: String) -> BothSolution {
+    let mut laps: Vec<Instant> = vec![Instant::now()];
+    let (answers, memory):
+        (Result<BothAnswers, SolveError>, Option<MemStats>) =
+        mem::measure(|| helpers::run_both_guarded(
+            both_fn, input, &mut || laps.push(Instant::now())));
+    laps.push(Instant::now());
+
+    let elapsed: Vec<Duration> =
+        laps.windows(2).map(|lap| lap[1] - lap[0]).collect();
+
+    return BothSolution{answers: answers, elapsed: elapsed, memory: memory};
+}
+
+// run a solver in a thread of its own and return None if it doesn't finish
+// in time (the thread keeps running in the background until the process
+// exits)
+fn run_with_timeout<T: Send + 'static>(
+    timeout: Duration, run: impl FnOnce() -> T + Send + 'static) ->
+    Option<T> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        // receiver is gone if the solver took too long
+        let _ = sender.send(run());
+    });
+
+    return receiver.recv_timeout(timeout).ok();
+}
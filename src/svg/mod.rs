@@ -0,0 +1,129 @@
+// minimal writer of SVG images: shapes and text are appended in drawing
+// order (later elements are drawn on top) and rendered as a single string
+
+use std::fmt::Write;
+
+pub struct Svg {
+    width: f64,
+    height: f64,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Svg {
+        return Svg{width: width, height: height, elements: Vec::new()};
+    }
+
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64,
+                fill: &str) {
+        self.elements.push(format!(
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" \
+             fill=\"{}\"/>",
+            x, y, width, height, escape(fill)));
+    }
+
+    pub fn circle(&mut self, cx: f64, cy: f64, r: f64, fill: &str) {
+        self.elements.push(format!(
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"{}\"/>",
+            cx, cy, r, escape(fill)));
+    }
+
+    pub fn line(&mut self, from: (f64, f64), to: (f64, f64),
+                stroke: &str, stroke_width: f64) {
+        self.elements.push(format!(
+            "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" \
+             stroke=\"{}\" stroke-width=\"{:.1}\"/>",
+            from.0, from.1, to.0, to.1, escape(stroke), stroke_width));
+    }
+
+    // line with an arrow head at its end, drawn in the color of the line
+    pub fn arrow(&mut self, from: (f64, f64), to: (f64, f64),
+                 stroke: &str, stroke_width: f64) {
+        const HEAD_LEN: f64 = 8.0;
+
+        self.line(from, to, stroke, stroke_width);
+
+        let (dx, dy): (f64, f64) = (to.0 - from.0, to.1 - from.1);
+        let len: f64 = (dx * dx + dy * dy).sqrt();
+        if len == 0.0 {
+            return;
+        }
+
+        // unit vector along the line and the one perpendicular to it
+        let (ux, uy): (f64, f64) = (dx / len, dy / len);
+        let (px, py): (f64, f64) = (-uy, ux);
+
+        let base: (f64, f64) = (to.0 - ux * HEAD_LEN, to.1 - uy * HEAD_LEN);
+        self.polygon(&[
+            to,
+            (base.0 + px * HEAD_LEN / 2.0, base.1 + py * HEAD_LEN / 2.0),
+            (base.0 - px * HEAD_LEN / 2.0, base.1 - py * HEAD_LEN / 2.0),
+        ], stroke, 1.0);
+    }
+
+    pub fn polygon(&mut self, points: &[(f64, f64)], fill: &str,
+                   opacity: f64) {
+        let mut points_str: String = String::new();
+        for (x, y) in points {
+            write!(points_str, "{:.1},{:.1} ", x, y).unwrap();
+        }
+
+        self.elements.push(format!(
+            "<polygon points=\"{}\" fill=\"{}\" fill-opacity=\"{:.2}\"/>",
+            points_str.trim_end(), escape(fill), opacity));
+    }
+
+    // text centered at the provided position
+    pub fn text(&mut self, x: f64, y: f64, size: f64, fill: &str,
+                content: &str) {
+        self.elements.push(format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.1}\" fill=\"{}\" \
+             text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>",
+            x, y, size, escape(fill), escape(content)));
+    }
+
+    // text starting at the provided position
+    pub fn label(&mut self, x: f64, y: f64, size: f64, fill: &str,
+                 content: &str) {
+        self.elements.push(format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.1}\" fill=\"{}\" \
+             dominant-baseline=\"central\">{}</text>",
+            x, y, size, escape(fill), escape(content)));
+    }
+
+    pub fn render(&self) -> String {
+        let mut svg: String = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" \
+             height=\"{:.0}\" viewBox=\"0 0 {:.0} {:.0}\" \
+             font-family=\"monospace\">\n",
+            self.width, self.height, self.width, self.height);
+
+        for element in &self.elements {
+            svg += "  ";
+            svg += element;
+            svg += "\n";
+        }
+
+        svg += "</svg>\n";
+
+        return svg;
+    }
+}
+
+// escape the characters with a special meaning in XML
+fn escape(text: &str) -> String {
+    let mut escaped: String = String::with_capacity(text.len());
+
+    for chr in text.chars() {
+        match chr {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&apos;",
+            _ => escaped.push(chr),
+        }
+    }
+
+    return escaped;
+}
@@ -0,0 +1,72 @@
+use std::env;
+use std::io;
+use std::io::IsTerminal;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Dim,
+    Bold,
+}
+
+impl Color {
+    fn code(&self) -> &'static str {
+        return match self {
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Dim => "\x1b[2m",
+            Color::Bold => "\x1b[1m",
+        };
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+// wraps text into ANSI escape codes, or leaves it as it is if the output
+// isn't colored
+pub struct Painter {
+    color: bool,
+}
+
+impl Painter {
+    pub fn new(color: bool) -> Painter {
+        return Painter{color: color};
+    }
+
+    pub fn paint(&self, text: &str, color: Color) -> String {
+        if !self.color {
+            return text.to_string();
+        }
+
+        return format!("{}{}{}", color.code(), text, RESET);
+    }
+
+    pub fn is_colored(&self) -> bool {
+        return self.color;
+    }
+}
+
+// whether to color the output for a --color choice: "auto" colors it only
+// when stdout is a terminal and NO_COLOR isn't set
+pub fn use_color(when: &str) -> bool {
+    return match when {
+        "always" => true,
+        "never" => false,
+        _ => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+    };
+}
+
+// pad a text to a width (right-aligned for numbers, left-aligned otherwise)
+// before painting it, since escape codes would count towards the width
+pub fn pad(text: &str, width: usize, right: bool) -> String {
+    if right {
+        return format!("{:>width$}", text, width = width);
+    }
+
+    return format!("{:<width$}", text, width = width);
+}
@@ -0,0 +1,284 @@
+// colored terminal output of the parsed puzzle inputs, for everyone who
+// doesn't want to open the SVG pictures of the viz module
+
+mod helpers;
+
+pub use helpers::use_color;
+
+use std::collections::HashMap;
+
+use crate::day3;
+use crate::day7;
+use crate::day7::CardRanks;
+use crate::day7::Hand;
+use crate::day7::Rank;
+use crate::day9;
+use crate::scan::Scanner;
+use crate::viz;
+use crate::viz::Cell;
+
+use helpers::Color;
+use helpers::Painter;
+use helpers::pad;
+
+pub trait Render {
+    fn render(&self, painter: &Painter) -> String;
+}
+
+// render an input of a day for the terminal, colored or not
+pub fn render(day: u8, file_content: &str, color: bool) ->
+    Result<String, String> {
+    let output: Box<dyn Render> = match day {
+        3 => Box::new(Schematic::parse(file_content)?),
+        7 => Box::new(Hands::parse(file_content)?),
+        9 => Box::new(Histories::parse(file_content)?),
+        _ => return Err(format!("No terminal output for AOC day {}", day)),
+    };
+
+    return Ok(output.render(&Painter::new(color)));
+}
+
+// day 3: the schematic with counted part numbers in green, ignored
+// numbers in red and gears in yellow
+struct Schematic<'a> {
+    rows: Vec<&'a str>,
+    cells: HashMap<(usize, usize), Cell>,
+}
+
+impl<'a> Schematic<'a> {
+    fn parse(file_content: &'a str) -> Result<Schematic<'a>, String> {
+        let rows: Vec<&str> = day3::get_schematic_lines(file_content)
+            .map_err(|err| err.to_string())?;
+        let cells: HashMap<(usize, usize), Cell> =
+            viz::schematic_cells(&rows).map_err(|err| err.to_string())?;
+
+        return Ok(Schematic{rows: rows, cells: cells});
+    }
+
+    fn color(&self, row: usize, column: usize, symbol: char) -> Color {
+        return match self.cells.get(&(row, column)) {
+            Some(Cell::PartNumber) => Color::Green,
+            Some(Cell::Ignored) => Color::Red,
+            Some(Cell::Gear) => Color::Yellow,
+            None if symbol == '.' => Color::Dim,
+            None => Color::Blue,
+        };
+    }
+}
+
+impl Render for Schematic<'_> {
+    fn render(&self, painter: &Painter) -> String {
+        let mut output: String = String::new();
+
+        for (i, row) in self.rows.iter().enumerate() {
+            // paint runs of equally colored cells at once
+            let mut run: String = String::new();
+            let mut run_color: Option<Color> = None;
+
+            for (j, symbol) in row.chars().enumerate() {
+                let color: Color = self.color(i, j, symbol);
+
+                if run_color.is_some_and(|run_color| run_color != color) {
+                    output += &painter.paint(&run, run_color.unwrap());
+                    run.clear();
+                }
+
+                run.push(symbol);
+                run_color = Some(color);
+            }
+
+            if let Some(color) = run_color {
+                output += &painter.paint(&run, color);
+            }
+            output += "\n";
+        }
+
+        if painter.is_colored() {
+            output += &format!("\n{}  {}  {}\n",
+                               painter.paint("part number", Color::Green),
+                               painter.paint("ignored number", Color::Red),
+                               painter.paint("gear", Color::Yellow));
+        }
+
+        return output;
+    }
+}
+
+// day 7: a table of the ranked hands of both puzzles
+struct Hands<'a> {
+    puzzles: Vec<(&'static str, Vec<Hand<'a>>)>,
+}
+
+impl<'a> Hands<'a> {
+    fn parse(file_content: &'a str) -> Result<Hands<'a>, String> {
+        let puzzles: [(&str, bool, CardRanks); 2] = [
+            ("puzzle 1", false, day7::get_card_ranks_puzzle1()),
+            ("puzzle 2 (J is a joker)", true, day7::get_card_ranks_puzzle2()),
+        ];
+
+        let mut ranked_hands: Vec<(&str, Vec<Hand>)> = Vec::new();
+        for (name, with_joker, card_ranks) in puzzles {
+            ranked_hands.push((name, day7::rank_hands(
+                file_content, with_joker, &card_ranks)
+                .map_err(|err| err.to_string())?));
+        }
+
+        return Ok(Hands{puzzles: ranked_hands});
+    }
+}
+
+fn rank_name(rank: Rank) -> &'static str {
+    return match rank {
+        Rank::FiveOfKind => "five of a kind",
+        Rank::FourOfKind => "four of a kind",
+        Rank::FullHouse => "full house",
+        Rank::ThreeOfKind => "three of a kind",
+        Rank::TwoPair => "two pair",
+        Rank::SinglePair => "one pair",
+        Rank::HighCard => "high card",
+    };
+}
+
+impl Render for Hands<'_> {
+    fn render(&self, painter: &Painter) -> String {
+        const HEADER: [&str; 5] = ["rank", "hand", "type", "bid", "winnings"];
+
+        let mut output: String = String::new();
+
+        for (n, (name, hands)) in self.puzzles.iter().enumerate() {
+            let with_joker: bool = n == 1;
+
+            // cells of every hand, the winnings with 128 bits since
+            // neither the rank nor the bid are bounded
+            let rows: Vec<[String; 5]> = hands.iter().enumerate().map(
+                |(i, hand)| [
+                    (i + 1).to_string(),
+                    hand.cards().to_string(),
+                    rank_name(hand.rank()).to_string(),
+                    hand.bid().to_string(),
+                    ((i + 1) as u128 * hand.bid() as u128).to_string(),
+                ]).collect();
+            let total: u128 = hands.iter().enumerate().map(
+                |(i, hand)| (i + 1) as u128 * hand.bid() as u128).sum();
+
+            let mut widths: [usize; 5] = HEADER.map(|title| title.len());
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.len());
+                }
+            }
+
+            if n > 0 {
+                output += "\n";
+            }
+            output += &painter.paint(&format!("Ranked hands of {}", name),
+                                     Color::Bold);
+            output += "\n";
+
+            let header: Vec<String> = HEADER.iter().enumerate().map(
+                |(i, title)| pad(title, widths[i], i != 1 && i != 2))
+                .collect();
+            output += &painter.paint(header.join("  ").trim_end(), Color::Dim);
+            output += "\n";
+
+            for row in &rows {
+                let mut cells: Vec<String> = Vec::new();
+
+                for (i, cell) in row.iter().enumerate() {
+                    let padded: String = pad(cell, widths[i], i != 1 && i != 2);
+
+                    cells.push(match i {
+                        // jokers stand out in the hands of puzzle 2
+                        1 if with_joker => padded.chars().map(
+                            |card| match card {
+                                'J' => painter.paint("J", Color::Yellow),
+                                _ => card.to_string(),
+                            }).collect(),
+                        2 => painter.paint(&padded, Color::Blue),
+                        4 => painter.paint(&padded, Color::Green),
+                        _ => padded,
+                    });
+                }
+
+                output += cells.join("  ").trim_end();
+                output += "\n";
+            }
+
+            output += &format!("{} {}\n",
+                               painter.paint("total winnings:", Color::Bold),
+                               painter.paint(&total.to_string(), Color::Green));
+        }
+
+        return output;
+    }
+}
+
+// day 9: the difference pyramid of every history with the extrapolated
+// values in red at both ends
+struct Histories {
+    pyramids: Vec<Vec<(i128, Vec<i128>, i128)>>,
+}
+
+impl Histories {
+    fn parse(file_content: &str) -> Result<Histories, String> {
+        let mut pyramids: Vec<Vec<(i128, Vec<i128>, i128)>> = Vec::new();
+
+        for mut line in Scanner::new(file_content).lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let history: Vec<i64> = day9::parse_nums_from_line(&mut line)
+                .map_err(|err| err.to_string())?;
+
+            pyramids.push(viz::difference_pyramid(&history).ok_or(
+                "A difference of the history doesn't fit into 128 bits")?);
+        }
+
+        return Ok(Histories{pyramids: pyramids});
+    }
+}
+
+impl Render for Histories {
+    fn render(&self, painter: &Painter) -> String {
+        // every column fits the longest number plus a space; the width is
+        // even, so every row can be shifted by half a column
+        let mut width: usize = 1;
+        for pyramid in &self.pyramids {
+            for (prev, row, next) in pyramid {
+                for num in row.iter().chain([prev, next]) {
+                    width = width.max(num.to_string().len());
+                }
+            }
+        }
+        width += 1 + (width + 1) % 2;
+
+        let mut output: String = String::new();
+
+        for (n, pyramid) in self.pyramids.iter().enumerate() {
+            if n > 0 {
+                output += "\n";
+            }
+
+            for (depth, (prev, row, next)) in pyramid.iter().enumerate() {
+                output += &" ".repeat(depth * width / 2);
+                output += &painter.paint(&pad(&prev.to_string(), width, true),
+                                         Color::Red);
+
+                for num in row {
+                    let cell: String = pad(&num.to_string(), width, true);
+                    output += &match num {
+                        0 => painter.paint(&cell, Color::Dim),
+                        _ => cell,
+                    };
+                }
+
+                output += &painter.paint(&pad(&next.to_string(), width, true),
+                                         Color::Red);
+                output += "\n";
+            }
+        }
+
+        return output;
+    }
+}
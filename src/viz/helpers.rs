@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use crate::day3;
+use crate::day3::PartNumber;
+use crate::error::SolveError;
+
+pub const BACKGROUND: &str = "#ffffff";
+pub const TEXT: &str = "#222222";
+pub const MUTED: &str = "#aaaaaa";
+pub const HIGHLIGHT: &str = "#2e9e44";  // part numbers, paths, seed ranges
+pub const ALERT: &str = "#d62728";      // gears, end nodes, extrapolations
+pub const SYMBOL: &str = "#1f77b4";
+
+// colors of consecutive elements (i.e. the ranges of a map)
+pub const PALETTE: [&str; 6] =
+    ["#1f77b4", "#ff7f0e", "#9467bd", "#8c564b", "#e377c2", "#17becf"];
+
+// highlighted cells of a schematic
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell {
+    PartNumber,  // digit of a number next to a symbol
+    Ignored,     // digit of a number without an adjacent symbol
+    Gear,        // '*' next to exactly two part numbers
+}
+
+// kind of every digit and gear of a schematic (all other cells are plain
+// symbols or dots)
+pub fn schematic_cells(rows: &Vec<&str>) ->
+    Result<HashMap<(usize, usize), Cell>, SolveError> {
+    let mut cells: HashMap<(usize, usize), Cell> = HashMap::new();
+
+    // number of part numbers next to every '*'
+    let mut gear_parts: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for i in 0..rows.len() {
+        let part_numbers: Vec<PartNumber> = day3::get_part_numbers(rows, i)?;
+
+        for part_number in part_numbers {
+            let cell: Cell = if part_number.has_adjacent_symbol(rows) {
+                Cell::PartNumber
+            } else {
+                Cell::Ignored
+            };
+
+            for field in &part_number.adjacent_fields {
+                let pos: (usize, usize) = (field.line(), field.column());
+                let symbol: u8 = rows[pos.0].as_bytes()[pos.1];
+
+                // the digits of a number are part of its adjacent fields
+                if pos.0 == i && symbol.is_ascii_digit() {
+                    cells.insert(pos, cell);
+                } else if symbol == b'*' {
+                    *gear_parts.entry(pos).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+
+    for (pos, parts) in gear_parts {
+        if parts == 2 {
+            cells.insert(pos, Cell::Gear);
+        }
+    }
+
+    return Ok(cells);
+}
+
+// destination of a source range of a map, clamped to the numbers an u64
+// can hold (the solver rejects maps that shift numbers outside of them)
+pub fn destination(source: &Range<u64>, offset: i128) -> Range<u64> {
+    let shift = |num: u64| -> u64 {
+        return (num as i128 + offset).clamp(0, u64::MAX as i128) as u64;
+    };
+
+    return shift(source.start)..shift(source.end);
+}
+
+// rows of differences of a history down to the row of zeros together
+// with the extrapolated previous and next value of every row;
+// None if a difference overflows
+pub fn difference_pyramid(history: &[i64]) ->
+    Option<Vec<(i128, Vec<i128>, i128)>> {
+    let mut rows: Vec<Vec<i128>> =
+        vec![history.iter().map(|num| *num as i128).collect()];
+
+    while rows.last()?.len() > 1 &&
+          rows.last()?.iter().any(|num| *num != 0) {
+        let row: &Vec<i128> = rows.last()?;
+
+        let mut differences: Vec<i128> = Vec::new();
+        for pair in row.windows(2) {
+            differences.push(pair[1].checked_sub(pair[0])?);
+        }
+
+        rows.push(differences);
+    }
+
+    // extrapolate from the bottom row up
+    let mut pyramid: Vec<(i128, Vec<i128>, i128)> = Vec::new();
+    let (mut prev, mut next): (i128, i128) = (0, 0);
+
+    for row in rows.into_iter().rev() {
+        if let (Some(first), Some(last)) = (row.first(), row.last()) {
+            prev = first.checked_sub(prev)?;
+            next = last.checked_add(next)?;
+        }
+
+        pyramid.push((prev, row, next));
+    }
+
+    pyramid.reverse();
+
+    return Some(pyramid);
+}
+
+// positions of n points evenly spread on a circle
+pub fn circle_layout(n: usize, center: (f64, f64), radius: f64) ->
+    Vec<(f64, f64)> {
+    return (0..n).map(|i| {
+        let angle: f64 = 2.0 * PI * i as f64 / n as f64 - PI / 2.0;
+        return (center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin());
+    }).collect();
+}
+
+// shorten a line at both ends (i.e. to stop at the border of a node)
+pub fn shorten(from: (f64, f64), to: (f64, f64), by: f64) ->
+    ((f64, f64), (f64, f64)) {
+    let (dx, dy): (f64, f64) = (to.0 - from.0, to.1 - from.1);
+    let len: f64 = (dx * dx + dy * dy).sqrt();
+
+    if len <= 2.0 * by {
+        return (from, to);
+    }
+
+    let (ux, uy): (f64, f64) = (dx / len * by, dy / len * by);
+
+    return ((from.0 + ux, from.1 + uy), (to.0 - ux, to.1 - uy));
+}
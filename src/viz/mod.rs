@@ -0,0 +1,420 @@
+// pictures of the parsed puzzle inputs for debugging: every day that can
+// be visualized parses its input into a type implementing Visualize
+
+mod helpers;
+
+pub use helpers::Cell;
+pub use helpers::difference_pyramid;
+pub use helpers::schematic_cells;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::day3;
+use crate::day5;
+use crate::day5::Map;
+use crate::day8;
+use crate::day8::JumpNodes;
+use crate::day9;
+use crate::fxhash::FxHashMap;
+use crate::scan::Scanner;
+use crate::svg::Svg;
+
+use helpers::ALERT;
+use helpers::BACKGROUND;
+use helpers::HIGHLIGHT;
+use helpers::MUTED;
+use helpers::PALETTE;
+use helpers::SYMBOL;
+use helpers::TEXT;
+
+const MARGIN: f64 = 20.0;
+const FONT_SIZE: f64 = 11.0;
+
+pub trait Visualize {
+    fn visualize(&self) -> Svg;
+}
+
+// render the picture of an input of a day as SVG
+pub fn render(day: u8, file_content: &str) -> Result<String, String> {
+    let picture: Box<dyn Visualize> = match day {
+        3 => Box::new(Schematic::parse(file_content)?),
+        5 => Box::new(Almanac::parse(file_content)?),
+        8 => Box::new(Network::parse(file_content)?),
+        9 => Box::new(Histories::parse(file_content)?),
+        _ => return Err(format!("No visualization for AOC day {}", day)),
+    };
+
+    return Ok(picture.visualize().render());
+}
+
+// day 3: the schematic with the part numbers and gears highlighted
+struct Schematic<'a> {
+    rows: Vec<&'a str>,
+    cells: HashMap<(usize, usize), Cell>,
+}
+
+impl<'a> Schematic<'a> {
+    fn parse(file_content: &'a str) -> Result<Schematic<'a>, String> {
+        let rows: Vec<&str> = day3::get_schematic_lines(file_content)
+            .map_err(|err| err.to_string())?;
+        let cells: HashMap<(usize, usize), Cell> =
+            schematic_cells(&rows).map_err(|err| err.to_string())?;
+
+        return Ok(Schematic{rows: rows, cells: cells});
+    }
+}
+
+impl Visualize for Schematic<'_> {
+    fn visualize(&self) -> Svg {
+        const CELL: f64 = 16.0;
+
+        let columns: usize = self.rows.first().map_or(0, |row| row.len());
+        let width: f64 = columns as f64 * CELL + 2.0 * MARGIN;
+        let height: f64 = self.rows.len() as f64 * CELL + 3.0 * MARGIN;
+
+        let mut svg: Svg = Svg::new(width.max(300.0), height);
+        svg.rect(0.0, 0.0, width.max(300.0), height, BACKGROUND);
+
+        let cell_pos = |row: usize, column: usize| -> (f64, f64) {
+            return (MARGIN + column as f64 * CELL, MARGIN + row as f64 * CELL);
+        };
+
+        let fills: HashMap<(usize, usize), &str> = self.cells.iter().map(
+            |(pos, cell)| (*pos, match cell {
+                Cell::PartNumber => HIGHLIGHT,
+                Cell::Ignored => MUTED,
+                Cell::Gear => ALERT,
+            })).collect();
+
+        for (i, row) in self.rows.iter().enumerate() {
+            for (j, symbol) in row.chars().enumerate() {
+                let (x, y): (f64, f64) = cell_pos(i, j);
+
+                if let Some(fill) = fills.get(&(i, j)) {
+                    svg.rect(x, y, CELL, CELL, fill);
+                }
+
+                let color: &str = match symbol {
+                    '.' => MUTED,
+                    _ if fills.contains_key(&(i, j)) => BACKGROUND,
+                    _ if symbol.is_ascii_digit() => TEXT,
+                    _ => SYMBOL,
+                };
+
+                svg.text(x + CELL / 2.0, y + CELL / 2.0, FONT_SIZE, color,
+                         &symbol.to_string());
+            }
+        }
+
+        let legend_y: f64 = height - MARGIN;
+        svg.rect(MARGIN, legend_y - 5.0, 10.0, 10.0, HIGHLIGHT);
+        svg.label(MARGIN + 15.0, legend_y, FONT_SIZE, TEXT, "part number");
+        svg.rect(MARGIN + 110.0, legend_y - 5.0, 10.0, 10.0, ALERT);
+        svg.label(MARGIN + 125.0, legend_y, FONT_SIZE, TEXT, "gear");
+
+        return svg;
+    }
+}
+
+// day 5: the seed ranges mapped from category to category
+struct Almanac {
+    maps: Vec<Map>,
+    layers: Vec<Vec<Range<u64>>>,  // mapped seed ranges of every category
+}
+
+impl Almanac {
+    fn parse(file_content: &str) -> Result<Almanac, String> {
+        let lines: Vec<Scanner> = Scanner::new(file_content).lines();
+
+        let seed_ranges: Vec<Range<u64>> = day5::get_seed_ranges(&lines)
+            .map_err(|err| err.to_string())?;
+        let maps: Vec<Map> = day5::get_all_maps(&lines)
+            .map_err(|err| err.to_string())?;
+
+        let mut layers: Vec<Vec<Range<u64>>> = vec![seed_ranges];
+        for map in &maps {
+            let mapped: Vec<Range<u64>> =
+                day5::map_ranges(layers.last().unwrap(), map)
+                    .map_err(|err| err.to_string())?;
+            layers.push(mapped);
+        }
+
+        return Ok(Almanac{maps: maps, layers: layers});
+    }
+}
+
+impl Visualize for Almanac {
+    fn visualize(&self) -> Svg {
+        const LABEL_WIDTH: f64 = 100.0;
+        const AXIS_WIDTH: f64 = 800.0;
+        const LAYER_GAP: f64 = 70.0;
+        const BAR_HEIGHT: f64 = 8.0;
+
+        // largest number of all ranges, which is the end of the axis
+        let mut max_num: u64 = 1;
+        for (offsets, source_ranges) in &self.maps {
+            for (offset, range) in offsets.iter().zip(source_ranges) {
+                max_num = max_num.max(range.end)
+                                 .max(helpers::destination(range, *offset).end);
+            }
+        }
+        for range in self.layers.iter().flatten() {
+            max_num = max_num.max(range.end);
+        }
+
+        let width: f64 = LABEL_WIDTH + AXIS_WIDTH + 2.0 * MARGIN;
+        let height: f64 =
+            (day5::CATEGORIES.len() - 1) as f64 * LAYER_GAP + 4.0 * MARGIN;
+
+        let mut svg: Svg = Svg::new(width, height);
+        svg.rect(0.0, 0.0, width, height, BACKGROUND);
+
+        let x = |num: u64| -> f64 {
+            return MARGIN + LABEL_WIDTH +
+                   num as f64 / max_num as f64 * AXIS_WIDTH;
+        };
+        let y = |layer: usize| -> f64 {
+            return 2.0 * MARGIN + layer as f64 * LAYER_GAP;
+        };
+
+        // every range of a map connects its source and destination
+        for (i, (offsets, source_ranges)) in self.maps.iter().enumerate() {
+            for (j, (offset, range)) in
+                offsets.iter().zip(source_ranges).enumerate() {
+                let destination: Range<u64> =
+                    helpers::destination(range, *offset);
+
+                svg.polygon(&[
+                    (x(range.start), y(i)),
+                    (x(range.end), y(i)),
+                    (x(destination.end), y(i + 1)),
+                    (x(destination.start), y(i + 1)),
+                ], PALETTE[j % PALETTE.len()], 0.25);
+            }
+        }
+
+        for (i, category) in day5::CATEGORIES.iter().enumerate() {
+            svg.line((x(0), y(i)), (x(max_num), y(i)), MUTED, 1.0);
+            svg.label(MARGIN, y(i), FONT_SIZE, TEXT, category);
+
+            for range in &self.layers[i] {
+                svg.rect(x(range.start), y(i) - BAR_HEIGHT / 2.0,
+                         (x(range.end) - x(range.start)).max(1.0),
+                         BAR_HEIGHT, HIGHLIGHT);
+            }
+        }
+
+        svg.label(x(0), MARGIN, FONT_SIZE, TEXT, "0");
+        svg.text(x(max_num), MARGIN, FONT_SIZE, TEXT, &max_num.to_string());
+
+        if let Some(lowest) = self.layers.last().unwrap().first() {
+            let last_layer: usize = self.layers.len() - 1;
+            svg.circle(x(lowest.start), y(last_layer), 4.0, ALERT);
+            svg.label(x(lowest.start) + 8.0, y(last_layer) + 14.0,
+                      FONT_SIZE, ALERT,
+                      &format!("lowest location {}", lowest.start));
+        }
+
+        return svg;
+    }
+}
+
+// day 8: the network with the path from AAA to ZZZ highlighted
+struct Network<'a> {
+    directions: Vec<u8>,
+    nodes: Vec<&'a str>,     // name of every node index
+    jumps: Vec<JumpNodes>,   // left and right jump of every node
+}
+
+impl<'a> Network<'a> {
+    fn parse(file_content: &'a str) -> Result<Network<'a>, String> {
+        let directions: Vec<u8> = day8::parse_directions(file_content)
+            .map_err(|err| err.to_string())?;
+        let node_locations: FxHashMap<&str, usize> =
+            day8::map_node_locations(file_content);
+        let jumps: Vec<JumpNodes> =
+            day8::parse_network(file_content, &node_locations)
+                .map_err(|err| err.to_string())?;
+
+        let mut nodes: Vec<&str> = vec!["?"; jumps.len()];
+        for (node, idx) in node_locations {
+            nodes[idx] = node;
+        }
+
+        return Ok(Network{directions: directions, nodes: nodes, jumps: jumps});
+    }
+
+    // jumps (from and to node) of the walk from AAA to ZZZ
+    fn path(&self) -> HashSet<(usize, usize)> {
+        let mut path: HashSet<(usize, usize)> = HashSet::new();
+
+        let Some(mut node) = self.nodes.iter().position(|n| *n == "AAA") else {
+            return path;
+        };
+
+        // the walk is cut off if it never reaches ZZZ
+        for step in 0..self.directions.len() * self.jumps.len() {
+            if self.nodes[node] == "ZZZ" {
+                break;
+            }
+
+            let direction: usize =
+                self.directions[step % self.directions.len()] as usize;
+            let next: usize = self.jumps[node][direction];
+
+            path.insert((node, next));
+            node = next;
+        }
+
+        return path;
+    }
+}
+
+impl Visualize for Network<'_> {
+    fn visualize(&self) -> Svg {
+        const NODE_RADIUS: f64 = 6.0;
+        const NODE_SPACING: f64 = 30.0;
+
+        let radius: f64 = (self.nodes.len() as f64 * NODE_SPACING /
+                           (2.0 * std::f64::consts::PI)).max(100.0);
+        let size: f64 = 2.0 * (radius + 4.0 * MARGIN);
+        let center: (f64, f64) = (size / 2.0, size / 2.0);
+
+        let mut svg: Svg = Svg::new(size, size);
+        svg.rect(0.0, 0.0, size, size, BACKGROUND);
+
+        let positions: Vec<(f64, f64)> =
+            helpers::circle_layout(self.nodes.len(), center, radius);
+        let path: HashSet<(usize, usize)> = self.path();
+
+        // left jumps in the first and right jumps in the second color,
+        // the jumps of the path on top of them
+        for (from, jump_nodes) in self.jumps.iter().enumerate() {
+            for (direction, to) in jump_nodes.iter().enumerate() {
+                if from == *to || path.contains(&(from, *to)) {
+                    continue;
+                }
+
+                let (start, end) = helpers::shorten(
+                    positions[from], positions[*to], NODE_RADIUS);
+                svg.arrow(start, end, PALETTE[direction], 1.0);
+            }
+        }
+
+        for (from, to) in &path {
+            if from != to {
+                let (start, end) = helpers::shorten(
+                    positions[*from], positions[*to], NODE_RADIUS);
+                svg.arrow(start, end, HIGHLIGHT, 2.5);
+            }
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let fill: &str = if node.ends_with('A') {
+                HIGHLIGHT
+            } else if node.ends_with('Z') {
+                ALERT
+            } else {
+                MUTED
+            };
+
+            let (x, y): (f64, f64) = positions[i];
+            svg.circle(x, y, NODE_RADIUS, fill);
+
+            // labels point away from the center
+            let (dx, dy): (f64, f64) = (x - center.0, y - center.1);
+            let scale: f64 = (NODE_RADIUS + 14.0) / radius;
+            svg.text(x + dx * scale, y + dy * scale, FONT_SIZE, TEXT, node);
+        }
+
+        svg.label(MARGIN, MARGIN, FONT_SIZE, PALETTE[0], "left jump");
+        svg.label(MARGIN, MARGIN + 15.0, FONT_SIZE, PALETTE[1], "right jump");
+        svg.label(MARGIN, MARGIN + 30.0, FONT_SIZE, HIGHLIGHT,
+                  "path from AAA to ZZZ");
+
+        return svg;
+    }
+}
+
+// day 9: the difference pyramid of every history with the
+// extrapolated values at both ends
+struct Histories {
+    pyramids: Vec<Vec<(i128, Vec<i128>, i128)>>,
+}
+
+impl Histories {
+    fn parse(file_content: &str) -> Result<Histories, String> {
+        let mut pyramids: Vec<Vec<(i128, Vec<i128>, i128)>> = Vec::new();
+
+        for mut line in Scanner::new(file_content).lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let history: Vec<i64> = day9::parse_nums_from_line(&mut line)
+                .map_err(|err| err.to_string())?;
+
+            pyramids.push(difference_pyramid(&history).ok_or(
+                "A difference of the history doesn't fit into 128 bits")?);
+        }
+
+        return Ok(Histories{pyramids: pyramids});
+    }
+}
+
+impl Visualize for Histories {
+    fn visualize(&self) -> Svg {
+        const ROW_HEIGHT: f64 = 18.0;
+
+        // every cell fits the longest number
+        let mut max_len: usize = 1;
+        let mut max_row_len: usize = 0;
+        for pyramid in &self.pyramids {
+            for (prev, row, next) in pyramid {
+                for num in row.iter().chain([prev, next]) {
+                    max_len = max_len.max(num.to_string().len());
+                }
+                max_row_len = max_row_len.max(row.len() + 2);
+            }
+        }
+
+        let cell: f64 = max_len as f64 * FONT_SIZE * 0.65 + 8.0;
+        let rows: usize = self.pyramids.iter().map(|p| p.len() + 1).sum();
+
+        let width: f64 = max_row_len as f64 * cell + 2.0 * MARGIN;
+        let height: f64 = rows as f64 * ROW_HEIGHT + 2.0 * MARGIN;
+
+        let mut svg: Svg = Svg::new(width, height);
+        svg.rect(0.0, 0.0, width, height, BACKGROUND);
+
+        let mut y: f64 = MARGIN;
+
+        for pyramid in &self.pyramids {
+            for (depth, (prev, row, next)) in pyramid.iter().enumerate() {
+                // every row is shifted by half a cell, like in the puzzle
+                let x0: f64 = MARGIN + depth as f64 * cell / 2.0;
+                let center_y: f64 = y + ROW_HEIGHT / 2.0;
+
+                svg.text(x0 + cell / 2.0, center_y, FONT_SIZE, ALERT,
+                         &prev.to_string());
+
+                for (j, num) in row.iter().enumerate() {
+                    let color: &str = if *num == 0 { MUTED } else { TEXT };
+                    svg.text(x0 + (j + 1) as f64 * cell + cell / 2.0,
+                             center_y, FONT_SIZE, color, &num.to_string());
+                }
+
+                svg.text(x0 + (row.len() + 1) as f64 * cell + cell / 2.0,
+                         center_y, FONT_SIZE, ALERT, &next.to_string());
+
+                y += ROW_HEIGHT;
+            }
+
+            y += ROW_HEIGHT;
+        }
+
+        return svg;
+    }
+}
@@ -0,0 +1,125 @@
+// watch mode: poll the modification times of the input file (and
+// optionally an answers file) and re-run the solvers whenever they change
+
+use std::fs;
+use std::thread;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::answer::Answer;
+use crate::answers::Answers;
+use crate::cache;
+use crate::config::Config;
+use crate::solver;
+use crate::solver::Solution;
+
+pub struct WatchOptions {
+    pub day: u8,
+    pub parts: Vec<u8>,                // puzzles of the day to re-run
+    pub input_file_path: String,
+    pub answers_file_path: Option<String>,
+    pub poll_interval: Duration,
+}
+
+// the solvers are run like by the runner, with the timeout and the cache
+// of the config
+pub fn run(config: &Config, options: &WatchOptions) -> Result<(), String> {
+    if solver::get_solver(options.day).is_none() {
+        return Err(format!("No solver for AOC day {}", options.day));
+    }
+
+    let mut watched_files: Vec<&str> = vec![options.input_file_path.as_str()];
+    if let Some(answers_file_path) = &options.answers_file_path {
+        watched_files.push(answers_file_path.as_str());
+    }
+
+    println!("Watching {} (Ctrl-C to stop)", watched_files.join(", "));
+
+    let mut last_mtimes: Vec<Option<SystemTime>> = Vec::new();
+
+    // answer of every watched puzzle from the previous run
+    let mut previous_answers: Vec<Option<String>> =
+        vec![None; options.parts.len()];
+
+    loop {
+        let mtimes: Vec<Option<SystemTime>> =
+            watched_files.iter().map(|path| get_mtime(path)).collect();
+
+        if mtimes != last_mtimes {
+            last_mtimes = mtimes;
+
+            // a missing input file just means we have to wait for it
+            match fs::read_to_string(&options.input_file_path) {
+                Ok(file_content) => run_solvers(
+                    config, options, &file_content, &mut previous_answers),
+                Err(err) => println!("Waiting for input file '{}': {}",
+                                     options.input_file_path, err),
+            }
+        }
+
+        thread::sleep(options.poll_interval);
+    }
+}
+
+fn run_solvers(
+    config: &Config, options: &WatchOptions, file_content: &str,
+    previous_answers: &mut Vec<Option<String>>) {
+    let answers: Option<Answers> = match &options.answers_file_path {
+        Some(path) => match Answers::read(path) {
+            Ok(answers) => Some(answers),
+            Err(err) => {
+                println!("{}", err);
+                None
+            },
+        },
+        None => None,
+    };
+
+    println!("---");
+
+    for (i, part) in options.parts.iter().enumerate() {
+        let solution: Solution = match cache::solve(
+            config, options.day, *part, None, file_content.to_string()) {
+            Ok(solution) => solution,
+            Err(err) => {
+                println!("{}", err);
+                continue;
+            },
+        };
+
+        let answer: String = match &solution.answer {
+            Ok(answer) => answer.to_string(),
+            Err(err) => format!("error: {}", err),
+        };
+
+        // compare with the answer of the previous run
+        let diff: String = match &previous_answers[i] {
+            None => String::new(),
+            Some(previous) if *previous == answer =>
+                String::from(" (unchanged)"),
+            Some(previous) => format!(" (was {})", previous),
+        };
+
+        // compare with the expected answer like the runner does (an
+        // unsolved puzzle is neither correct nor wrong)
+        let expected: Option<&str> =
+            answers.as_ref().and_then(|a| a.get(options.day, *part));
+        let verdict: String = match (expected, &solution.answer) {
+            (None, _) | (_, Ok(Answer::Unsolved)) => String::new(),
+            (Some(expected), Ok(answer)) if answer.matches(expected) =>
+                String::from(" [correct]"),
+            (Some(expected), _) => format!(" [expected {}]", expected),
+        };
+        let cached: &str = if solution.cached { " (cached)" } else { "" };
+
+        println!("Day {}, puzzle {}: {}{}{} in {:.3?}{}",
+                 options.day, part, answer, diff, verdict, solution.elapsed,
+                 cached);
+
+        previous_answers[i] = Some(answer);
+    }
+}
+
+fn get_mtime(path: &str) -> Option<SystemTime> {
+    return fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+}
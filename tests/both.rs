@@ -0,0 +1,138 @@
+// solve both puzzles of a day from a single parse of the input, which must
+// find the same answers as solving each puzzle on its own
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+use adventofcode23::answer::BothAnswers;
+use adventofcode23::day5;
+
+const DAYS: [&str; 5] = ["3", "4", "5", "7", "8"];
+
+fn run(args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--cache-dir", ""])
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+fn stdout(output: &Output) -> String {
+    return String::from_utf8_lossy(&output.stdout).to_string();
+}
+
+fn write_input(name: &str, content: &str) -> PathBuf {
+    let path: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-both-{}.txt", name));
+    fs::write(&path, content).unwrap();
+
+    return path;
+}
+
+#[test]
+fn both_finds_the_answers_of_the_single_puzzles() {
+    for day in DAYS {
+        let path: String = format!("tests/inputs/day{}.txt", day);
+
+        let output: Output = run(&[day, "both", &path]);
+        assert!(output.status.success(), "day {}", day);
+
+        let both: String = stdout(&output);
+        let lines: Vec<&str> = both.lines().collect();
+        assert_eq!(lines[0], stdout(&run(&[day, "1", &path])).trim_end());
+        assert_eq!(lines[1], stdout(&run(&[day, "2", &path])).trim_end());
+        assert!(lines[2].starts_with("Time: parse "), "{}", both);
+        assert!(lines[2].contains(", puzzle 1 "), "{}", both);
+        assert!(lines[2].contains(", puzzle 2 "), "{}", both);
+    }
+}
+
+#[test]
+fn json_reports_the_time_of_every_stage() {
+    let output: Output = run(&["7", "both", "tests/inputs/day7.txt", "--json"]);
+    let json: String = stdout(&output);
+
+    assert!(json.starts_with("{\"day\":7,\"part\":\"both\",\
+                              \"title\":\"Camel Cards\",\
+                              \"answers\":[6440,5905],\
+                              \"time_ms\":{\"parse\":"), "{}", json);
+    assert!(json.contains(",\"puzzle1\":"), "{}", json);
+    assert!(json.contains(",\"puzzle2\":"), "{}", json);
+}
+
+#[test]
+fn failing_stage_is_reported() {
+    // a distance is missing for puzzle 1, but not for puzzle 2, which is
+    // still solved
+    let path: PathBuf = write_input("puzzle1", "Time: 7 15\nDistance: 940\n");
+    let output: Output = run(&["6", "both", path.to_str().unwrap()]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Solver failed (puzzle 1): Invalid input for day 6: Expected a \
+         distance for every race time"));
+    let lines: String = stdout(&output);
+    assert!(lines.starts_with("Result of AOC day 6, puzzle 2: "), "{}", lines);
+    assert!(lines.contains("\nTime: parse "), "{}", lines);
+    assert!(lines.contains(", puzzle 2 "), "{}", lines);
+
+    let path: PathBuf = write_input("parse", "Time: 7\n");
+    let output: Output = run(&["6", "both", path.to_str().unwrap(), "--json"]);
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains(
+        "\"answers\":null,\"time_ms\":{\"parse\":"));
+    assert!(stdout(&output).contains(
+        "\"puzzle1\":null,\"puzzle2\":null}"));
+}
+
+#[test]
+fn puzzles_are_reported_independently() {
+    // the example of puzzle 2 of day 1 spells out the digits of some lines,
+    // which puzzle 1 doesn't read
+    let path: PathBuf = write_input(
+        "day1", "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n\
+                 4nineeightseven2\nzoneight234\n7pqrstsixteen\n");
+    let output: Output = run(&["1", "both", path.to_str().unwrap()]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Solver failed (puzzle 1): Invalid input for day 1: Expected a digit \
+         in the line"));
+    assert!(stdout(&output).starts_with("Result of AOC day 1, puzzle 2: 281"));
+
+    let output: Output = run(&["1", "both", path.to_str().unwrap(), "--json"]);
+    let json: String = stdout(&output);
+
+    assert!(!output.status.success());
+    assert!(json.contains("\"answers\":[null,281],"), "{}", json);
+    assert!(json.contains("\"error\":null,\"errors\":[\"Invalid input for \
+                           day 1: Expected a digit in the line at byte 9\",\
+                           null]"), "{}", json);
+}
+
+#[test]
+fn both_runs_only_the_default_strategy() {
+    let output: Output = run(&["8", "both", "tests/inputs/day8.txt",
+                               "--strategy", "simulation"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "--strategy can't be used with both"));
+}
+
+#[test]
+fn library_calls_lap_after_the_parse_and_puzzle_1() {
+    let input: String = fs::read_to_string("tests/inputs/day5.txt").unwrap();
+    let mut laps: u32 = 0;
+
+    let answers: BothAnswers =
+        day5::both(input.clone(), &mut || laps += 1).unwrap();
+
+    assert_eq!(laps, 2);
+    assert_eq!(answers, [day5::puzzle1(input.clone()),
+                         day5::puzzle2(input)]);
+}
@@ -0,0 +1,97 @@
+// solve the example inputs with the runner twice, where the second run
+// has to answer from the cache unless the input, the solver or the
+// command line asks for a new answer
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+// directories of the inputs and the cache of a test
+fn setup(name: &str) -> (PathBuf, PathBuf) {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-cache-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+
+    let input_dir: PathBuf = dir.join("inputs");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::copy("tests/inputs/day7.txt", input_dir.join("day7.txt")).unwrap();
+
+    return (input_dir, dir.join("cache"));
+}
+
+fn run(input_dir: &PathBuf, cache_dir: &PathBuf, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--input-dir", input_dir.to_str().unwrap(),
+               "--cache-dir", cache_dir.to_str().unwrap()])
+        .args(args)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    return String::from_utf8_lossy(&output.stdout).to_string();
+}
+
+#[test]
+fn second_run_is_cached() {
+    let (input_dir, cache_dir) = setup("second-run");
+
+    let first: String = run(&input_dir, &cache_dir, &["run", "7", "1"]);
+    assert_eq!(first, "Result of AOC day 7, puzzle 1: 6440\n");
+
+    let second: String = run(&input_dir, &cache_dir, &["run", "7", "1"]);
+    assert_eq!(second, "Result of AOC day 7, puzzle 1: 6440 (cached)\n");
+
+    let no_cache: String =
+        run(&input_dir, &cache_dir, &["run", "7", "1", "--no-cache"]);
+    assert_eq!(no_cache, "Result of AOC day 7, puzzle 1: 6440\n");
+}
+
+#[test]
+fn changed_input_is_solved() {
+    let (input_dir, cache_dir) = setup("changed-input");
+    run(&input_dir, &cache_dir, &["run", "7", "1"]);
+
+    fs::write(input_dir.join("day7.txt"), "32T3K 765\nKK677 28\n").unwrap();
+    assert_eq!(run(&input_dir, &cache_dir, &["run", "7", "1"]),
+               "Result of AOC day 7, puzzle 1: 821\n");
+}
+
+#[test]
+fn answer_of_another_solver_is_ignored() {
+    let (input_dir, cache_dir) = setup("changed-solver");
+    run(&input_dir, &cache_dir, &["run", "7", "1"]);
+
+    // pretend the answer was cached by an older version of the solver,
+    // whose fingerprint is the last part of the file name
+    for entry in fs::read_dir(&cache_dir).unwrap() {
+        let path: PathBuf = entry.unwrap().path();
+        let name: String = path.file_name().unwrap().to_string_lossy()
+            .to_string();
+        let (key, _) = name.rsplit_once('-').unwrap();
+
+        fs::write(cache_dir.join(format!("{}-0000000000000000.txt", key)),
+                  "12345").unwrap();
+        fs::remove_file(&path).unwrap();
+    }
+
+    assert_eq!(run(&input_dir, &cache_dir, &["run", "7", "1"]),
+               "Result of AOC day 7, puzzle 1: 6440\n");
+}
+
+#[test]
+fn clear_removes_all_answers() {
+    let (input_dir, cache_dir) = setup("clear");
+    run(&input_dir, &cache_dir, &["all"]);
+
+    let output: String = run(&input_dir, &cache_dir, &["cache", "clear"]);
+    assert_eq!(output, format!("Removed 2 cached answers from {}\n",
+                               cache_dir.display()));
+    assert!(!cache_dir.exists());
+
+    let all: String = run(&input_dir, &cache_dir, &["all"]);
+    assert!(all.contains("Day 7, puzzle 2: 5905 in") &&
+            !all.contains("(cached)"), "{}", all);
+}
@@ -0,0 +1,70 @@
+// run the `check` command of the runner on the example inputs and on
+// broken inputs, whose problems have to be reported with their line
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+fn check(day: u8, path: &str) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["check", &day.to_string(), path])
+        .output()
+        .unwrap();
+}
+
+fn assert_problem(day: u8, name: &str, input: &str, problem: &str) {
+    let path: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-check-{}.txt", name));
+    fs::write(&path, input).unwrap();
+
+    let output: Output = check(day, path.to_str().unwrap());
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(!output.status.success(), "{} passed the check", name);
+    assert!(stdout.contains(problem),
+            "{} didn't report '{}':\n{}", name, problem, stdout);
+}
+
+#[test]
+fn examples_are_valid() {
+    for day in [3, 4, 5, 7, 8] {
+        let output: Output =
+            check(day, &format!("tests/inputs/day{}.txt", day));
+        assert!(output.status.success(), "day {}: {}", day,
+                String::from_utf8_lossy(&output.stdout));
+    }
+}
+
+#[test]
+fn missing_seeds_line() {
+    assert_problem(5, "missing-seeds", "seed-to-soil map:\n50 98 2\n",
+                   "missing the 'seeds:' line");
+}
+
+#[test]
+fn odd_seed_count() {
+    assert_problem(5, "odd-seeds", "seeds: 79 14 55\n",
+                   "line 1: odd number of seeds (3)");
+}
+
+#[test]
+fn hand_with_invalid_cards() {
+    assert_problem(7, "invalid-cards", "32T3K 765\nT5XJ1 684\n",
+                   "line 2: hand 'T5XJ1' has invalid cards 'X1'");
+}
+
+#[test]
+fn dangling_node_reference() {
+    assert_problem(8, "dangling-node",
+                   "LR\n\nAAA = (BBB, ZZZ)\nBBB = (QQQ, ZZZ)\n\
+                    ZZZ = (ZZZ, ZZZ)\n",
+                   "line 4: node 'BBB' jumps to undefined node 'QQQ'");
+}
+
+#[test]
+fn ragged_schematic_rows() {
+    assert_problem(3, "ragged-rows", "467..\n...*\n..35.\n",
+                   "line 2: row has 4 fields, but line 1 has 5");
+}
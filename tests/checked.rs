@@ -0,0 +1,69 @@
+// inputs whose answers don't fit into the integers of the solvers, which
+// are only reported as overflows when built with the "checked" feature
+// (cargo test --features checked)
+#![cfg(feature = "checked")]
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+use adventofcode23::answer::Answer;
+use adventofcode23::day2;
+use adventofcode23::day9;
+use adventofcode23::error::SolveError;
+
+// 5000000^3 is bigger than the biggest u64
+const DAY2_INPUT: &str = "Game 1: 5000000 red, 5000000 green, 5000000 blue\n";
+
+// check that the error names the day, the function and the line of
+// the overflowing operation
+fn assert_overflow(error: SolveError, expected_day: u8,
+                   expected_function: &str, expected_file: &str) {
+    let SolveError::Overflow{day, function, file, line} = error else {
+        panic!("Expected an overflow, got '{}'", error);
+    };
+
+    assert_eq!(day, expected_day);
+    assert_eq!(function, expected_function);
+    assert_eq!(file, expected_file);
+
+    let source: String = fs::read_to_string(file).unwrap();
+    let source_line: &str = source.lines().nth(line as usize - 1).unwrap();
+    assert!(source_line.contains("checked!("), "{}:{}", file, line);
+}
+
+#[test]
+fn day2_power_overflows() {
+    assert_eq!(day2::puzzle1(DAY2_INPUT.to_string()), Ok(Answer::Unsigned(0)));
+    assert_overflow(day2::puzzle2(DAY2_INPUT.to_string()).unwrap_err(), 2,
+                    "calc_power_of_minimum_cubeset", "src/day2/helpers.rs");
+}
+
+#[test]
+fn day9_difference_overflows() {
+    let input: &str = "-9223372036854775807 9223372036854775807\n";
+
+    assert_overflow(day9::puzzle1(input.to_string()).unwrap_err(), 9,
+                    "calc_nums_difference", "src/day9/helpers.rs");
+    assert_overflow(day9::puzzle2(input.to_string()).unwrap_err(), 9,
+                    "calc_nums_difference", "src/day9/helpers.rs");
+}
+
+#[test]
+fn runner_reports_the_overflow() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-checked-day2.txt");
+    fs::write(&path, DAY2_INPUT).unwrap();
+
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--cache-dir", "", "2", "2", path.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Arithmetic overflow in day 2, calc_power_of_minimum_cubeset \
+         (src/day2/helpers.rs:"), "{}",
+        String::from_utf8_lossy(&output.stderr));
+}
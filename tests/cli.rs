@@ -0,0 +1,85 @@
+// check the argument parsing of the runner: bad arguments are reported
+// by name and every command has its own help
+
+use std::process::Command;
+use std::process::Output;
+
+fn run(args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+fn assert_fails_with(args: &[&str], message: &str) {
+    let output: Output = run(args);
+    let stderr: String = String::from_utf8_lossy(&output.stderr).to_string();
+
+    assert!(!output.status.success(), "{:?} succeeded", args);
+    assert!(stderr.contains(message),
+            "{:?} didn't report '{}':\n{}", args, message, stderr);
+}
+
+#[test]
+fn invalid_arguments_are_reported_by_name() {
+    assert_fails_with(&["run", "26", "1"], "Invalid value '26' for DAY");
+    // days outside of an u8 are still days of the run shorthand
+    assert_fails_with(&["300", "1"], "Invalid value '300' for DAY");
+    assert_fails_with(&["run", "1", "3"], "Invalid value '3' for PUZZLE");
+    assert_fails_with(&["gen", "1", "--seed", "x"],
+                      "Invalid value 'x' for --seed");
+    assert_fails_with(&["bench", "--runs", "0"],
+                      "Invalid value '0' for --runs");
+}
+
+#[test]
+fn missing_and_unknown_arguments_are_reported() {
+    assert_fails_with(&["run", "1"], "Missing argument PUZZLE for 'run'");
+    assert_fails_with(&["list", "--foo"], "Unknown option '--foo' for 'list'");
+    assert_fails_with(&["snapshot", "3", "a", "b"],
+                      "Unexpected argument 'b' for 'snapshot'");
+    assert_fails_with(&["frobnicate"], "Unknown command 'frobnicate'");
+    assert_fails_with(&["--no-cache", "1", "1"],
+                      "Option '--no-cache' must follow the command");
+}
+
+#[test]
+fn every_command_has_a_help() {
+    for command in ["run", "all", "bench", "verify", "gen", "list", "check"] {
+        let output: Output = run(&[command, "--help"]);
+        let stdout: String =
+            String::from_utf8_lossy(&output.stdout).to_string();
+
+        assert!(output.status.success());
+        assert!(stdout.starts_with(
+            &format!("Usage: adventofcode23 {}", command)), "{}", stdout);
+    }
+}
+
+#[test]
+fn help_lists_the_titles_of_all_days() {
+    for args in [&["--help"][..], &["help", "run"][..]] {
+        let help: String = String::from_utf8_lossy(&run(args).stdout)
+            .to_string();
+
+        assert!(help.contains("Available days:\n   1  Trebuchet?!\n"),
+                "{:?}:\n{}", args, help);
+        assert!(help.contains("\n   9  Mirage Maintenance\n"),
+                "{:?}:\n{}", args, help);
+    }
+}
+
+#[test]
+fn completions_cover_all_commands() {
+    for shell in ["bash", "zsh", "fish"] {
+        let output: Output = run(&["completions", shell]);
+        let script: String =
+            String::from_utf8_lossy(&output.stdout).to_string();
+
+        assert!(output.status.success());
+        for command in ["run", "bench", "verify", "check", "completions"] {
+            assert!(script.contains(command),
+                    "{} completions miss '{}'", shell, command);
+        }
+    }
+}
@@ -0,0 +1,288 @@
+// fetch inputs and submit answers against a mock of the Advent of Code
+// server on localhost, which serves the example input of day 7 and judges
+// the answers of its first puzzle
+
+use std::env;
+use std::fs;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+
+const SESSION: &str = "53616c7465645f5f";
+const DAY7_ANSWER: &str = "6440";
+
+// requests the mock server got, as "METHOD PATH BODY"
+type Requests = Arc<Mutex<Vec<String>>>;
+
+struct MockServer {
+    url: String,
+    requests: Requests,
+}
+
+impl MockServer {
+    // serve requests in the background until the test process exits
+    fn start() -> MockServer {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url: String =
+            format!("http://{}/", listener.local_addr().unwrap());
+        let requests: Requests = Arc::new(Mutex::new(Vec::new()));
+
+        let server_requests: Requests = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                handle(stream.unwrap(), &server_requests);
+            }
+        });
+
+        return MockServer{url: url, requests: requests};
+    }
+
+    fn request_count(&self) -> usize {
+        return self.requests.lock().unwrap().len();
+    }
+}
+
+fn handle(mut stream: TcpStream, requests: &Requests) {
+    let mut reader: BufReader<&TcpStream> = BufReader::new(&stream);
+
+    let mut request_line: String = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let request_line: Vec<&str> = request_line.split(' ').collect();
+    let (method, path): (&str, &str) = (request_line[0], request_line[1]);
+
+    let mut content_length: usize = 0;
+    let mut cookie: String = String::new();
+    loop {
+        let mut header: String = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header: &str = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        let (name, value) = header.split_once(": ").unwrap();
+        match name {
+            "Content-Length" => content_length = value.parse().unwrap(),
+            "Cookie" => cookie = value.to_string(),
+            _ => (),
+        }
+    }
+
+    let mut body: Vec<u8> = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    let body: String = String::from_utf8(body).unwrap();
+    requests.lock().unwrap().push(format!("{} {} {}", method, path, body));
+
+    let response: String = match (method, path) {
+        _ if cookie != format!("session={}", SESSION) => response(
+            400, "Puzzle inputs differ by user.  Please log in to get your \
+                  puzzle input.\n"),
+        // the input is sent in chunks, like the real server does
+        ("GET", "/2023/day/7/input") => {
+            let input: String =
+                fs::read_to_string("tests/inputs/day7.txt").unwrap();
+            let (first, second) = input.split_at(input.len() / 2);
+            format!("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+                     {:x}\r\n{}\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+                    first.len(), first, second.len(), second)
+        },
+        ("POST", "/2023/day/7/answer") => response(200, &judge(&body)),
+        _ => response(404, "404 Not Found\n"),
+    };
+
+    stream.write_all(response.as_bytes()).unwrap();
+}
+
+fn response(status: u16, body: &str) -> String {
+    return format!("HTTP/1.1 {} Mock\r\nContent-Length: {}\r\n\r\n{}",
+                   status, body.len(), body);
+}
+
+// page with the verdict on a "level=PART&answer=ANSWER" submission
+fn judge(form: &str) -> String {
+    let verdict: &str = match form.strip_prefix("level=1&answer=") {
+        Some(DAY7_ANSWER) => "That's the right answer! You are one gold \
+                              star closer to restoring snow operations.",
+        Some(answer) if answer.parse::<u64>().is_ok_and(|a| a < 6440) =>
+            "That's not the right answer; your answer is too low.",
+        Some(_) => "That's not the right answer.",
+        None => "You don't seem to be solving the right level.",
+    };
+
+    return format!("<html><main><article><p>{}</p></article></main></html>",
+                   verdict);
+}
+
+// empty input directory for a test
+fn setup(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-client-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    return dir;
+}
+
+fn run(server: &MockServer, dir: &PathBuf, session: &str, args: &[&str]) ->
+    Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--input-dir", dir.to_str().unwrap(), "--cache-dir", "",
+               "--base-url", &server.url, "--session", session])
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+fn stdout(output: &Output) -> String {
+    return String::from_utf8_lossy(&output.stdout).to_string();
+}
+
+fn stderr(output: &Output) -> String {
+    return String::from_utf8_lossy(&output.stderr).to_string();
+}
+
+#[test]
+fn fetch_saves_the_input_once() {
+    let server: MockServer = MockServer::start();
+    let dir: PathBuf = setup("fetch");
+
+    let output: Output = run(&server, &dir, SESSION, &["fetch", "7"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("day7.txt")).unwrap(),
+               fs::read_to_string("tests/inputs/day7.txt").unwrap());
+
+    // the saved input is used instead of downloading it again
+    let output: Output = run(&server, &dir, SESSION, &["fetch", "7"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("exists already"));
+    assert_eq!(server.request_count(), 1);
+
+    let output: Output =
+        run(&server, &dir, SESSION, &["fetch", "7", "--force"]);
+    assert!(output.status.success());
+    assert_eq!(server.request_count(), 2);
+}
+
+#[test]
+fn fetch_reports_a_wrong_session() {
+    let server: MockServer = MockServer::start();
+    let dir: PathBuf = setup("session");
+
+    let output: Output = run(&server, &dir, "expired", &["fetch", "7"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("status 400 (Puzzle inputs differ"),
+            "{}", stderr(&output));
+    assert!(!dir.join("day7.txt").exists());
+
+    let output: Output = run(&server, &dir, "", &["fetch", "7"]);
+    assert!(stderr(&output).contains("No session"), "{}", stderr(&output));
+    assert_eq!(server.request_count(), 1);
+}
+
+#[test]
+fn submit_records_the_verdicts() {
+    let server: MockServer = MockServer::start();
+    let dir: PathBuf = setup("submit");
+
+    let output: Output =
+        run(&server, &dir, SESSION, &["submit", "7", "1", "--answer", "42"]);
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output),
+               "Day 7, puzzle 1, answer 42: wrong (too low)\n");
+
+    // answers that can't be right aren't submitted again
+    for answer in ["42", "17"] {
+        let output: Output = run(&server, &dir, SESSION,
+                                 &["submit", "7", "1", "--answer", answer]);
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains("42 was already"),
+                "{}", stderr(&output));
+    }
+    assert_eq!(server.request_count(), 1);
+
+    // without --answer, the puzzle is solved with the fetched input
+    assert!(run(&server, &dir, SESSION, &["fetch", "7"]).status.success());
+    let output: Output = run(&server, &dir, SESSION, &["submit", "7", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day 7, puzzle 1, answer 6440: correct\n");
+
+    let requests: Vec<String> = server.requests.lock().unwrap().clone();
+    assert_eq!(requests.last().unwrap(),
+               "POST /2023/day/7/answer level=1&answer=6440");
+
+    let submissions: Vec<String> =
+        fs::read_to_string(dir.join("submissions.txt")).unwrap().lines()
+        .filter(|line| !line.starts_with('#')).map(String::from).collect();
+    assert_eq!(submissions.len(), 2);
+    assert!(submissions[0].ends_with(" 7 1 42 too-low"), "{}", submissions[0]);
+    assert!(submissions[1].ends_with(" 7 1 6440 correct"),
+            "{}", submissions[1]);
+}
+
+#[test]
+fn recorded_answers_are_read_back() {
+    let server: MockServer = MockServer::start();
+    let dir: PathBuf = setup("round-trip");
+
+    // answers that wouldn't fit into a line of the record aren't submitted
+    for answer in ["4 2", "42\t", "#42"] {
+        let output: Output = run(&server, &dir, SESSION,
+                                 &["submit", "7", "1", "--answer", answer]);
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains("Invalid answer"),
+                "{}", stderr(&output));
+    }
+    assert_eq!(server.request_count(), 0);
+
+    // a text answer is recorded and read back by the next submit
+    let output: Output =
+        run(&server, &dir, SESSION, &["submit", "7", "1", "--answer", "abc"]);
+    assert_eq!(stdout(&output), "Day 7, puzzle 1, answer abc: wrong\n");
+
+    let output: Output =
+        run(&server, &dir, SESSION, &["submit", "7", "1", "--answer", "abc"]);
+    assert!(stderr(&output).contains("abc was already submitted: wrong"),
+            "{}", stderr(&output));
+    assert_eq!(server.request_count(), 1);
+}
+
+#[test]
+fn https_needs_a_proxy() {
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--input-dir", setup("https").to_str().unwrap(),
+               "--base-url", "https://adventofcode.com", "--session", SESSION,
+               "fetch", "7"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains(
+        "invalid value 'https://adventofcode.com' for 'base_url': HTTPS \
+         isn't supported"), "{}", stderr(&output));
+}
+
+#[test]
+fn there_is_no_default_server() {
+    // neither the config of the user nor the one of the repo set one
+    let dir: PathBuf = setup("no-server");
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--input-dir", dir.to_str().unwrap(), "--session", SESSION,
+               "fetch", "7"])
+        .current_dir(&dir)
+        .env("XDG_CONFIG_HOME", &dir)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains(
+        "No server to connect to; set 'base_url'"), "{}", stderr(&output));
+}
@@ -0,0 +1,159 @@
+// read the settings from the config files of a temporary repo root and
+// user config directory, and print them with the `config show` command
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+// a directory for the repo config file (aoc.ini), with the user config
+// directory ($XDG_CONFIG_HOME) in it
+fn setup(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-config-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("xdg").join("adventofcode23")).unwrap();
+
+    return dir;
+}
+
+fn write_repo_config(dir: &PathBuf, content: &str) {
+    fs::write(dir.join("aoc.ini"), content).unwrap();
+}
+
+fn write_user_config(dir: &PathBuf, content: &str) -> String {
+    let path: PathBuf = dir.join("xdg").join("adventofcode23")
+        .join("config.ini");
+    fs::write(&path, content).unwrap();
+
+    return path.display().to_string();
+}
+
+fn config_show(dir: &PathBuf, args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .current_dir(dir)
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .args(args)
+        .args(["config", "show"])
+        .output()
+        .unwrap();
+}
+
+// the value and source of a setting in the output of `config show`
+fn setting(output: &Output, key: &str) -> (String, String) {
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+    let line: &str = stdout.lines()
+        .find(|line| line.split(' ').next() == Some(key))
+        .unwrap_or_else(|| panic!("No setting '{}' in {}", key, stdout));
+
+    let (_, rest): (&str, &str) = line.split_once(" = ").unwrap();
+    let (value, source): (&str, &str) = rest.rsplit_once(" (").unwrap();
+
+    return (value.trim().to_string(),
+            source.trim_end_matches(')').to_string());
+}
+
+fn stderr(output: &Output) -> String {
+    return String::from_utf8_lossy(&output.stderr).to_string();
+}
+
+#[test]
+fn defaults_without_config_files() {
+    let dir: PathBuf = setup("defaults");
+    let output: Output = config_show(&dir, &[]);
+
+    assert_eq!(setting(&output, "jobs"), (String::from("1"),
+                                          String::from("default")));
+    assert_eq!(setting(&output, "cache_dir"), (String::from(".cache"),
+                                               String::from("default")));
+}
+
+#[test]
+fn comments_blank_lines_and_quotes() {
+    let dir: PathBuf = setup("comments");
+    write_repo_config(&dir, "# comment\n; another comment\n\n  \
+                             jobs = 3  \ninput_dir = \"my inputs\"\n\
+                             # jobs = 5\n");
+
+    let output: Output = config_show(&dir, &[]);
+
+    assert_eq!(setting(&output, "jobs"), (String::from("3"),
+                                          String::from("aoc.ini")));
+    assert_eq!(setting(&output, "input_dir"), (String::from("my inputs"),
+                                               String::from("aoc.ini")));
+}
+
+#[test]
+fn sections_are_errors() {
+    // all settings are top-level, so a section can't hold any of them
+    let dir: PathBuf = setup("sections");
+
+    for header in ["[runner]", "[ ]"] {
+        write_repo_config(&dir, &format!("jobs = 2\n{}\njobs = 3\n", header));
+
+        let output: Output = config_show(&dir, &[]);
+        assert!(!output.status.success());
+        assert_eq!(stderr(&output), format!(
+            "aoc.ini: line 2: sections aren't supported, got '{}'\n",
+            header));
+    }
+}
+
+#[test]
+fn bad_lines_are_errors() {
+    let dir: PathBuf = setup("bad-lines");
+    let cases = [
+        ("jobs = 2\njobs 3\n",
+         "aoc.ini: line 2: expected 'key = value', got 'jobs 3'"),
+        ("= 3\n", "aoc.ini: line 1: expected 'key = value', got '= 3'"),
+        ("jobs = 0\n", "aoc.ini: invalid value '0' for 'jobs'"),
+        ("color = auto\n", "aoc.ini: unknown setting 'color'"),
+    ];
+
+    for (content, error) in cases {
+        write_repo_config(&dir, content);
+
+        let output: Output = config_show(&dir, &[]);
+        assert!(!output.status.success());
+        assert_eq!(stderr(&output).trim_end(), error);
+    }
+}
+
+#[test]
+fn repo_config_overrides_user_config() {
+    let dir: PathBuf = setup("precedence");
+    let user_config: String =
+        write_user_config(&dir, "jobs = 2\ntimeout = 5\n");
+    write_repo_config(&dir, "jobs = 4\n");
+
+    let output: Output = config_show(&dir, &[]);
+
+    assert_eq!(setting(&output, "jobs"), (String::from("4"),
+                                          String::from("aoc.ini")));
+    assert_eq!(setting(&output, "timeout"), (String::from("5"),
+                                             user_config));
+}
+
+#[test]
+fn command_line_overrides_config_files() {
+    let dir: PathBuf = setup("command-line");
+    write_user_config(&dir, "jobs = 2\n");
+    write_repo_config(&dir, "jobs = 4\nformat = json\n");
+
+    let output: Output = config_show(&dir, &["--jobs", "8"]);
+
+    assert_eq!(setting(&output, "jobs"), (String::from("8"),
+                                          String::from("command line")));
+    assert_eq!(setting(&output, "format"), (String::from("json"),
+                                            String::from("aoc.ini")));
+
+    // invalid values are reported with their option
+    let output: Output = config_show(&dir, &["--jobs", "none"]);
+    assert!(!output.status.success());
+    assert_eq!(stderr(&output).trim_end(),
+               "Option '--jobs': invalid value 'none' for 'jobs'");
+}
@@ -0,0 +1,70 @@
+// export the graphs of the example inputs with the `graph` command of the
+// runner as DOT and node-link JSON
+
+use std::process::Command;
+use std::process::Output;
+
+fn graph(args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .arg("graph")
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+    return String::from_utf8_lossy(&output.stdout).to_string();
+}
+
+#[test]
+fn network_as_dot() {
+    let dot: String = stdout(&graph(&["8", "tests/inputs/day8.txt"]));
+
+    assert!(dot.starts_with("digraph \"day8\" {\n"), "{}", dot);
+    assert!(dot.contains("    \"AAA\" [start=true, end=false];\n"), "{}", dot);
+    assert!(dot.contains("    \"ZZZ\" [start=false, end=true];\n"), "{}", dot);
+    assert!(dot.contains("    \"CCC\" -> \"ZZZ\" [label=\"L\"];\n"),
+            "{}", dot);
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn network_as_json() {
+    let json: String =
+        stdout(&graph(&["8", "tests/inputs/day8.txt", "--format", "json"]));
+
+    assert!(json.starts_with("{\"directed\":true,"), "{}", json);
+    assert!(json.contains("{\"id\":\"AAA\",\"start\":true,\"end\":false}"),
+            "{}", json);
+    assert!(json.contains(
+        "{\"source\":\"AAA\",\"target\":\"CCC\",\"label\":\"R\"}"),
+        "{}", json);
+}
+
+#[test]
+fn schematic_adjacency() {
+    let dot: String =
+        stdout(&graph(&["3", "tests/inputs/day3.txt", "--format=dot"]));
+
+    assert!(dot.starts_with("graph \"day3\" {\n"), "{}", dot);
+    // 467 and 35 are both next to the gear in row 1
+    assert!(dot.contains("    \"n 0 0\" -- \"s 1 3\";\n"), "{}", dot);
+    assert!(dot.contains("    \"n 2 2\" -- \"s 1 3\";\n"), "{}", dot);
+    assert!(dot.contains("symbol=\"*\", row=1, column=3, gear=true]"),
+            "{}", dot);
+    // 114 isn't next to a symbol
+    assert!(dot.contains("number=114, row=0, column=5, part=false]"),
+            "{}", dot);
+}
+
+#[test]
+fn format_of_the_command_over_the_global_one() {
+    let output: Output = graph(&["8", "tests/inputs/day8.txt",
+                                 "--format", "text"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Invalid value 'text' for --format (expected one of dot, json)"));
+}
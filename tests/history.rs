@@ -0,0 +1,95 @@
+// save benchmark results to a history file and compare later runs with
+// them, where a median that got slower than the threshold has to fail
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+// directory with the example input of day 7 and the path of its history
+fn setup(name: &str) -> (PathBuf, PathBuf) {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-history-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy("tests/inputs/day7.txt", dir.join("day7.txt")).unwrap();
+
+    return (dir.clone(), dir.join("history.txt"));
+}
+
+fn bench(dir: &PathBuf, history: &PathBuf, args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--input-dir", dir.to_str().unwrap(), "--cache-dir", "",
+               "bench", "7", "1", "--runs", "3",
+               "--history", history.to_str().unwrap()])
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+// replace the median of every saved entry
+fn set_medians(history: &PathBuf, median_ns: u64) {
+    let lines: Vec<String> = fs::read_to_string(history).unwrap().lines().map(
+        |line| match line.starts_with('#') {
+            true => line.to_string(),
+            false => format!("{} {}", &line[..line.rfind(' ').unwrap()],
+                             median_ns),
+        }).collect();
+
+    fs::write(history, lines.join("\n")).unwrap();
+}
+
+#[test]
+fn save_appends_to_the_history() {
+    let (dir, history) = setup("save");
+
+    for _ in 0..2 {
+        assert!(bench(&dir, &history, &["--save"]).status.success());
+    }
+
+    let entries: Vec<String> = fs::read_to_string(&history).unwrap().lines()
+        .filter(|line| !line.starts_with('#')).map(String::from).collect();
+
+    assert_eq!(entries.len(), 2);
+    for entry in entries {
+        let components: Vec<&str> = entry.split(' ').collect();
+        assert_eq!(components.len(), 8, "{}", entry);
+        assert_eq!(components[2..5], ["7", "1", "compare"], "{}", entry);
+        assert_eq!(components[6], "3", "{}", entry);
+    }
+}
+
+#[test]
+fn compare_fails_on_regression() {
+    let (dir, history) = setup("regression");
+    assert!(bench(&dir, &history, &["--save"]).status.success());
+
+    // a baseline that no solver can beat
+    set_medians(&history, 1);
+    let output: Output = bench(&dir, &history, &["--compare"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(": regression"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "1 of 1 benchmarks regressed by more than 10%"));
+
+    // a baseline that every solver beats
+    set_medians(&history, 60_000_000_000);
+    let output: Output = bench(&dir, &history, &["--compare"]);
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains(": ok"));
+}
+
+#[test]
+fn compare_ignores_other_inputs() {
+    let (dir, history) = setup("other-input");
+    assert!(bench(&dir, &history, &["--save"]).status.success());
+    set_medians(&history, 1);
+
+    fs::write(dir.join("day7.txt"), "32T3K 765\nKK677 28\n").unwrap();
+    let output: Output = bench(&dir, &history, &["--compare"]);
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("no baseline"));
+}
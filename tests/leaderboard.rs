@@ -0,0 +1,101 @@
+// render the JSON export of a private leaderboard
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+fn leaderboard(path: &str) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["leaderboard", path])
+        .output()
+        .unwrap();
+}
+
+#[test]
+fn shows_the_stars_by_day() {
+    let output: Output = leaderboard("tests/inputs/leaderboard.json");
+    assert!(output.status.success());
+
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines[0], "Private leaderboard 2023 (3 members)");
+    assert_eq!(lines[4],
+               "            *********................  this crate");
+    // ordered by local score, anonymous members by their id
+    assert_eq!(lines[5], "  1)    24  ***+.....................  Alice");
+    assert_eq!(lines[6],
+               "  2)    10  *........................  \
+                (anonymous user #1002)");
+    assert_eq!(lines[7], "  3)     0  .........................  Zoë");
+}
+
+#[test]
+fn shows_the_times_of_the_stars() {
+    let output: Output = leaderboard("tests/inputs/leaderboard.json");
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    for line in [
+        "Alice (local score 24, 7 stars)",
+        "  day  1: part 1 2023-12-01 05:11:12, part 2 2023-12-01 05:17:15 \
+         (+6m 03s)",
+        "  day  3: part 1 2023-12-03 05:06:40, part 2 2023-12-03 07:53:20 \
+         (+2h 46m)",
+        "  day  4: part 1 2023-12-04 05:10:00",
+        "  day  1: part 1 2023-12-01 06:00:00, part 2 2023-12-02 06:53:20 \
+         (+1d 00h)",
+        "  no stars yet",
+    ] {
+        assert!(stdout.lines().any(|l| l == line), "{}\n{}", line, stdout);
+    }
+}
+
+#[test]
+fn reports_where_the_json_is_broken() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-leaderboard.json");
+
+    for (json, message) in [
+        ("{\"event\":\"2023\",\"members\":{\"1\":[1,}}",
+         "Expected a value at byte 34"),
+        ("{\"event\":\"2023\",\"members\":{}} x",
+         "Expected the end of the document at byte 30"),
+        ("{\"event\":\"2023\"}", "Expected an object 'members'"),
+        ("{\"event\":\"2023\",\"members\":{\"7\":{\"id\":7}}}",
+         "Member 7: expected a number 'local_score'"),
+    ] {
+        fs::write(&path, json).unwrap();
+        let output: Output = leaderboard(path.to_str().unwrap());
+        let stderr: String =
+            String::from_utf8_lossy(&output.stderr).to_string();
+
+        assert!(!output.status.success());
+        assert!(stderr.contains(message), "{}: {}", json, stderr);
+    }
+}
+
+#[test]
+fn single_member_with_an_earlier_second_star() {
+    let path: PathBuf =
+        env::temp_dir().join("adventofcode23-leaderboard-single.json");
+    fs::write(&path, "{\"event\":\"2023\",\"members\":{\"5\":{\"id\":5,\
+                      \"name\":\"Bob\",\"stars\":1,\"local_score\":1,\
+                      \"completion_day_level\":{\"2\":{\
+                      \"1\":{\"get_star_ts\":1701493958},\
+                      \"2\":{\"get_star_ts\":1701493900}}}}}}").unwrap();
+
+    let output: Output = leaderboard(path.to_str().unwrap());
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!(lines[0], "Private leaderboard 2023 (1 member)");
+    assert!(lines.contains(&"Bob (local score 1, 1 star)"), "{}", stdout);
+    // an export edited by hand can have the second star first
+    assert!(lines.contains(
+        &"  day  2: part 1 2023-12-02 05:12:38, part 2 2023-12-02 05:11:40 \
+          (-58s)"), "{}", stdout);
+}
@@ -0,0 +1,63 @@
+// solve the example inputs through the library instead of the runner, like
+// a no_std user of the parsers and solvers would
+
+use adventofcode23::answer::Answer;
+use adventofcode23::day6;
+use adventofcode23::day7;
+use adventofcode23::day9;
+use adventofcode23::error::SolveError;
+
+const DAY6_EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+#[test]
+fn solvers_are_callable_without_the_runner() {
+    let day7_example: String =
+        std::fs::read_to_string("tests/inputs/day7.txt").unwrap();
+
+    assert_eq!(day6::puzzle1(DAY6_EXAMPLE.to_string()),
+               Ok(Answer::Unsigned(288)));
+    assert_eq!(day7::puzzle2(day7_example), Ok(Answer::Unsigned(5905)));
+}
+
+#[test]
+fn invalid_inputs_are_errors() {
+    let error: SolveError =
+        day6::puzzle1(String::from("Time: 7\n")).unwrap_err();
+
+    assert_eq!(error.to_string(),
+               "Invalid input for day 6: Expected a line starting with \
+                'Distance:'");
+}
+
+#[test]
+fn too_many_races_only_fail_puzzle_2() {
+    // the example races four times, whose 24 digits don't fit into an u64
+    let input: String = String::from(
+        "Time: 7 15 30 7 15 30 7 15 30 7 15 30\n\
+         Distance: 9 40 200 9 40 200 9 40 200 9 40 200\n");
+
+    assert_eq!(day6::puzzle1(input.clone()), Ok(Answer::Unsigned(6879707136)));
+    assert_eq!(day6::puzzle1_closed_form(input.clone()),
+               Ok(Answer::Unsigned(6879707136)));
+
+    assert_eq!(day6::puzzle2(input).unwrap_err().to_string(),
+               "Invalid input for day 6: Expected a number that fits into \
+                its type at byte 5");
+}
+
+#[test]
+fn answers_compare_by_value() {
+    let answer: Answer =
+        day9::puzzle2(String::from("10 13 16 21 30 45\n")).unwrap();
+
+    assert_eq!(answer, Answer::Signed(5));
+    assert_eq!(answer, Answer::Unsigned(5));
+    assert_eq!(answer, "5");
+    assert_ne!(answer, "-5");
+    assert_eq!(day9::puzzle2(String::from("1 3 5\n")).unwrap(), "-1");
+
+    assert_eq!(Answer::parse("-3"), Answer::Signed(-3));
+    assert_eq!(Answer::parse("abc"), Answer::Text(String::from("abc")));
+    assert_eq!(Answer::Signed(-3).to_string(), "-3");
+    assert!(!Answer::Unsolved.matches("unsolved"));
+}
@@ -0,0 +1,59 @@
+// profile the memory of the solvers with --mem and check that the counters
+// of the JSON output add up
+
+use std::process::Command;
+use std::process::Output;
+
+fn run_json(args: &[&str]) -> String {
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--cache-dir", ""])
+        .args(args)
+        .arg("--json")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    return String::from_utf8_lossy(&output.stdout).to_string();
+}
+
+// look up an integer field of the "memory" object of the JSON output
+fn memory_field(json: &str, name: &str) -> u64 {
+    let memory: &str = json.split("\"memory\":{").nth(1)
+        .unwrap_or_else(|| panic!("No memory in {}", json));
+    let value: &str = memory.split(&format!("\"{}\":", name)).nth(1)
+        .unwrap_or_else(|| panic!("No {} in {}", name, json));
+    let digits: &str = value.split([',', '}']).next().unwrap();
+
+    return digits.parse().unwrap();
+}
+
+#[test]
+fn memory_is_reported_with_mem() {
+    for (day, puzzle) in [("3", "1"), ("5", "2"), ("7", "1"), ("7", "both")] {
+        let input: String = format!("tests/inputs/day{}.txt", day);
+        let json: String = run_json(&[day, puzzle, &input, "--mem"]);
+
+        let allocations: u64 = memory_field(&json, "allocations");
+        let deallocations: u64 = memory_field(&json, "deallocations");
+        let bytes_allocated: u64 = memory_field(&json, "bytes_allocated");
+        let peak_live_bytes: u64 = memory_field(&json, "peak_live_bytes");
+
+        // every solver parses its input into vectors, which stay alive
+        // while it solves the puzzle; memory that was freed must have been
+        // allocated (except for the input the solver is given)
+        assert!(allocations > 0, "{}", json);
+        assert!(deallocations > 0, "{}", json);
+        assert!(peak_live_bytes > 0, "{}", json);
+        assert!(bytes_allocated >= peak_live_bytes, "{}", json);
+        assert!(deallocations <= allocations + 1, "{}", json);
+    }
+}
+
+#[test]
+fn no_memory_without_mem() {
+    let json: String = run_json(&["7", "1", "tests/inputs/day7.txt"]);
+
+    assert!(json.contains("\"memory\":null"), "{}", json);
+}
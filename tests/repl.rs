@@ -0,0 +1,82 @@
+// drive the REPL by piping commands into its stdin; the puzzles are solved
+// from the input that was parsed when the REPL started
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::Command;
+use std::process::Output;
+use std::process::Stdio;
+
+fn repl(day: &str, input_path: &str, commands: &str) -> String {
+    let mut child: Child = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["repl", day, input_path])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(commands.as_bytes()).unwrap();
+    let output: Output = child.wait_with_output().unwrap();
+
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    // every command is answered after the prompt it was entered at
+    let prompt: String = format!("day{}> ", day);
+    return String::from_utf8_lossy(&output.stdout).replace(&prompt, "\n");
+}
+
+// the answers of the output lines, without the times of the solvers
+fn answers(output: &str) -> Vec<&str> {
+    return output.lines().filter(|line| line.ends_with(')'))
+        .map(|line| line.split(" (").next().unwrap()).collect();
+}
+
+#[test]
+fn puzzles_are_solved_from_the_parsed_input() {
+    let cases = [("3", 4361, 467835), ("4", 13, 30), ("5", 35, 46),
+                 ("7", 6440, 5905), ("8", 2, 2)];
+
+    for (day, answer1, answer2) in cases {
+        let path: String = format!("tests/inputs/day{}.txt", day);
+        let output: String = repl(day, &path, "part1\npart2\n!1\nquit\n");
+
+        assert!(output.starts_with(&format!(
+            "Loaded '{}' for AOC day {}", path, day)), "{}", output);
+        assert_eq!(answers(&output),
+                   [answer1.to_string(), answer2.to_string(),
+                    answer1.to_string()], "day {}", day);
+    }
+}
+
+#[test]
+fn failing_solver_keeps_the_session() {
+    // a distance is missing for puzzle 1, but not for puzzle 2
+    let path: PathBuf = env::temp_dir().join("adventofcode23-repl-day6.txt");
+    fs::write(&path, "Time: 7 15\nDistance: 940\n").unwrap();
+
+    let output: String = repl("6", path.to_str().unwrap(), "part1\npart2\n");
+
+    assert!(output.contains("\nSolver failed: Invalid input for day 6: \
+                             Expected a distance for every race time\n"),
+            "{}", output);
+    assert_eq!(answers(&output), ["712"]);
+}
+
+#[test]
+fn day_commands_and_history() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-repl-day9.txt");
+    fs::write(&path, "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n")
+        .unwrap();
+
+    let output: String = repl("9", path.to_str().unwrap(),
+                              "extrapolate 3\nhistory\nfly\n");
+
+    assert!(output.contains("\n(5) 10 13 16 21 30 45 (68)\n"), "{}", output);
+    assert!(output.contains("\n   1  extrapolate 3\n"), "{}", output);
+    assert!(output.contains("\nUnknown command 'fly'"), "{}", output);
+}
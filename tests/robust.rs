@@ -0,0 +1,70 @@
+// run the robustness driver of the runner for every day: all solvers
+// have to answer or reject the mutated inputs without panicking or hanging
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+const MUTATED_INPUTS: u64 = 200;
+
+fn assert_robust(day: u8) {
+    let crash_dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-crashes-day{}", day));
+
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["robust", &day.to_string(), &MUTATED_INPUTS.to_string()])
+        .arg("--crash-dir")
+        .arg(&crash_dir)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "day {} isn't robust:\n{}{}", day,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn day1_is_robust() {
+    assert_robust(1);
+}
+
+#[test]
+fn day2_is_robust() {
+    assert_robust(2);
+}
+
+#[test]
+fn day3_is_robust() {
+    assert_robust(3);
+}
+
+#[test]
+fn day4_is_robust() {
+    assert_robust(4);
+}
+
+#[test]
+fn day5_is_robust() {
+    assert_robust(5);
+}
+
+#[test]
+fn day6_is_robust() {
+    assert_robust(6);
+}
+
+#[test]
+fn day7_is_robust() {
+    assert_robust(7);
+}
+
+#[test]
+fn day8_is_robust() {
+    assert_robust(8);
+}
+
+#[test]
+fn day9_is_robust() {
+    assert_robust(9);
+}
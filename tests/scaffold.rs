@@ -0,0 +1,157 @@
+// scaffold a new day in a copy of this crate, which must never overwrite
+// existing files; the generated crate must compile
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+const REGISTRATIONS: [&str; 3] = ["src/lib.rs", "src/main.rs",
+                                  "src/solver/mod.rs"];
+
+// copy of the manifest and the sources of this crate, so that the scaffold
+// edits the real registrations without touching them
+fn setup(name: &str) -> PathBuf {
+    let root: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-scaffold-{}", name));
+    let _ = fs::remove_dir_all(&root);
+
+    copy_dir(Path::new("src"), &root.join("src"));
+    fs::copy("Cargo.toml", root.join("Cargo.toml")).unwrap();
+
+    return root;
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+
+    for entry in fs::read_dir(from).unwrap() {
+        let path: PathBuf = entry.unwrap().path();
+        let target: PathBuf = to.join(path.file_name().unwrap());
+
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+// scaffold from the working directory of the test (not the root), with
+// the default input directory
+fn scaffold(root: &PathBuf, args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .env("XDG_CONFIG_HOME", root.join("config"))
+        .args(["scaffold", "--root", root.to_str().unwrap()])
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+// the last registered day module, i.e. "day9"
+fn last_day(root: &PathBuf) -> String {
+    let lib: String = read(root, "src/lib.rs");
+    let last: &str = lib.lines().rev().find_map(
+        |line| line.strip_prefix("pub mod day")).unwrap();
+
+    return format!("day{}", last.trim_end_matches(';'));
+}
+
+fn read(root: &PathBuf, path: &str) -> String {
+    return fs::read_to_string(root.join(path)).unwrap();
+}
+
+#[test]
+fn generates_and_registers_a_day() {
+    let root: PathBuf = setup("new");
+    let last_day: String = last_day(&root);
+    let days: usize = read(&root, "src/lib.rs").lines().filter(
+        |line| line.starts_with("pub mod day")).count();
+
+    let output: Output = scaffold(&root, &["12", "--title", "Hot Springs"]);
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    assert!(read(&root, "src/day12/mod.rs").contains(
+        "const DAY: u8 = 12;"));
+    assert!(read(&root, "src/day12/mod.rs").contains(
+        "return Ok(Answer::Unsolved);"));
+    assert!(read(&root, "src/day12/helpers.rs").contains(
+        "pub fn parse_lines("));
+    assert!(read(&root, "tests/day12.rs").contains(
+        "assert_eq!(day12::puzzle1(EXAMPLE.to_string()),\n               \
+         Ok(Answer::Unsolved));"));
+    assert_eq!(read(&root, "inputs/day12.txt"), "");
+    assert!(String::from_utf8_lossy(&output.stdout).contains(&format!(
+        "Wrote {}\n", root.join("inputs/day12.txt").display())));
+
+    assert!(read(&root, "src/lib.rs").contains(&format!(
+        "pub mod {};\npub mod day12;\n", last_day)));
+    assert!(read(&root, "src/main.rs").contains(&format!(
+        "use adventofcode23::{};\nuse adventofcode23::day12;\n", last_day)));
+
+    let solver: String = read(&root, "src/solver/mod.rs");
+    assert!(solver.contains(&format!(
+        "use crate::{};\nuse crate::day12;\n", last_day)));
+    assert!(solver.contains(&format!(
+        "pub const SOLVERS: [Solver; {}] = [", days + 1)));
+    assert!(solver.contains(
+        "        day: 12,\n        title: \"Hot Springs\","));
+    // the stubs don't count as solved puzzles
+    assert!(solver.contains("            puzzles: [None, None],\n"));
+    assert!(solver.contains(
+        "                  include_str!(\"../day12/helpers.rs\")],\n    },\n\
+         ];\n"));
+
+    // the generated day, its test and its registrations compile (in a
+    // target directory that is kept between the runs of the test)
+    let output: Output = Command::new(env::var("CARGO").unwrap_or(
+        String::from("cargo")))
+        .args(["check", "--quiet", "--all-targets"])
+        .current_dir(&root)
+        .env("CARGO_TARGET_DIR",
+             Path::new(env!("CARGO_TARGET_TMPDIR")).join("scaffold"))
+        .env("RUSTFLAGS", "-D warnings")
+        .output()
+        .unwrap();
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn never_overwrites_files() {
+    let root: PathBuf = setup("existing");
+    fs::create_dir_all(root.join("tests")).unwrap();
+    fs::write(root.join("tests/day12.rs"), "// mine\n").unwrap();
+
+    let originals: Vec<String> =
+        REGISTRATIONS.iter().map(|path| read(&root, path)).collect();
+
+    let output: Output = scaffold(&root, &["12"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "day12.rs exists already, not overwriting it"));
+
+    // nothing was written
+    assert_eq!(read(&root, "tests/day12.rs"), "// mine\n");
+    assert!(!root.join("src/day12").exists());
+    for (path, original) in REGISTRATIONS.iter().zip(originals) {
+        assert_eq!(read(&root, path), original, "{}", path);
+    }
+}
+
+#[test]
+fn rejects_solved_and_invalid_days() {
+    let root: PathBuf = setup("invalid");
+
+    let output: Output = scaffold(&root, &["7"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Day 7 has a solver already"));
+
+    let output: Output = scaffold(&root, &["26"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Invalid value '26' for N"));
+    assert!(!root.join("inputs").exists());
+}
@@ -0,0 +1,56 @@
+// feed broken inputs to the parsers of the days, which have to tell what
+// they expected and at which byte of the input they found something else
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+// day, broken input and the expected part of the error message
+const BROKEN_INPUTS: [(u8, &str, &str); 5] = [
+    (2, "Game 1: 3 blue, 4 red\nGame 2; 1 red\n",
+     "Expected ': ' at byte 28"),
+    (4, "Card 1: 41 48 | 83 86\nCard 2: 13 32 / 61 30\n",
+     "Expected '|' at byte 36"),
+    (7, "32T3K 765\nT55J5 68x\n", "Expected the end of the line at byte 18"),
+    (8, "LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, QQQ)\nZZZ = (ZZZ, ZZZ)\n",
+     "Expected a known node at byte 33"),
+    (9, "0 3 6 9\n1 3 - 10\n", "Expected a number at byte 12"),
+];
+
+fn run(day: u8, input: &str) -> Output {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-scan-day{}", day));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    let input_file: PathBuf = dir.join("input.txt");
+    fs::write(&input_file, input).unwrap();
+
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--cache-dir", "", "run", &day.to_string(), "1",
+               input_file.to_str().unwrap()])
+        .output()
+        .unwrap();
+}
+
+#[test]
+fn parse_errors_point_at_the_byte() {
+    for (day, input, message) in BROKEN_INPUTS {
+        let output: Output = run(day, input);
+        let stderr: String =
+            String::from_utf8_lossy(&output.stderr).to_string();
+
+        assert!(!output.status.success(), "day {}", day);
+        assert!(stderr.contains(message), "day {}: {}", day, stderr);
+    }
+}
+
+#[test]
+fn valid_inputs_still_parse() {
+    let output: Output = run(9, "0 3 6 9 12 15\n-1 -2 -3\n");
+
+    assert_eq!(String::from_utf8_lossy(&output.stdout),
+               "Result of AOC day 9, puzzle 1: 14\n");
+}
@@ -0,0 +1,124 @@
+// drive the `serve` mode of the runner over localhost
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::process::Child;
+use std::process::ChildStdout;
+use std::process::Command;
+use std::process::Stdio;
+
+const DAY1_EXAMPLE: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+// kills the server process once the test is done (even if it failed)
+struct Server {
+    process: Child,
+    address: String,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+fn start_server() -> Server {
+    let mut process: Child = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["serve", "--port", "0"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    // first line of output announces the address the server listens on
+    let stdout: ChildStdout = process.stdout.take().unwrap();
+    let mut line: String = String::new();
+    BufReader::new(stdout).read_line(&mut line).unwrap();
+
+    let address: String =
+        line.trim().strip_prefix("Listening on http://").unwrap().to_string();
+
+    return Server{process: process, address: address};
+}
+
+// send a request and return the status code and body of the response
+fn request(server: &Server, method: &str, path: &str, body: &str) ->
+    (u16, String) {
+    let mut stream: TcpStream = TcpStream::connect(&server.address).unwrap();
+
+    write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\n\
+                    Content-Length: {}\r\n\r\n{}",
+           method, path, body.len(), body).unwrap();
+
+    let mut response: String = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let status: u16 = response[9..12].parse().unwrap();
+    let (_headers, body) = response.split_once("\r\n\r\n").unwrap();
+
+    return (status, body.to_string());
+}
+
+#[test]
+fn lists_all_days() {
+    let server: Server = start_server();
+
+    let (status, body) = request(&server, "GET", "/days", "");
+
+    assert_eq!(status, 200);
+    assert!(body.starts_with("{\"days\":[{\"day\":1,\"title\":"));
+    assert!(body.contains("\"day\":9,\"title\":\"Mirage Maintenance\""));
+    assert!(body.contains("\"parts\":[1,2]"));
+}
+
+#[test]
+fn solves_posted_input() {
+    let server: Server = start_server();
+
+    let (status, body) = request(&server, "POST", "/solve/1/1", DAY1_EXAMPLE);
+
+    assert_eq!(status, 200);
+    assert!(body.contains("\"answer\":142"));
+    assert!(body.contains("\"time_ms\":"));
+    assert!(body.contains("\"error\":null"));
+}
+
+#[test]
+fn reports_malformed_input() {
+    let server: Server = start_server();
+
+    let (status, body) = request(&server, "POST", "/solve/2/1", "not a game");
+
+    assert_eq!(status, 422);
+    assert!(body.contains("\"answer\":null"));
+    assert!(!body.contains("\"error\":null"));
+}
+
+#[test]
+fn rejects_unknown_routes() {
+    let server: Server = start_server();
+
+    assert_eq!(request(&server, "POST", "/solve/42/1", "").0, 404);
+    assert_eq!(request(&server, "POST", "/solve/1/3", "").0, 404);
+    assert_eq!(request(&server, "GET", "/solve/1/1", "").0, 405);
+    assert_eq!(request(&server, "GET", "/nothing", "").0, 404);
+}
+
+#[test]
+fn times_out_incomplete_requests() {
+    let server: Server = start_server();
+
+    // the body never arrives, so the server gives up waiting for it
+    let mut stream: TcpStream = TcpStream::connect(&server.address).unwrap();
+    write!(stream, "POST /solve/1/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\n")
+        .unwrap();
+
+    let mut response: String = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"),
+            "{}", response);
+}
@@ -0,0 +1,67 @@
+// print the example inputs with the `show` command of the runner, which
+// must only color its output when asked to (or printing to a terminal)
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+fn show(args: &[&str]) -> String {
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .arg("show")
+        .args(args)
+        .env_remove("NO_COLOR")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    return String::from_utf8_lossy(&output.stdout).to_string();
+}
+
+#[test]
+fn no_colors_when_piped() {
+    for day in ["3", "7"] {
+        let input: String = format!("tests/inputs/day{}.txt", day);
+
+        // the output of the test isn't a terminal
+        assert!(!show(&[day, &input]).contains('\x1b'));
+        assert!(!show(&[day, &input, "--color", "never"]).contains('\x1b'));
+    }
+}
+
+#[test]
+fn schematic_colors() {
+    let output: String =
+        show(&["3", "tests/inputs/day3.txt", "--color", "always"]);
+
+    // counted part number, ignored number and gear
+    assert!(output.contains("\x1b[32m467\x1b[0m"), "{}", output);
+    assert!(output.contains("\x1b[31m114\x1b[0m"), "{}", output);
+    assert!(output.contains("\x1b[33m*\x1b[0m"), "{}", output);
+}
+
+#[test]
+fn hand_ranking_table() {
+    let output: String = show(&["7", "tests/inputs/day7.txt"]);
+
+    assert!(output.contains("   1  32T3K  one pair         765       765\n"),
+            "{}", output);
+    assert!(output.contains("total winnings: 6440\n"), "{}", output);
+    assert!(output.contains("total winnings: 5905\n"), "{}", output);
+}
+
+#[test]
+fn aligned_difference_pyramid() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-show-day9.txt");
+    fs::write(&path, "10 13 16 21 30 45\n").unwrap();
+
+    assert_eq!(show(&["9", path.to_str().unwrap()]),
+               "   5  10  13  16  21  30  45  68\n\
+                \x20    5   3   3   5   9  15  23\n\
+                \x20     -2   0   2   4   6   8\n\
+                \x20        2   2   2   2   2\n\
+                \x20          0   0   0   0\n");
+}
@@ -0,0 +1,95 @@
+// golden snapshot tests of the intermediate structures of the solvers;
+// run with UPDATE_SNAPSHOTS=1 to (re)write the snapshots after an
+// intended change of a parser and review the diff before committing
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+// directory of this crate (the tests/ files are relative to it)
+fn crate_dir() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+}
+
+// dump the intermediate structures of a day for its example input
+fn dump(day: u8) -> String {
+    let input_file_path: PathBuf =
+        crate_dir().join(format!("tests/inputs/day{}.txt", day));
+
+    let output: Output = Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .arg("snapshot")
+        .arg(day.to_string())
+        .arg(&input_file_path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "snapshot of day {} failed: {}",
+            day, String::from_utf8_lossy(&output.stderr));
+
+    return String::from_utf8(output.stdout).unwrap();
+}
+
+fn assert_snapshot(day: u8) {
+    let snapshot_path: PathBuf =
+        crate_dir().join(format!("tests/snapshots/day{}.snap", day));
+    let actual: String = dump(day);
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&snapshot_path, &actual).unwrap();
+        return;
+    }
+
+    let expected: String = fs::read_to_string(&snapshot_path).unwrap_or_else(
+        |_| panic!("Missing snapshot '{}'; run with UPDATE_SNAPSHOTS=1",
+                   snapshot_path.display()));
+
+    if actual == expected {
+        return;
+    }
+
+    // report every differing line instead of two huge strings
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut diff: Vec<String> = Vec::new();
+
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line: Option<&&str> = expected_lines.get(i);
+        let actual_line: Option<&&str> = actual_lines.get(i);
+
+        if expected_line != actual_line {
+            diff.push(format!("line {}:\n  - {}\n  + {}", i + 1,
+                              expected_line.unwrap_or(&"<none>"),
+                              actual_line.unwrap_or(&"<none>")));
+        }
+    }
+
+    panic!("Snapshot of day {} changed (UPDATE_SNAPSHOTS=1 to accept):\n{}",
+           day, diff.join("\n"));
+}
+
+#[test]
+fn day3_part_numbers() {
+    assert_snapshot(3);
+}
+
+#[test]
+fn day4_card_copies() {
+    assert_snapshot(4);
+}
+
+#[test]
+fn day5_maps() {
+    assert_snapshot(5);
+}
+
+#[test]
+fn day7_ranked_hands() {
+    assert_snapshot(7);
+}
+
+#[test]
+fn day8_network() {
+    assert_snapshot(8);
+}
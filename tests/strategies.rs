@@ -0,0 +1,120 @@
+// solve the example inputs with every strategy of a day, which all have to
+// find the same answer, and let bench and verify compare them
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+// day, strategies and answers of both puzzles for the example inputs
+const EXAMPLES: [(u8, &[&str], [&str; 2]); 4] = [
+    (5, &["ranges", "brute-force"], ["35", "46"]),
+    (6, &["binary-search", "closed-form"], ["288", "71503"]),
+    (7, &["compare", "packed"], ["6440", "5905"]),
+    (8, &["lcm", "simulation"], ["2", "2"]),
+];
+
+const DAY6_EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+// directory with the example inputs of days 5 to 8
+fn setup(name: &str) -> PathBuf {
+    let dir: PathBuf =
+        env::temp_dir().join(format!("adventofcode23-strategies-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    for day in [5, 7, 8] {
+        fs::copy(format!("tests/inputs/day{}.txt", day),
+                 dir.join(format!("day{}.txt", day))).unwrap();
+    }
+    fs::write(dir.join("day6.txt"), DAY6_EXAMPLE).unwrap();
+
+    return dir;
+}
+
+fn run(input_dir: &PathBuf, args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .args(["--input-dir", input_dir.to_str().unwrap(),
+               "--cache-dir", ""])
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+#[test]
+fn all_strategies_find_the_same_answer() {
+    let dir: PathBuf = setup("answers");
+
+    for (day, strategies, answers) in EXAMPLES {
+        let input: String =
+            dir.join(format!("day{}.txt", day)).to_str().unwrap().to_string();
+
+        for strategy in strategies {
+            for (puzzle, answer) in ["1", "2"].iter().zip(answers) {
+                let output: Output = run(&dir, &[
+                    "run", &day.to_string(), puzzle, &input,
+                    "--strategy", strategy]);
+
+                assert_eq!(String::from_utf8_lossy(&output.stdout),
+                           format!("Result of AOC day {}, puzzle {}: {}\n",
+                                   day, puzzle, answer),
+                           "strategy {} of day {}", strategy, day);
+            }
+        }
+    }
+}
+
+#[test]
+fn unknown_strategy_fails() {
+    let dir: PathBuf = setup("unknown");
+
+    let output: Output = run(&dir, &["run", "5", "1", "--strategy", "lcm"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(
+        "Unknown strategy 'lcm' for AOC day 5 \
+         (expected one of ranges, brute-force)"));
+}
+
+#[test]
+fn bench_measures_every_strategy() {
+    let dir: PathBuf = setup("bench");
+
+    let output: Output = run(&dir, &["bench", "7", "--all-strategies",
+                                     "--runs", "1"]);
+    let stdout: String = String::from_utf8_lossy(&output.stdout).to_string();
+
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+    for line in ["Day 7, puzzle 1 [compare]", "Day 7, puzzle 1 [packed]",
+                 "Day 7, puzzle 2 [compare]", "Day 7, puzzle 2 [packed]"] {
+        assert!(stdout.contains(line), "{}", stdout);
+    }
+}
+
+#[test]
+fn verify_reports_disagreeing_strategies() {
+    let dir: PathBuf = setup("verify");
+    let answers: PathBuf = dir.join("answers.txt");
+    fs::write(&answers, "7 1 6440\n7 2 5905\n").unwrap();
+
+    let output: Output = run(&dir, &["--answers", answers.to_str().unwrap(),
+                                     "verify", "7", "--all-strategies"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(
+        "Day 7, puzzle 2 [packed]: ok"));
+
+    // the ghosts stay on the end node ZZZ once they reached it, so
+    // the steps of the single walks aren't cycles and their LCM is wrong
+    fs::write(dir.join("day8.txt"),
+              "LRRR\n\nAAA = (LHA, RDA)\nLHA = (RDA, RDA)\n\
+               RDA = (XNL, UTP)\nXNL = (UTP, RKZ)\nUTP = (RKZ, ZZZ)\n\
+               RKZ = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    let output: Output = run(&dir, &["--answers", answers.to_str().unwrap(),
+                                     "verify", "8", "--all-strategies"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(
+        "Day 8, puzzle 2: strategies disagree (lcm: 12, simulation: 4)"));
+}
@@ -0,0 +1,76 @@
+// render the example inputs with the `viz` command of the runner and check
+// that every picture is a complete SVG document
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+
+fn viz(args: &[&str]) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+        .arg("viz")
+        .args(args)
+        .output()
+        .unwrap();
+}
+
+fn assert_svg(svg: &str) {
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""),
+            "not an SVG document:\n{}", svg);
+    assert!(svg.trim_end().ends_with("</svg>"), "incomplete SVG:\n{}", svg);
+}
+
+#[test]
+fn examples_are_rendered() {
+    for day in [3, 5, 8] {
+        let output: Output =
+            viz(&[&day.to_string(), &format!("tests/inputs/day{}.txt", day)]);
+        assert!(output.status.success(), "day {}: {}", day,
+                String::from_utf8_lossy(&output.stderr));
+        assert_svg(&String::from_utf8_lossy(&output.stdout));
+    }
+}
+
+#[test]
+fn extrapolated_values_are_drawn() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-viz-day9.txt");
+    fs::write(&path, "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n")
+        .unwrap();
+
+    let output: Output = viz(&["9", path.to_str().unwrap()]);
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+
+    let svg: String = String::from_utf8_lossy(&output.stdout).to_string();
+    assert_svg(&svg);
+    for value in ["18", "28", "68", "-3", "5"] {
+        assert!(svg.contains(&format!(">{}</text>", value)),
+                "extrapolated value {} is missing:\n{}", value, svg);
+    }
+}
+
+#[test]
+fn picture_is_written_to_output() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-viz-day8.svg");
+    let _ = fs::remove_file(&path);
+
+    let output: Output =
+        viz(&["8", "tests/inputs/day8.txt", "-o", path.to_str().unwrap()]);
+    assert!(output.status.success(),
+            "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.stdout.is_empty());
+
+    let svg: String = fs::read_to_string(&path).unwrap();
+    assert_svg(&svg);
+    // the nodes of the network are labeled
+    assert!(svg.contains(">AAA</text>") && svg.contains(">ZZZ</text>"));
+}
+
+#[test]
+fn day_without_visualization() {
+    let output: Output = viz(&["1", "tests/inputs/day8.txt"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("No visualization for AOC day 1"));
+}
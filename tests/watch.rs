@@ -0,0 +1,136 @@
+// run the watch mode on an input file that the test creates and changes,
+// and check that the solvers run again after every change
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::ChildStdout;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::thread;
+use std::time::Duration;
+use std::time::SystemTime;
+
+// the watch mode runs until it's killed, so it's killed with the watcher
+struct Watcher {
+    child: Child,
+    lines: Receiver<String>,  // output of the watch mode, line by line
+}
+
+impl Watcher {
+    fn start(day: &str, input_path: &PathBuf, options: &[&str]) -> Watcher {
+        let mut child: Child =
+            Command::new(env!("CARGO_BIN_EXE_adventofcode23"))
+            .args(options)
+            .args(["watch", day, "--input", input_path.to_str().unwrap(),
+                   "--interval", "10"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let stdout: ChildStdout = child.stdout.take().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                if sender.send(line.unwrap()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        return Watcher{child: child, lines: receiver};
+    }
+
+    // wait for the next output line that starts with the prefix
+    fn wait_for(&self, prefix: &str) -> String {
+        loop {
+            let line: String = self.lines.recv_timeout(Duration::from_secs(10))
+                .unwrap_or_else(|_| panic!("No line starting with '{}'",
+                                           prefix));
+            if line.starts_with(prefix) {
+                return line;
+            }
+        }
+    }
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// replace the input with a file whose modification time is in the future,
+// so that every write is a change, even if the file system stores coarse
+// times (the rename makes sure that the watch mode never sees a half
+// written input)
+fn write_input(path: &PathBuf, content: &str, seconds_ahead: u64) {
+    let new_path: PathBuf = path.with_extension("new");
+    fs::write(&new_path, content).unwrap();
+    File::options().write(true).open(&new_path).unwrap().set_modified(
+        SystemTime::now() + Duration::from_secs(seconds_ahead)).unwrap();
+    fs::rename(&new_path, path).unwrap();
+}
+
+// the answer of an output line ("Day 9, puzzle 1: 68 (was 18) in 1.2µs"),
+// without the time
+fn answer(line: &str) -> &str {
+    let (_, answer): (&str, &str) = line.split_once(": ").unwrap();
+    return answer.rsplit_once(" in ").unwrap().0;
+}
+
+#[test]
+fn solvers_run_again_when_the_input_changes() {
+    let path: PathBuf = env::temp_dir().join("adventofcode23-watch-day9.txt");
+    let _ = fs::remove_file(&path);
+
+    let watcher: Watcher = Watcher::start("9", &path, &["--cache-dir", ""]);
+    watcher.wait_for("Waiting for input file");
+
+    write_input(&path, "10 13 16 21 30 45\n", 10);
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 1")), "68");
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 2")), "5");
+
+    write_input(&path, "0 3 6 9 12 15\n", 20);
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 1")), "18 (was 68)");
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 2")), "-3 (was 5)");
+
+    // a new modification time is enough to run the solvers again
+    write_input(&path, "0 3 6 9 12 15\n", 30);
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 1")),
+               "18 (unchanged)");
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 2")),
+               "-3 (unchanged)");
+}
+
+#[test]
+fn answers_are_checked_and_cached_like_by_the_runner() {
+    let dir: PathBuf = env::temp_dir().join("adventofcode23-watch-answers");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    // "+68" is the same number as 68
+    let answers_path: PathBuf = dir.join("answers.txt");
+    fs::write(&answers_path, "9 1 +68\n9 2 7\n").unwrap();
+    let path: PathBuf = dir.join("day9.txt");
+    write_input(&path, "10 13 16 21 30 45\n", 10);
+
+    let watcher: Watcher = Watcher::start(
+        "9", &path, &["--answers", answers_path.to_str().unwrap(),
+                      "--cache-dir", dir.join("cache").to_str().unwrap()]);
+
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 1")), "68 [correct]");
+    assert_eq!(answer(&watcher.wait_for("Day 9, puzzle 2")),
+               "5 [expected 7]");
+
+    // the same input again is answered from the cache
+    write_input(&path, "10 13 16 21 30 45\n", 20);
+    assert!(watcher.wait_for("Day 9, puzzle 1").ends_with(" (cached)"));
+}